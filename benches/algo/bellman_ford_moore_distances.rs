@@ -269,7 +269,7 @@ fn bellman_ford_moore(bencher: Bencher<'_, '_>, order: usize) {
     bencher.bench_local(|| {
         for u in 0..order {
             let mut bfm = BellmanFordMoore::new(&digraph, u);
-            let _ = bfm.distances();
+            drop(bfm.distances());
         }
     });
 }
@@ -8,10 +8,14 @@ use {
         AdjacencyMatrix,
         BellmanFordMoore,
         BfsDist,
+        DialDist,
         DijkstraDist,
         EdgeList,
         ErdosRenyi,
         FloydWarshall,
+        Johnson77,
+        Spfa,
+        ZeroOneBfsDist,
     },
     std::iter::once,
 };
@@ -108,6 +112,27 @@ fn bfs_edge_list(bencher: Bencher<'_, '_>) {
     });
 }
 
+#[divan::bench]
+fn dial(bencher: Bencher<'_, '_>) {
+    let digraph = AdjacencyListWeighted::from(AdjacencyList::erdos_renyi(
+        ORDER,
+        PROBABILITY,
+        SEED,
+    ));
+
+    let mut dial = DialDist::new(&digraph, once(0));
+    let dist = dial.distances();
+
+    assert_eq!(dist[0], 0, "expected 0, got {}", dist[0]);
+    assert_eq!(dist[999], 6, "expected 6, got {}", dist[999]);
+
+    bencher.bench_local(|| {
+        let mut dial = DialDist::new(&digraph, once(0));
+
+        drop(dial.distances());
+    });
+}
+
 #[divan::bench]
 fn dijkstra(bencher: Bencher<'_, '_>) {
     let digraph = AdjacencyListWeighted::from(AdjacencyList::erdos_renyi(
@@ -138,13 +163,74 @@ fn floyd_warshall(bencher: Bencher<'_, '_>) {
     ));
 
     let mut floyd_warshall = FloydWarshall::new(&digraph);
-    let dist = floyd_warshall.distances();
+    let dist = floyd_warshall.distances().unwrap();
 
     assert_eq!(dist[(0, 0)], 0, "expected 0, got {}", dist[(0, 0)]);
     assert_eq!(dist[(0, 999)], 6, "expected 6, got {}", dist[(0, 999)]);
 
     bencher.bench_local(|| {
         let mut floyd_warshall = FloydWarshall::new(&digraph);
-        let _ = floyd_warshall.distances();
+        drop(floyd_warshall.distances());
+    });
+}
+
+#[divan::bench]
+fn johnson_77(bencher: Bencher<'_, '_>) {
+    let digraph = AdjacencyListWeighted::from(AdjacencyList::erdos_renyi(
+        ORDER,
+        PROBABILITY,
+        SEED,
+    ));
+
+    let mut johnson = Johnson77::new(&digraph);
+    let dist = johnson.distances().unwrap();
+
+    assert_eq!(dist[(0, 0)], 0, "expected 0, got {}", dist[(0, 0)]);
+    assert_eq!(dist[(0, 999)], 6, "expected 6, got {}", dist[(0, 999)]);
+
+    bencher.bench_local(|| {
+        let mut johnson = Johnson77::new(&digraph);
+        let _ = johnson.distances().unwrap();
+    });
+}
+
+#[divan::bench]
+fn spfa(bencher: Bencher<'_, '_>) {
+    let digraph = AdjacencyListWeighted::from(AdjacencyList::erdos_renyi(
+        ORDER,
+        PROBABILITY,
+        SEED,
+    ));
+
+    let mut spfa = Spfa::new(&digraph, 0);
+    let dist = spfa.distances().unwrap();
+
+    assert_eq!(dist[0], 0, "expected 0, got {}", dist[0]);
+    assert_eq!(dist[999], 6, "expected 6, got {}", dist[999]);
+
+    bencher.bench_local(|| {
+        let mut spfa = Spfa::new(&digraph, 0);
+        let _ = spfa.distances().unwrap();
+    });
+}
+
+#[divan::bench]
+fn zero_one_bfs(bencher: Bencher<'_, '_>) {
+    let digraph = AdjacencyListWeighted::from(AdjacencyList::erdos_renyi(
+        ORDER,
+        PROBABILITY,
+        SEED,
+    ));
+
+    let mut bfs = ZeroOneBfsDist::new(&digraph, once(0));
+    let dist = bfs.distances();
+
+    assert_eq!(dist[0], 0, "expected 0, got {}", dist[0]);
+    assert_eq!(dist[999], 6, "expected 6, got {}", dist[999]);
+
+    bencher.bench_local(|| {
+        let mut bfs = ZeroOneBfsDist::new(&digraph, once(0));
+
+        drop(bfs.distances());
     });
 }
@@ -38,7 +38,7 @@ fn bellman_ford_moore(bencher: Bencher<'_, '_>) {
 
     bencher.bench_local(|| {
         let mut bellman_ford_moore = BellmanFordMoore::new(&digraph, 0);
-        let _ = bellman_ford_moore.distances();
+        drop(bellman_ford_moore.distances());
     });
 }
 
@@ -60,7 +60,7 @@ fn dijkstra(bencher: Bencher<'_, '_>) {
 fn floyd_warshall(bencher: Bencher<'_, '_>) {
     let digraph = kattis_bryr_3_isize();
     let mut floyd_warshall = FloydWarshall::new(&digraph);
-    let dist = floyd_warshall.distances();
+    let dist = floyd_warshall.distances().unwrap();
 
     assert!(
         dist[0..10].iter().eq(&DISTANCES_ISIZE),
@@ -69,6 +69,6 @@ fn floyd_warshall(bencher: Bencher<'_, '_>) {
 
     bencher.bench_local(|| {
         let mut floyd_warshall = FloydWarshall::new(&digraph);
-        let _ = floyd_warshall.distances();
+        drop(floyd_warshall.distances());
     });
 }
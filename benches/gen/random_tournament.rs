@@ -129,7 +129,7 @@ fn random_tournament_adjacency_list_btree_set_parallel(
         pairs.set_len(total_pairs);
     }
 
-    let num_threads = available_parallelism().map(NonZero::get).unwrap_or(1);
+    let num_threads = available_parallelism().map_or(1, NonZero::get);
 
     let chunk_size = order.div_ceil(num_threads);
     let mut offsets = vec![0_usize; num_threads + 1];
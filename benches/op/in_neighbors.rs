@@ -116,7 +116,7 @@ fn in_neighbors_adjacency_list_parallel(
 ) -> impl Iterator<Item = usize> + use<> {
     let arcs = &digraph.arcs;
     let len = arcs.len();
-    let num_threads = available_parallelism().map(NonZero::get).unwrap_or(1);
+    let num_threads = available_parallelism().map_or(1, NonZero::get);
     let chunk_size = len.div_ceil(num_threads);
     let mut indices = Vec::new();
 
@@ -0,0 +1,550 @@
+//! The A* algorithm.
+//!
+//! The A* algorithm finds the shortest path in an arc-weighted digraph from
+//! one or more source vertices to a target vertex.[^1] A heuristic estimates
+//! the distance from each vertex to the nearest target and guides the search
+//! toward the targets.
+//!
+//! The heuristic must be consistent: for each arc `(u, v)` with weight `w`,
+//! `heuristic(u) <= w + heuristic(v)`, and the heuristic is zero at the
+//! targets. Debug builds assert these conditions. The zero heuristic turns
+//! the A* algorithm into Dijkstra's algorithm.
+//!
+//! The time complexity is `O(v log v + a)`, where `v` is the digraph's order
+//! and `a` is the digraph's size.
+//!
+//! # Examples
+//!
+//! The heuristic is the exact distance to vertex `6`, so the algorithm only
+//! visits the vertices on the shortest path.
+//!
+//! ```
+//! use {
+//!     graaf::{
+//!         AStar,
+//!         AddArcWeighted,
+//!         AdjacencyListWeighted,
+//!         Empty,
+//!     },
+//!     std::iter::once,
+//! };
+//!
+//! let mut digraph = AdjacencyListWeighted::<usize>::empty(7);
+//!
+//! digraph.add_arc_weighted(0, 1, 1);
+//! digraph.add_arc_weighted(1, 2, 1);
+//! digraph.add_arc_weighted(1, 6, 6);
+//! digraph.add_arc_weighted(2, 4, 1);
+//! digraph.add_arc_weighted(3, 0, 2);
+//! digraph.add_arc_weighted(4, 5, 2);
+//! digraph.add_arc_weighted(5, 6, 1);
+//!
+//! let heuristic = [6, 5, 4, 8, 3, 1, 0];
+//! let mut a_star = AStar::new(&digraph, once(0), |v| heuristic[v]);
+//!
+//! assert!(a_star.eq([
+//!     (None, 0),
+//!     (Some(0), 1),
+//!     (Some(1), 2),
+//!     (Some(2), 4),
+//!     (Some(4), 5),
+//!     (Some(5), 6),
+//! ]));
+//! ```
+//!
+//! [^1]: Peter E. Hart, Nils J. Nilsson, and Bertram Raphael. 1968. A Formal
+//!   Basis for the Heuristic Determination of Minimum Cost Paths. IEEE
+//!   Transactions on Systems Science and Cybernetics 4, 2 (July 1968),
+//!   100–107. <https://doi.org/10.1109/TSSC.1968.300136>
+
+use {
+    crate::{
+        Order,
+        OutNeighborsWeighted,
+        PredecessorTree,
+    },
+    core::cmp::Reverse,
+    std::{
+        collections::BinaryHeap,
+        ops::Add,
+    },
+};
+
+type Step = (Option<usize>, usize);
+
+/// The A* algorithm.
+///
+/// # Examples
+///
+/// The heuristic is the exact distance to vertex `6`, so the algorithm only
+/// visits the vertices on the shortest path.
+///
+/// ```
+/// use {
+///     graaf::{
+///         AStar,
+///         AddArcWeighted,
+///         AdjacencyListWeighted,
+///         Empty,
+///     },
+///     std::iter::once,
+/// };
+///
+/// let mut digraph = AdjacencyListWeighted::<usize>::empty(7);
+///
+/// digraph.add_arc_weighted(0, 1, 1);
+/// digraph.add_arc_weighted(1, 2, 1);
+/// digraph.add_arc_weighted(1, 6, 6);
+/// digraph.add_arc_weighted(2, 4, 1);
+/// digraph.add_arc_weighted(3, 0, 2);
+/// digraph.add_arc_weighted(4, 5, 2);
+/// digraph.add_arc_weighted(5, 6, 1);
+///
+/// let heuristic = [6, 5, 4, 8, 3, 1, 0];
+/// let mut a_star = AStar::new(&digraph, once(0), |v| heuristic[v]);
+///
+/// assert!(a_star.eq([
+///     (None, 0),
+///     (Some(0), 1),
+///     (Some(1), 2),
+///     (Some(2), 4),
+///     (Some(4), 5),
+///     (Some(5), 6),
+/// ]));
+/// ```
+#[derive(Clone, Debug)]
+pub struct AStar<'a, D, W, H> {
+    digraph: &'a D,
+    dist: Vec<Option<W>>,
+    heap: BinaryHeap<(Reverse<W>, W, Step)>,
+    heuristic: H,
+}
+
+impl<'a, D, W, H> AStar<'a, D, W, H>
+where
+    D: Order,
+    W: Copy + Ord,
+    H: Fn(usize) -> W,
+{
+    /// Initialize the A* algorithm.
+    ///
+    /// # Arguments
+    ///
+    /// * `digraph`: The digraph.
+    /// * `sources`: The source vertices.
+    /// * `heuristic`: A consistent estimate of the distance from a vertex to
+    ///   the nearest target vertex.
+    ///
+    /// # Panics
+    ///
+    /// * Panics if a source vertex isn't in the digraph.
+    /// * Panics if `heuristic` panics.
+    #[must_use]
+    pub fn new<T>(digraph: &'a D, sources: T, heuristic: H) -> Self
+    where
+        T: Iterator<Item = usize>,
+        W: Default,
+    {
+        let order = digraph.order();
+        let mut heap = BinaryHeap::with_capacity(order);
+        let mut dist = vec![None; order];
+
+        for u in sources {
+            let zero = W::default();
+
+            // Each distinct source is settled once.
+            if dist[u] == Some(zero) {
+                continue;
+            }
+
+            dist[u] = Some(zero);
+            heap.push((Reverse(heuristic(u)), zero, (None, u)));
+        }
+
+        Self {
+            digraph,
+            dist,
+            heap,
+            heuristic,
+        }
+    }
+
+    /// Find the shortest path from the source vertices to a target vertex.
+    ///
+    /// # Arguments
+    ///
+    /// * `is_target`: The function determining if the vertex is a target.
+    ///
+    /// # Returns
+    ///
+    /// If a target vertex is reachable, the function returns the shortest
+    /// path to the nearest target vertex and the path's weight. Otherwise,
+    /// it returns `None`.
+    ///
+    /// # Panics
+    ///
+    /// * Panics if `is_target` panics.
+    /// * Panics if `heuristic` panics.
+    /// * Panics if a successor vertex isn't in the digraph.
+    /// * Panics in debug builds if an arc weight is negative.
+    /// * Panics in debug builds if `heuristic` isn't consistent.
+    ///
+    /// # Examples
+    ///
+    /// The vertices are the cells of a three-by-three grid. The heuristic is
+    /// the Manhattan distance to the bottom-right cell.
+    ///
+    /// ```
+    /// use {
+    ///     graaf::{
+    ///         AStar,
+    ///         AddArcWeighted,
+    ///         AdjacencyListWeighted,
+    ///         Empty,
+    ///     },
+    ///     std::iter::once,
+    /// };
+    ///
+    /// let mut digraph = AdjacencyListWeighted::<usize>::empty(9);
+    ///
+    /// for u in 0..9 {
+    ///     if u % 3 < 2 {
+    ///         digraph.add_arc_weighted(u, u + 1, 1);
+    ///         digraph.add_arc_weighted(u + 1, u, 1);
+    ///     }
+    ///
+    ///     if u < 6 {
+    ///         digraph.add_arc_weighted(u, u + 3, 2);
+    ///         digraph.add_arc_weighted(u + 3, u, 2);
+    ///     }
+    /// }
+    ///
+    /// let heuristic = |v: usize| (2 - v % 3) + (2 - v / 3);
+    /// let mut a_star = AStar::new(&digraph, once(0), heuristic);
+    ///
+    /// assert_eq!(
+    ///     a_star.shortest_path(|v| v == 8),
+    ///     Some((vec![0, 1, 2, 5, 8], 6))
+    /// );
+    /// ```
+    #[must_use]
+    pub fn shortest_path<P>(&mut self, is_target: P) -> Option<(Vec<usize>, W)>
+    where
+        D: OutNeighborsWeighted<Weight = W>,
+        P: Fn(usize) -> bool,
+        W: Add<Output = W> + Default,
+    {
+        let mut pred = PredecessorTree::new(self.digraph.order());
+
+        while let Some((u, v)) = self.next() {
+            pred[v] = u;
+
+            if is_target(v) {
+                debug_assert!(
+                    (self.heuristic)(v) == W::default(),
+                    "the heuristic is zero at a target vertex"
+                );
+
+                let mut path = pred.search_by(v, |_, b| b.is_none())?;
+
+                path.reverse();
+
+                return Some((path, self.dist[v]?));
+            }
+        }
+
+        None
+    }
+}
+
+impl<D, W, H> Iterator for AStar<'_, D, W, H>
+where
+    D: Order + OutNeighborsWeighted<Weight = W>,
+    W: Add<Output = W> + Copy + Default + Ord,
+    H: Fn(usize) -> W,
+{
+    type Item = Step;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let (_, distance, step @ (_, v)) = self.heap.pop()?;
+
+            if self.dist[v] != Some(distance) {
+                continue;
+            }
+
+            let h_v = (self.heuristic)(v);
+
+            for (x, &w) in self.digraph.out_neighbors_weighted(v) {
+                let h_x = (self.heuristic)(x);
+
+                debug_assert!(
+                    w >= W::default(),
+                    "arc ({v}, {x}) has a negative weight"
+                );
+
+                debug_assert!(
+                    h_v <= w + h_x,
+                    "the heuristic isn't consistent on arc ({v}, {x})"
+                );
+
+                let distance = distance + w;
+
+                if self.dist[x].is_none_or(|d| distance < d) {
+                    self.dist[x] = Some(distance);
+                    self.heap.push((
+                        Reverse(distance + h_x),
+                        distance,
+                        (Some(v), x),
+                    ));
+                }
+            }
+
+            return Some(step);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::*,
+        crate::{
+            AddArcWeighted,
+            AdjacencyListWeighted,
+            ArcsWeighted,
+            DijkstraDist,
+            Empty,
+            proptest_strategy::digraph_weighted,
+            repr::adjacency_list_weighted::fixture::{
+                bang_jensen_94_usize,
+                bang_jensen_96_usize,
+                kattis_bryr_1_usize,
+                kattis_bryr_2_usize,
+                kattis_bryr_3_usize,
+                kattis_crosscountry_usize,
+                kattis_shortestpath1_usize,
+            },
+        },
+        proptest::prelude::*,
+        std::iter::once,
+    };
+
+    fn grid(n: usize) -> AdjacencyListWeighted<usize> {
+        let mut digraph = AdjacencyListWeighted::empty(n * n);
+
+        for u in 0..n * n {
+            if u % n < n - 1 {
+                digraph.add_arc_weighted(u, u + 1, 1);
+                digraph.add_arc_weighted(u + 1, u, 1);
+            }
+
+            if u < n * (n - 1) {
+                digraph.add_arc_weighted(u, u + n, 1);
+                digraph.add_arc_weighted(u + n, u, 1);
+            }
+        }
+
+        digraph
+    }
+
+    #[test]
+    fn iter_duplicate_sources() {
+        let digraph = kattis_shortestpath1_usize();
+
+        assert!(AStar::new(&digraph, [0, 0].into_iter(), |_| 0).eq([
+            (None, 0),
+            (Some(0), 1),
+            (Some(1), 2),
+        ]));
+    }
+
+    #[test]
+    fn iter_grid() {
+        let digraph = grid(5);
+        let heuristic = |v: usize| (4 - v % 5) + (4 - v / 5);
+
+        assert_eq!(
+            AStar::new(&digraph, once(0), heuristic)
+                .position(|(_, v)| v == 24),
+            Some(8)
+        );
+
+        assert_eq!(
+            AStar::new(&digraph, once(0), |_| 0).position(|(_, v)| v == 24),
+            Some(24)
+        );
+    }
+
+    #[test]
+    fn iter_kattis_shortestpath1() {
+        let digraph = kattis_shortestpath1_usize();
+
+        assert!(AStar::new(&digraph, once(0), |_| 0).eq([
+            (None, 0),
+            (Some(0), 1),
+            (Some(1), 2),
+        ]));
+    }
+
+    #[test]
+    fn iter_stale_entry() {
+        let mut digraph = AdjacencyListWeighted::<usize>::empty(4);
+
+        digraph.add_arc_weighted(0, 1, 10);
+        digraph.add_arc_weighted(0, 2, 1);
+        digraph.add_arc_weighted(1, 3, 20);
+        digraph.add_arc_weighted(2, 1, 1);
+
+        assert!(AStar::new(&digraph, once(0), |_| 0).eq([
+            (None, 0),
+            (Some(0), 2),
+            (Some(2), 1),
+            (Some(1), 3),
+        ]));
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic(expected = "the heuristic isn't consistent on arc (0, 1)")]
+    fn iter_inconsistent() {
+        let digraph = kattis_bryr_1_usize();
+
+        let _ = AStar::new(&digraph, once(0), |v| [5, 0, 0][v]).count();
+    }
+
+    #[test]
+    fn shortest_path_bang_jensen_94() {
+        let digraph = bang_jensen_94_usize();
+
+        assert_eq!(
+            AStar::new(&digraph, once(0), |_| 0).shortest_path(|v| v == 6),
+            Some((vec![0, 2, 4, 6], 3))
+        );
+    }
+
+    #[test]
+    fn shortest_path_bang_jensen_96() {
+        let digraph = bang_jensen_96_usize();
+
+        assert_eq!(
+            AStar::new(&digraph, once(0), |_| 0).shortest_path(|v| v == 5),
+            Some((vec![0, 2, 4, 3, 5], 7))
+        );
+    }
+
+    #[test]
+    fn shortest_path_grid() {
+        let digraph = grid(5);
+        let heuristic = |v: usize| (4 - v % 5) + (4 - v / 5);
+        let (path, weight) = AStar::new(&digraph, once(0), heuristic)
+            .shortest_path(|v| v == 24)
+            .unwrap();
+
+        assert_eq!(path.len(), 9);
+        assert_eq!(weight, 8);
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic(expected = "the heuristic is zero at a target vertex")]
+    fn shortest_path_heuristic_nonzero_at_target() {
+        let digraph = kattis_bryr_1_usize();
+
+        drop(AStar::new(&digraph, once(0), |_| 1).shortest_path(|v| v == 2));
+    }
+
+    #[test]
+    fn shortest_path_kattis_bryr_1() {
+        let digraph = kattis_bryr_1_usize();
+
+        assert_eq!(
+            AStar::new(&digraph, once(0), |_| 0).shortest_path(|v| v == 2),
+            Some((vec![0, 2], 1))
+        );
+    }
+
+    #[test]
+    fn shortest_path_kattis_bryr_2() {
+        let digraph = kattis_bryr_2_usize();
+
+        assert_eq!(
+            AStar::new(&digraph, once(0), |_| 0).shortest_path(|v| v == 5),
+            Some((vec![0, 3, 4, 5], 3))
+        );
+    }
+
+    #[test]
+    fn shortest_path_kattis_bryr_3() {
+        let digraph = kattis_bryr_3_usize();
+
+        assert_eq!(
+            AStar::new(&digraph, once(0), |_| 0).shortest_path(|v| v == 9),
+            Some((vec![0, 3, 7, 1, 9], 1))
+        );
+    }
+
+    #[test]
+    fn shortest_path_kattis_crosscountry() {
+        let digraph = kattis_crosscountry_usize();
+
+        assert_eq!(
+            AStar::new(&digraph, once(0), |_| 0).shortest_path(|v| v == 2),
+            Some((vec![0, 2], 3))
+        );
+    }
+
+    #[test]
+    fn shortest_path_kattis_shortestpath1() {
+        let digraph = kattis_shortestpath1_usize();
+
+        assert_eq!(
+            AStar::new(&digraph, once(0), |_| 0).shortest_path(|v| v == 3),
+            None
+        );
+    }
+
+    proptest! {
+        #[test]
+        fn shortest_path_eq_dijkstra_dist(
+            order in 1..8_usize,
+            arcs in prop::collection::vec(
+                (0..8_usize, 0..8_usize, 0..10_usize),
+                0..24,
+            ),
+            target in 0..8_usize,
+        ) {
+            let digraph = digraph_weighted(order, &arcs);
+            let target = target % order;
+            let dist = DijkstraDist::new(&digraph, once(0)).distances();
+
+            // The distances to the target form a consistent heuristic.
+            let mut reversed = AdjacencyListWeighted::empty(order);
+
+            for (u, v, &w) in digraph.arcs_weighted() {
+                reversed.add_arc_weighted(v, u, w);
+            }
+
+            let to_target =
+                DijkstraDist::new(&reversed, once(target)).distances();
+
+            let heuristic = |v: usize| {
+                if to_target[v] == usize::MAX { 1000 } else { to_target[v] }
+            };
+
+            let a_star = AStar::new(&digraph, once(0), |_| 0)
+                .shortest_path(|v| v == target);
+
+            let informed = AStar::new(&digraph, once(0), heuristic)
+                .shortest_path(|v| v == target);
+
+            if dist[target] == usize::MAX {
+                assert_eq!(a_star, None);
+                assert_eq!(informed, None);
+            } else {
+                assert_eq!(a_star.unwrap().1, dist[target]);
+                assert_eq!(informed.unwrap().1, dist[target]);
+            }
+        }
+    }
+}
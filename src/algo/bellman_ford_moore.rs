@@ -63,15 +63,32 @@
 //! digraph.add_arc_weighted(1, 2, -1);
 //! digraph.add_arc_weighted(2, 0, -1);
 //!
-//! assert_eq!(BellmanFordMoore::new(&digraph, 0).distances(), None);
+//! let circuit = BellmanFordMoore::new(&digraph, 0).distances().unwrap_err();
+//!
+//! assert!(circuit.circuit.eq(&[0, 1, 2]));
+//! assert_eq!(circuit.weight, -4);
 //! ```
 #![doc(alias = "bellman_ford")]
 
 use crate::{
     ArcsWeighted,
     ContiguousOrder,
+    PredecessorTree,
 };
 
+/// A negative circuit.
+///
+/// A negative circuit is a circuit whose total weight is negative. There are
+/// no shortest paths through a negative circuit.
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[non_exhaustive]
+pub struct NegativeCircuit {
+    /// The circuit's vertices, starting at its smallest vertex.
+    pub circuit: Vec<usize>,
+    /// The circuit's total weight.
+    pub weight: isize,
+}
+
 /// Find the shortest distances from a source vertex to all other vertices in
 /// an arc-weighted digraph with negative weights.
 ///
@@ -82,8 +99,9 @@ use crate::{
 ///
 /// # Returns
 ///
-/// The distances from the source vertex to all other vertices. Returns `None`
-/// if the digraph contains a negative circuit.
+/// The distances from the source vertex to all other vertices. Returns the
+/// [`NegativeCircuit`] if the digraph contains a negative circuit reachable
+/// from the source vertex.
 ///
 /// # Examples
 ///
@@ -142,12 +160,16 @@ use crate::{
 /// digraph.add_arc_weighted(1, 2, -1);
 /// digraph.add_arc_weighted(2, 0, -1);
 ///
-/// assert_eq!(BellmanFordMoore::new(&digraph, 0).distances(), None);
+/// let circuit = BellmanFordMoore::new(&digraph, 0).distances().unwrap_err();
+///
+/// assert!(circuit.circuit.eq(&[0, 1, 2]));
+/// assert_eq!(circuit.weight, -4);
 /// ```
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct BellmanFordMoore<'a, D> {
     digraph: &'a D,
     dist: Vec<isize>,
+    pred: Vec<Option<usize>>,
 }
 
 impl<'a, D> BellmanFordMoore<'a, D> {
@@ -177,81 +199,16 @@ impl<'a, D> BellmanFordMoore<'a, D> {
             *dist_ptr.add(s) = 0;
         }
 
-        Self { digraph, dist }
+        Self {
+            digraph,
+            dist,
+            pred: vec![None; order],
+        }
     }
 
-    /// Find the shortest distances from a source vertex to all other vertices
-    /// in an arc-weighted digraph with negative weights.
-    ///
-    /// # Returns
-    ///
-    /// The distances from the source vertex to all other vertices. Returns
-    /// `None` if the digraph contains a negative circuit.
-    ///
-    /// # Examples
-    ///
-    /// ## Shortest distances
-    ///
-    /// The shortest path from vertex `0` to `4` is red. The dashed arcs
-    /// represent the other shortest distances.
-    ///
-    /// ![A digraph and the shortest distances between the source vertex and the other vertices](https://raw.githubusercontent.com/bsdrks/graaf-images/main/out/bellman_ford_moore_1-0.87.4.svg?)
-    ///
-    /// ```
-    /// use graaf::{
-    ///     AddArcWeighted,
-    ///     AdjacencyListWeighted,
-    ///     BellmanFordMoore,
-    ///     Empty,
-    /// };
-    ///
-    /// let mut digraph = AdjacencyListWeighted::<isize>::empty(6);
-    ///
-    /// digraph.add_arc_weighted(0, 1, 8);
-    /// digraph.add_arc_weighted(0, 2, 4);
-    /// digraph.add_arc_weighted(1, 2, -5);
-    /// digraph.add_arc_weighted(2, 3, -2);
-    /// digraph.add_arc_weighted(2, 4, 4);
-    /// digraph.add_arc_weighted(3, 5, -2);
-    /// digraph.add_arc_weighted(4, 3, 10);
-    /// digraph.add_arc_weighted(4, 5, 9);
-    /// digraph.add_arc_weighted(5, 3, 5);
-    /// digraph.add_arc_weighted(5, 4, -3);
-    ///
-    /// let mut bellman_ford_moore = BellmanFordMoore::new(&digraph, 0);
-    /// let dist = bellman_ford_moore.distances();
-    ///
-    /// assert!(dist.unwrap().eq(&[0, 8, 3, 1, -4, -1]));
-    /// ```
-    ///
-    /// ## Negative cycle
-    ///
-    /// There is no shortest path between vertices `0` and the other vertices
-    /// due to the negative cycle.
-    ///
-    /// ![A digraph with a negative cycle](https://raw.githubusercontent.com/bsdrks/graaf-images/main/out/bellman_ford_moore_2-0.87.4.svg)
-    ///
-    /// ```
-    /// use graaf::{
-    ///     AddArcWeighted,
-    ///     AdjacencyListWeighted,
-    ///     BellmanFordMoore,
-    ///     Empty,
-    /// };
-    ///
-    /// let mut digraph = AdjacencyListWeighted::empty(3);
-    ///
-    /// digraph.add_arc_weighted(0, 1, -2);
-    /// digraph.add_arc_weighted(1, 2, -1);
-    /// digraph.add_arc_weighted(2, 0, -1);
-    ///
-    /// let mut bellman_ford_moore = BellmanFordMoore::new(&digraph, 0);
-    /// let dist = bellman_ford_moore.distances();
-    ///
-    /// assert_eq!(dist, None);
-    /// ```
-    #[must_use]
-    pub fn distances(&mut self) -> Option<&[isize]>
+    /// Relax the arcs until the distances converge or a negative circuit is
+    /// found.
+    fn search(&mut self) -> Result<(), NegativeCircuit>
     where
         D: ArcsWeighted<Weight = isize> + ContiguousOrder,
     {
@@ -259,27 +216,38 @@ impl<'a, D> BellmanFordMoore<'a, D> {
         let arcs = self.digraph.arcs_weighted().collect::<Vec<_>>();
         let arcs_len = arcs.len();
         let arcs_ptr = arcs.as_ptr();
+        let mut weight = vec![0; order];
+        let mut relaxed = None;
 
-        for _ in 1..order {
-            let mut updated = false;
+        // The first `order - 1` passes converge unless there is a negative
+        // circuit. A relaxation in the last pass proves there is one.
+        for _ in 0..order {
             let dist_ptr = self.dist.as_mut_ptr();
+            let pred_ptr = self.pred.as_mut_ptr();
+            let weight_ptr = weight.as_mut_ptr();
             let mut i = 0;
 
+            relaxed = None;
+
             while i < arcs_len {
                 unsafe {
                     {
                         // Safety: `i` is always less than `arcs_len`, so `i`
-                        // is a valid index.
-                        let (u, v, w) = *arcs_ptr.add(i);
+                        // is a valid index. `u` and `v` are less than
+                        // `order`, the length of `dist`, `pred`, and
+                        // `weight`.
+                        let (u, v, &w) = *arcs_ptr.add(i);
                         let dist_u = *dist_ptr.add(u);
 
                         if dist_u != isize::MAX {
-                            let w = dist_u + w;
+                            let w_v = dist_u + w;
                             let dist_v = dist_ptr.add(v);
 
-                            if *dist_v > w {
-                                *dist_v = w;
-                                updated = true;
+                            if *dist_v > w_v {
+                                *dist_v = w_v;
+                                *pred_ptr.add(v) = Some(u);
+                                *weight_ptr.add(v) = w;
+                                relaxed = Some(v);
                             }
                         }
                     }
@@ -287,16 +255,18 @@ impl<'a, D> BellmanFordMoore<'a, D> {
                     i += 1;
 
                     if i < arcs_len {
-                        let (u, v, w) = *arcs_ptr.add(i);
+                        let (u, v, &w) = *arcs_ptr.add(i);
                         let dist_u = *dist_ptr.add(u);
 
                         if dist_u != isize::MAX {
-                            let w = dist_u + w;
+                            let w_v = dist_u + w;
                             let dist_v = dist_ptr.add(v);
 
-                            if *dist_v > w {
-                                *dist_v = w;
-                                updated = true;
+                            if *dist_v > w_v {
+                                *dist_v = w_v;
+                                *pred_ptr.add(v) = Some(u);
+                                *weight_ptr.add(v) = w;
+                                relaxed = Some(v);
                             }
                         }
                     }
@@ -304,16 +274,18 @@ impl<'a, D> BellmanFordMoore<'a, D> {
                     i += 1;
 
                     if i < arcs_len {
-                        let (u, v, w) = *arcs_ptr.add(i);
+                        let (u, v, &w) = *arcs_ptr.add(i);
                         let dist_u = *dist_ptr.add(u);
 
                         if dist_u != isize::MAX {
-                            let w = dist_u + w;
+                            let w_v = dist_u + w;
                             let dist_v = dist_ptr.add(v);
 
-                            if *dist_v > w {
-                                *dist_v = w;
-                                updated = true;
+                            if *dist_v > w_v {
+                                *dist_v = w_v;
+                                *pred_ptr.add(v) = Some(u);
+                                *weight_ptr.add(v) = w;
+                                relaxed = Some(v);
                             }
                         }
                     }
@@ -321,16 +293,18 @@ impl<'a, D> BellmanFordMoore<'a, D> {
                     i += 1;
 
                     if i < arcs_len {
-                        let (u, v, w) = *arcs_ptr.add(i);
+                        let (u, v, &w) = *arcs_ptr.add(i);
                         let dist_u = *dist_ptr.add(u);
 
                         if dist_u != isize::MAX {
-                            let w = dist_u + w;
+                            let w_v = dist_u + w;
                             let dist_v = dist_ptr.add(v);
 
-                            if *dist_v > w {
-                                *dist_v = w;
-                                updated = true;
+                            if *dist_v > w_v {
+                                *dist_v = w_v;
+                                *pred_ptr.add(v) = Some(u);
+                                *weight_ptr.add(v) = w;
+                                relaxed = Some(v);
                             }
                         }
                     }
@@ -339,27 +313,181 @@ impl<'a, D> BellmanFordMoore<'a, D> {
                 i += 1;
             }
 
-            if !updated {
-                break;
+            if relaxed.is_none() {
+                return Ok(());
             }
         }
 
-        {
-            let dist_ptr = self.dist.as_mut_ptr();
+        relaxed.map_or(Ok(()), |v| Err(self.negative_circuit(v, &weight)))
+    }
 
-            for i in 0..arcs_len {
-                unsafe {
-                    let (u, v, w) = *arcs_ptr.add(i);
-                    let dist_u = *dist_ptr.add(u);
+    /// Trace the negative circuit through the last relaxed vertex `v`.
+    fn negative_circuit(&self, v: usize, weight: &[isize]) -> NegativeCircuit {
+        let mut start = v;
 
-                    if dist_u != isize::MAX && *dist_ptr.add(v) > dist_u + w {
-                        return None;
-                    }
-                }
+        // Walking back `order` predecessors from the last relaxed vertex
+        // lands on the circuit.
+        for _ in 0..self.pred.len() {
+            if let Some(u) = self.pred[start] {
+                start = u;
+            }
+        }
+
+        let mut circuit = vec![start];
+        let mut total = weight[start];
+        let mut v = start;
+
+        while let Some(u) = self.pred[v] {
+            if u == start {
+                break;
             }
+
+            circuit.push(u);
+            total += weight[u];
+            v = u;
         }
 
-        Some(&self.dist[..])
+        circuit.reverse();
+
+        if let Some(i) = (0..circuit.len()).min_by_key(|&i| circuit[i]) {
+            circuit.rotate_left(i);
+        }
+
+        NegativeCircuit {
+            circuit,
+            weight: total,
+        }
+    }
+
+    /// Find the shortest distances from a source vertex to all other vertices
+    /// in an arc-weighted digraph with negative weights.
+    ///
+    /// # Returns
+    ///
+    /// The distances from the source vertex to all other vertices.
+    ///
+    /// # Errors
+    ///
+    /// Returns the [`NegativeCircuit`] if the digraph contains a negative
+    /// circuit reachable from the source vertex.
+    ///
+    /// # Examples
+    ///
+    /// ## Shortest distances
+    ///
+    /// The shortest path from vertex `0` to `4` is red. The dashed arcs
+    /// represent the other shortest distances.
+    ///
+    /// ![A digraph and the shortest distances between the source vertex and the other vertices](https://raw.githubusercontent.com/bsdrks/graaf-images/main/out/bellman_ford_moore_1-0.87.4.svg?)
+    ///
+    /// ```
+    /// use graaf::{
+    ///     AddArcWeighted,
+    ///     AdjacencyListWeighted,
+    ///     BellmanFordMoore,
+    ///     Empty,
+    /// };
+    ///
+    /// let mut digraph = AdjacencyListWeighted::<isize>::empty(6);
+    ///
+    /// digraph.add_arc_weighted(0, 1, 8);
+    /// digraph.add_arc_weighted(0, 2, 4);
+    /// digraph.add_arc_weighted(1, 2, -5);
+    /// digraph.add_arc_weighted(2, 3, -2);
+    /// digraph.add_arc_weighted(2, 4, 4);
+    /// digraph.add_arc_weighted(3, 5, -2);
+    /// digraph.add_arc_weighted(4, 3, 10);
+    /// digraph.add_arc_weighted(4, 5, 9);
+    /// digraph.add_arc_weighted(5, 3, 5);
+    /// digraph.add_arc_weighted(5, 4, -3);
+    ///
+    /// let mut bellman_ford_moore = BellmanFordMoore::new(&digraph, 0);
+    /// let dist = bellman_ford_moore.distances();
+    ///
+    /// assert!(dist.unwrap().eq(&[0, 8, 3, 1, -4, -1]));
+    /// ```
+    ///
+    /// ## Negative cycle
+    ///
+    /// There is no shortest path between vertices `0` and the other vertices
+    /// due to the negative cycle.
+    ///
+    /// ![A digraph with a negative cycle](https://raw.githubusercontent.com/bsdrks/graaf-images/main/out/bellman_ford_moore_2-0.87.4.svg)
+    ///
+    /// ```
+    /// use graaf::{
+    ///     AddArcWeighted,
+    ///     AdjacencyListWeighted,
+    ///     BellmanFordMoore,
+    ///     Empty,
+    /// };
+    ///
+    /// let mut digraph = AdjacencyListWeighted::empty(3);
+    ///
+    /// digraph.add_arc_weighted(0, 1, -2);
+    /// digraph.add_arc_weighted(1, 2, -1);
+    /// digraph.add_arc_weighted(2, 0, -1);
+    ///
+    /// let mut bellman_ford_moore = BellmanFordMoore::new(&digraph, 0);
+    /// let circuit = bellman_ford_moore.distances().unwrap_err();
+    ///
+    /// assert!(circuit.circuit.eq(&[0, 1, 2]));
+    /// assert_eq!(circuit.weight, -4);
+    /// ```
+    pub fn distances(&mut self) -> Result<&[isize], NegativeCircuit>
+    where
+        D: ArcsWeighted<Weight = isize> + ContiguousOrder,
+    {
+        self.search()?;
+
+        Ok(&self.dist)
+    }
+
+    /// Find the predecessors on the shortest paths from the source vertex.
+    ///
+    /// # Returns
+    ///
+    /// The [`PredecessorTree`] of the shortest paths from the source vertex.
+    ///
+    /// # Errors
+    ///
+    /// Returns the [`NegativeCircuit`] if the digraph contains a negative
+    /// circuit reachable from the source vertex.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use graaf::{
+    ///     AddArcWeighted,
+    ///     AdjacencyListWeighted,
+    ///     BellmanFordMoore,
+    ///     Empty,
+    /// };
+    ///
+    /// let mut digraph = AdjacencyListWeighted::<isize>::empty(6);
+    ///
+    /// digraph.add_arc_weighted(0, 1, 8);
+    /// digraph.add_arc_weighted(0, 2, 4);
+    /// digraph.add_arc_weighted(1, 2, -5);
+    /// digraph.add_arc_weighted(2, 3, -2);
+    /// digraph.add_arc_weighted(2, 4, 4);
+    /// digraph.add_arc_weighted(3, 5, -2);
+    /// digraph.add_arc_weighted(4, 3, 10);
+    /// digraph.add_arc_weighted(4, 5, 9);
+    /// digraph.add_arc_weighted(5, 3, 5);
+    /// digraph.add_arc_weighted(5, 4, -3);
+    ///
+    /// let pred = BellmanFordMoore::new(&digraph, 0).predecessors().unwrap();
+    ///
+    /// assert_eq!(pred.search(4, 0), Some(vec![4, 5, 3, 2, 1, 0]));
+    /// ```
+    pub fn predecessors(&mut self) -> Result<PredecessorTree, NegativeCircuit>
+    where
+        D: ArcsWeighted<Weight = isize> + ContiguousOrder,
+    {
+        self.search()?;
+
+        Ok(PredecessorTree::from(self.pred.clone()))
     }
 }
 
@@ -683,7 +811,26 @@ mod tests {
     fn distances_kattis_shortestpath3() {
         assert_eq!(
             BellmanFordMoore::new(&kattis_shortestpath3(), 0).distances(),
-            None
+            Err(NegativeCircuit {
+                circuit: vec![1, 2],
+                weight: -1
+            })
+        );
+    }
+
+    #[test]
+    fn distances_unreachable_negative_circuit() {
+        let mut digraph = AdjacencyListWeighted::<isize>::empty(4);
+
+        digraph.add_arc_weighted(0, 1, 2);
+        digraph.add_arc_weighted(2, 3, -2);
+        digraph.add_arc_weighted(3, 2, -1);
+
+        assert!(
+            BellmanFordMoore::new(&digraph, 0)
+                .distances()
+                .unwrap()
+                .eq(&[0, 2, isize::MAX, isize::MAX])
         );
     }
 
@@ -695,6 +842,79 @@ mod tests {
         digraph.add_arc_weighted(1, 2, -1);
         digraph.add_arc_weighted(2, 0, -1);
 
-        assert_eq!(BellmanFordMoore::new(&digraph, 0).distances(), None);
+        assert_eq!(
+            BellmanFordMoore::new(&digraph, 0).distances(),
+            Err(NegativeCircuit {
+                circuit: vec![0, 1, 2],
+                weight: -4
+            })
+        );
+    }
+
+    #[test]
+    fn negative_circuit_behind_path() {
+        let mut digraph = AdjacencyListWeighted::<isize>::empty(6);
+
+        digraph.add_arc_weighted(0, 1, 1);
+        digraph.add_arc_weighted(1, 2, 1);
+        digraph.add_arc_weighted(2, 3, 1);
+        digraph.add_arc_weighted(3, 4, -3);
+        digraph.add_arc_weighted(4, 5, 1);
+        digraph.add_arc_weighted(5, 3, 1);
+
+        assert_eq!(
+            BellmanFordMoore::new(&digraph, 0).predecessors(),
+            Err(NegativeCircuit {
+                circuit: vec![3, 4, 5],
+                weight: -1
+            })
+        );
+    }
+
+    #[test]
+    fn predecessors_bang_jensen_99() {
+        assert!(
+            BellmanFordMoore::new(&bang_jensen_99(), 0)
+                .predecessors()
+                .unwrap()
+                .into_iter()
+                .eq([None, Some(0), Some(0), Some(2), Some(5), Some(3)])
+        );
+    }
+
+    #[test]
+    fn predecessors_kattis_shortestpath1() {
+        assert!(
+            BellmanFordMoore::new(&kattis_shortestpath1_isize(), 0)
+                .predecessors()
+                .unwrap()
+                .into_iter()
+                .eq([None, Some(0), Some(1), None])
+        );
+    }
+
+    #[test]
+    fn predecessors_kattis_shortestpath3() {
+        assert_eq!(
+            BellmanFordMoore::new(&kattis_shortestpath3(), 0).predecessors(),
+            Err(NegativeCircuit {
+                circuit: vec![1, 2],
+                weight: -1
+            })
+        );
+    }
+
+    #[test]
+    fn predecessors_trivial() {
+        assert!(
+            BellmanFordMoore::new(
+                &AdjacencyListWeighted::<isize>::trivial(),
+                0
+            )
+            .predecessors()
+            .unwrap()
+            .into_iter()
+            .eq([None])
+        );
     }
 }
@@ -0,0 +1,333 @@
+//! Bidirectional breadth-first search.
+//!
+//! Bidirectional breadth-first search finds the shortest path between two
+//! vertices in an unweighted digraph.[^1] It searches forward from the source
+//! vertex over the digraph and backward from the target vertex over the
+//! digraph's converse, one layer at a time, and stops when the frontiers
+//! meet.
+//!
+//! The time complexity is `O(v + a)`, where `v` is the digraph's order and `a`
+//! is the digraph's size. In practice, the searches explore far fewer
+//! vertices than [`BfsPred::shortest_path`](crate::BfsPred::shortest_path).
+//!
+//! # Examples
+//!
+//! ```
+//! use graaf::{
+//!     AddArc,
+//!     AdjacencyList,
+//!     BidirectionalBfs,
+//!     Empty,
+//! };
+//!
+//! let mut digraph = AdjacencyList::empty(6);
+//!
+//! digraph.add_arc(0, 1);
+//! digraph.add_arc(1, 2);
+//! digraph.add_arc(1, 4);
+//! digraph.add_arc(2, 5);
+//! digraph.add_arc(3, 0);
+//! digraph.add_arc(4, 5);
+//!
+//! let bfs = BidirectionalBfs::new(&digraph);
+//!
+//! assert_eq!(bfs.shortest_path(3, 5), Some(vec![3, 0, 1, 2, 5]));
+//! assert_eq!(bfs.shortest_path(5, 3), None);
+//! ```
+//!
+//! [^1]: Ira Pohl. 1971. Bi-directional search. Machine Intelligence 6
+//!   (1971), 127–140.
+
+use crate::{
+    Converse,
+    Order,
+    OutNeighbors,
+    PredecessorTree,
+};
+
+/// Bidirectional breadth-first search.
+///
+/// # Examples
+///
+/// ```
+/// use graaf::{
+///     AddArc,
+///     AdjacencyList,
+///     BidirectionalBfs,
+///     Empty,
+/// };
+///
+/// let mut digraph = AdjacencyList::empty(6);
+///
+/// digraph.add_arc(0, 1);
+/// digraph.add_arc(1, 2);
+/// digraph.add_arc(1, 4);
+/// digraph.add_arc(2, 5);
+/// digraph.add_arc(3, 0);
+/// digraph.add_arc(4, 5);
+///
+/// let bfs = BidirectionalBfs::new(&digraph);
+///
+/// assert_eq!(bfs.shortest_path(3, 5), Some(vec![3, 0, 1, 2, 5]));
+/// assert_eq!(bfs.shortest_path(5, 3), None);
+/// ```
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct BidirectionalBfs<'a, D> {
+    digraph: &'a D,
+    converse: D,
+}
+
+/// One direction of the search.
+struct Search {
+    dist: Vec<usize>,
+    pred: PredecessorTree,
+    frontier: Vec<usize>,
+}
+
+impl Search {
+    fn new(order: usize, source: usize) -> Self {
+        let mut dist = vec![usize::MAX; order];
+
+        dist[source] = 0;
+
+        Self {
+            dist,
+            pred: PredecessorTree::new(order),
+            frontier: vec![source],
+        }
+    }
+
+    /// Expand the frontier by one layer and return the vertex where the
+    /// shortest path through the new layer meets the other search.
+    fn expand<D>(&mut self, digraph: &D, other: &Self) -> Option<usize>
+    where
+        D: OutNeighbors,
+    {
+        let mut frontier = Vec::new();
+        let mut meet = None;
+        let mut best = usize::MAX;
+
+        for u in self.frontier.drain(..) {
+            let distance = self.dist[u] + 1;
+
+            for v in digraph.out_neighbors(u) {
+                if self.dist[v] != usize::MAX {
+                    continue;
+                }
+
+                self.dist[v] = distance;
+                self.pred[v] = Some(u);
+                frontier.push(v);
+
+                if other.dist[v] != usize::MAX
+                    && distance + other.dist[v] < best
+                {
+                    best = distance + other.dist[v];
+                    meet = Some(v);
+                }
+            }
+        }
+
+        self.frontier = frontier;
+
+        meet
+    }
+}
+
+impl<'a, D> BidirectionalBfs<'a, D> {
+    /// Initialize bidirectional breadth-first search.
+    ///
+    /// # Arguments
+    ///
+    /// * `digraph`: The digraph.
+    #[must_use]
+    pub fn new(digraph: &'a D) -> Self
+    where
+        D: Converse,
+    {
+        Self {
+            digraph,
+            converse: digraph.converse(),
+        }
+    }
+
+    /// Find the shortest path from a source vertex to a target vertex.
+    ///
+    /// # Arguments
+    ///
+    /// * `s`: The source vertex.
+    /// * `t`: The target vertex.
+    ///
+    /// # Returns
+    ///
+    /// If `t` is reachable from `s`, the function returns the shortest path
+    /// from `s` to `t`. Otherwise, it returns `None`.
+    ///
+    /// # Panics
+    ///
+    /// * Panics if `s` isn't in the digraph.
+    /// * Panics if `t` isn't in the digraph.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use graaf::{
+    ///     AddArc,
+    ///     AdjacencyList,
+    ///     BidirectionalBfs,
+    ///     Empty,
+    /// };
+    ///
+    /// let mut digraph = AdjacencyList::empty(4);
+    ///
+    /// digraph.add_arc(0, 1);
+    /// digraph.add_arc(1, 2);
+    /// digraph.add_arc(2, 3);
+    /// digraph.add_arc(3, 0);
+    ///
+    /// let bfs = BidirectionalBfs::new(&digraph);
+    ///
+    /// assert_eq!(bfs.shortest_path(1, 0), Some(vec![1, 2, 3, 0]));
+    /// assert_eq!(bfs.shortest_path(2, 2), Some(vec![2]));
+    /// ```
+    #[must_use]
+    pub fn shortest_path(&self, s: usize, t: usize) -> Option<Vec<usize>>
+    where
+        D: Order + OutNeighbors,
+    {
+        let order = self.digraph.order();
+
+        assert!(s < order, "s = {s} isn't in the digraph");
+        assert!(t < order, "t = {t} isn't in the digraph");
+
+        if s == t {
+            return Some(vec![s]);
+        }
+
+        let mut forward = Search::new(order, s);
+        let mut backward = Search::new(order, t);
+
+        loop {
+            if forward.frontier.is_empty() || backward.frontier.is_empty() {
+                return None;
+            }
+
+            let meet = if forward.frontier.len() <= backward.frontier.len() {
+                forward.expand(self.digraph, &backward)
+            } else {
+                backward.expand(&self.converse, &forward)
+            };
+
+            if let Some(v) = meet {
+                let mut path =
+                    forward.pred.search_by(v, |_, u| u.is_none())?;
+
+                path.reverse();
+                path.extend(
+                    backward
+                        .pred
+                        .search_by(v, |_, u| u.is_none())?
+                        .into_iter()
+                        .skip(1),
+                );
+
+                return Some(path);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::*,
+        crate::{
+            BfsPred,
+            HasArc,
+            proptest_strategy::digraph,
+            repr::adjacency_list::fixture::{
+                bang_jensen_34,
+                bang_jensen_94,
+                bang_jensen_196,
+                kattis_builddeps,
+                kattis_escapewallmaria_1,
+            },
+        },
+        proptest::prelude::*,
+        std::iter::once,
+    };
+
+    #[test]
+    fn shortest_path_bang_jensen_196() {
+        let digraph = bang_jensen_196();
+
+        assert_eq!(
+            BidirectionalBfs::new(&digraph).shortest_path(0, 6),
+            Some(vec![0, 7, 5, 6])
+        );
+    }
+
+    #[test]
+    fn shortest_path_bang_jensen_34() {
+        let digraph = bang_jensen_34();
+
+        assert_eq!(
+            BidirectionalBfs::new(&digraph).shortest_path(0, 5),
+            BfsPred::new(&digraph, once(0)).shortest_path(|v| v == 5)
+        );
+    }
+
+    #[test]
+    fn shortest_path_bang_jensen_94() {
+        let digraph = bang_jensen_94();
+
+        assert_eq!(
+            BidirectionalBfs::new(&digraph).shortest_path(0, 6),
+            Some(vec![0, 2, 4, 6])
+        );
+    }
+
+    #[test]
+    fn shortest_path_kattis_builddeps() {
+        let digraph = kattis_builddeps();
+        let bfs = BidirectionalBfs::new(&digraph);
+
+        assert_eq!(bfs.shortest_path(0, 5), None);
+        assert_eq!(bfs.shortest_path(2, 2), Some(vec![2]));
+    }
+
+    #[test]
+    #[should_panic(expected = "s = 16 isn't in the digraph")]
+    fn shortest_path_out_of_bounds() {
+        let digraph = kattis_escapewallmaria_1();
+
+        drop(BidirectionalBfs::new(&digraph).shortest_path(16, 0));
+    }
+
+    proptest! {
+        #[test]
+        fn shortest_path_len_eq_bfs_pred(
+            order in 1..8_usize,
+            arcs in prop::collection::vec((0..8_usize, 0..8_usize), 0..24),
+            s in 0..8_usize,
+            t in 0..8_usize,
+        ) {
+            let digraph = digraph(order, &arcs);
+            let (s, t) = (s % order, t % order);
+
+            let path = BidirectionalBfs::new(&digraph)
+                .shortest_path(s, t);
+
+            let expected =
+                BfsPred::new(&digraph, once(s)).shortest_path(|v| v == t);
+
+            assert_eq!(path.as_ref().map(Vec::len), expected.map(|p| p.len()));
+
+            if let Some(path) = path {
+                assert_eq!(path.first(), Some(&s));
+                assert_eq!(path.last(), Some(&t));
+                assert!(path.windows(2).all(|w| digraph.has_arc(w[0], w[1])));
+            }
+        }
+    }
+}
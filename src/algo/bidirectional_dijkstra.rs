@@ -0,0 +1,398 @@
+//! Bidirectional Dijkstra's algorithm.
+//!
+//! Bidirectional Dijkstra's algorithm finds the shortest path between two
+//! vertices in an arc-weighted digraph.[^1] It searches forward from the
+//! source vertex over the digraph and backward from the target vertex over
+//! the digraph's converse, and stops when the sum of the smallest tentative
+//! distances in both directions exceeds the best path found.
+//!
+//! The time complexity is `O(v log v + a)`, where `v` is the digraph's order
+//! and `a` is the digraph's size. In practice, the searches explore far fewer
+//! vertices than
+//! [`DijkstraPred::shortest_path`](crate::DijkstraPred::shortest_path).
+//!
+//! # Examples
+//!
+//! ```
+//! use graaf::{
+//!     AddArcWeighted,
+//!     AdjacencyListWeighted,
+//!     BidirectionalDijkstra,
+//!     Empty,
+//! };
+//!
+//! let mut digraph = AdjacencyListWeighted::<usize>::empty(7);
+//!
+//! digraph.add_arc_weighted(0, 1, 1);
+//! digraph.add_arc_weighted(1, 2, 1);
+//! digraph.add_arc_weighted(1, 6, 6);
+//! digraph.add_arc_weighted(2, 4, 1);
+//! digraph.add_arc_weighted(3, 0, 2);
+//! digraph.add_arc_weighted(4, 5, 2);
+//! digraph.add_arc_weighted(5, 6, 1);
+//!
+//! let dijkstra = BidirectionalDijkstra::new(&digraph);
+//!
+//! assert_eq!(
+//!     dijkstra.shortest_path(3, 6),
+//!     Some(vec![3, 0, 1, 2, 4, 5, 6])
+//! );
+//! assert_eq!(dijkstra.shortest_path(6, 3), None);
+//! ```
+//!
+//! [^1]: Ira Pohl. 1971. Bi-directional search. Machine Intelligence 6
+//!   (1971), 127–140.
+
+use {
+    crate::{
+        Converse,
+        Order,
+        OutNeighborsWeighted,
+        PredecessorTree,
+    },
+    core::cmp::Reverse,
+    std::collections::BinaryHeap,
+};
+
+/// Bidirectional Dijkstra's algorithm.
+///
+/// # Examples
+///
+/// ```
+/// use graaf::{
+///     AddArcWeighted,
+///     AdjacencyListWeighted,
+///     BidirectionalDijkstra,
+///     Empty,
+/// };
+///
+/// let mut digraph = AdjacencyListWeighted::<usize>::empty(7);
+///
+/// digraph.add_arc_weighted(0, 1, 1);
+/// digraph.add_arc_weighted(1, 2, 1);
+/// digraph.add_arc_weighted(1, 6, 6);
+/// digraph.add_arc_weighted(2, 4, 1);
+/// digraph.add_arc_weighted(3, 0, 2);
+/// digraph.add_arc_weighted(4, 5, 2);
+/// digraph.add_arc_weighted(5, 6, 1);
+///
+/// let dijkstra = BidirectionalDijkstra::new(&digraph);
+///
+/// assert_eq!(
+///     dijkstra.shortest_path(3, 6),
+///     Some(vec![3, 0, 1, 2, 4, 5, 6])
+/// );
+/// assert_eq!(dijkstra.shortest_path(6, 3), None);
+/// ```
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct BidirectionalDijkstra<'a, D> {
+    digraph: &'a D,
+    converse: D,
+}
+
+/// One direction of the search.
+struct Search {
+    dist: Vec<usize>,
+    pred: PredecessorTree,
+    heap: BinaryHeap<(Reverse<usize>, usize)>,
+}
+
+impl Search {
+    fn new(order: usize, source: usize) -> Self {
+        let mut dist = vec![usize::MAX; order];
+        let mut heap = BinaryHeap::new();
+
+        dist[source] = 0;
+        heap.push((Reverse(0), source));
+
+        Self {
+            dist,
+            pred: PredecessorTree::new(order),
+            heap,
+        }
+    }
+
+    /// Return the smallest tentative distance in the heap.
+    fn min(&mut self) -> Option<usize> {
+        while let Some(&(Reverse(distance), v)) = self.heap.peek() {
+            if distance == self.dist[v] {
+                return Some(distance);
+            }
+
+            let _ = self.heap.pop();
+        }
+
+        None
+    }
+
+    /// Settle the vertex with the smallest tentative distance and update the
+    /// best path through the relaxed arcs.
+    fn settle<D>(
+        &mut self,
+        digraph: &D,
+        other: &Self,
+        best: &mut (usize, Option<usize>),
+    ) where
+        D: OutNeighborsWeighted<Weight = usize>,
+    {
+        let Some((Reverse(distance), u)) = self.heap.pop() else {
+            return;
+        };
+
+        for (v, w) in digraph.out_neighbors_weighted(u) {
+            let distance = distance + w;
+
+            if distance < self.dist[v] {
+                self.dist[v] = distance;
+                self.pred[v] = Some(u);
+                self.heap.push((Reverse(distance), v));
+            }
+
+            if other.dist[v] != usize::MAX
+                && self.dist[v] + other.dist[v] < best.0
+            {
+                *best = (self.dist[v] + other.dist[v], Some(v));
+            }
+        }
+    }
+}
+
+impl<'a, D> BidirectionalDijkstra<'a, D> {
+    /// Initialize bidirectional Dijkstra's algorithm.
+    ///
+    /// # Arguments
+    ///
+    /// * `digraph`: The digraph.
+    #[must_use]
+    pub fn new(digraph: &'a D) -> Self
+    where
+        D: Converse,
+    {
+        Self {
+            digraph,
+            converse: digraph.converse(),
+        }
+    }
+
+    /// Find the shortest path from a source vertex to a target vertex.
+    ///
+    /// # Arguments
+    ///
+    /// * `s`: The source vertex.
+    /// * `t`: The target vertex.
+    ///
+    /// # Returns
+    ///
+    /// If `t` is reachable from `s`, the function returns the shortest path
+    /// from `s` to `t`. Otherwise, it returns `None`.
+    ///
+    /// # Panics
+    ///
+    /// * Panics if `s` isn't in the digraph.
+    /// * Panics if `t` isn't in the digraph.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use graaf::{
+    ///     AddArcWeighted,
+    ///     AdjacencyListWeighted,
+    ///     BidirectionalDijkstra,
+    ///     Empty,
+    /// };
+    ///
+    /// let mut digraph = AdjacencyListWeighted::<usize>::empty(4);
+    ///
+    /// digraph.add_arc_weighted(0, 1, 1);
+    /// digraph.add_arc_weighted(0, 3, 5);
+    /// digraph.add_arc_weighted(1, 2, 1);
+    /// digraph.add_arc_weighted(2, 3, 1);
+    ///
+    /// let dijkstra = BidirectionalDijkstra::new(&digraph);
+    ///
+    /// assert_eq!(dijkstra.shortest_path(0, 3), Some(vec![0, 1, 2, 3]));
+    /// assert_eq!(dijkstra.shortest_path(2, 2), Some(vec![2]));
+    /// ```
+    #[must_use]
+    pub fn shortest_path(&self, s: usize, t: usize) -> Option<Vec<usize>>
+    where
+        D: Order + OutNeighborsWeighted<Weight = usize>,
+    {
+        let order = self.digraph.order();
+
+        assert!(s < order, "s = {s} isn't in the digraph");
+        assert!(t < order, "t = {t} isn't in the digraph");
+
+        if s == t {
+            return Some(vec![s]);
+        }
+
+        let mut forward = Search::new(order, s);
+        let mut backward = Search::new(order, t);
+        let mut best = (usize::MAX, None);
+
+        while let (Some(f), Some(b)) = (forward.min(), backward.min()) {
+            if f.saturating_add(b) >= best.0 {
+                break;
+            }
+
+            if f <= b {
+                forward.settle(self.digraph, &backward, &mut best);
+            } else {
+                backward.settle(&self.converse, &forward, &mut best);
+            }
+        }
+
+        let v = best.1?;
+        let mut path = forward.pred.search_by(v, |_, u| u.is_none())?;
+
+        path.reverse();
+        path.extend(
+            backward
+                .pred
+                .search_by(v, |_, u| u.is_none())?
+                .into_iter()
+                .skip(1),
+        );
+
+        Some(path)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::*,
+        crate::{
+            ArcWeight,
+            DijkstraDist,
+            proptest_strategy::digraph_weighted,
+            repr::adjacency_list_weighted::fixture::{
+                bang_jensen_94_usize,
+                bang_jensen_96_usize,
+                kattis_bryr_1_usize,
+                kattis_bryr_2_usize,
+                kattis_bryr_3_usize,
+                kattis_crosscountry_usize,
+                kattis_shortestpath1_usize,
+            },
+        },
+        proptest::prelude::*,
+        std::iter::once,
+    };
+
+    #[test]
+    fn shortest_path_bang_jensen_94() {
+        let digraph = bang_jensen_94_usize();
+
+        assert_eq!(
+            BidirectionalDijkstra::new(&digraph).shortest_path(0, 6),
+            Some(vec![0, 2, 4, 6])
+        );
+    }
+
+    #[test]
+    fn shortest_path_bang_jensen_96() {
+        let digraph = bang_jensen_96_usize();
+
+        assert_eq!(
+            BidirectionalDijkstra::new(&digraph).shortest_path(0, 5),
+            Some(vec![0, 2, 4, 3, 5])
+        );
+    }
+
+    #[test]
+    fn shortest_path_kattis_bryr_1() {
+        let digraph = kattis_bryr_1_usize();
+
+        assert_eq!(
+            BidirectionalDijkstra::new(&digraph).shortest_path(0, 2),
+            Some(vec![0, 2])
+        );
+    }
+
+    #[test]
+    fn shortest_path_kattis_bryr_2() {
+        let digraph = kattis_bryr_2_usize();
+
+        assert_eq!(
+            BidirectionalDijkstra::new(&digraph).shortest_path(0, 5),
+            Some(vec![0, 3, 4, 5])
+        );
+    }
+
+    #[test]
+    fn shortest_path_kattis_bryr_3() {
+        let digraph = kattis_bryr_3_usize();
+
+        assert_eq!(
+            BidirectionalDijkstra::new(&digraph)
+                .shortest_path(0, 9)
+                .map(|path| path.len()),
+            Some(5)
+        );
+    }
+
+    #[test]
+    fn shortest_path_kattis_crosscountry() {
+        let digraph = kattis_crosscountry_usize();
+
+        assert_eq!(
+            BidirectionalDijkstra::new(&digraph).shortest_path(0, 2),
+            Some(vec![0, 2])
+        );
+    }
+
+    #[test]
+    fn shortest_path_kattis_shortestpath1() {
+        let digraph = kattis_shortestpath1_usize();
+        let dijkstra = BidirectionalDijkstra::new(&digraph);
+
+        assert_eq!(dijkstra.shortest_path(0, 3), None);
+        assert_eq!(dijkstra.shortest_path(3, 3), Some(vec![3]));
+    }
+
+    #[test]
+    #[should_panic(expected = "t = 4 isn't in the digraph")]
+    fn shortest_path_out_of_bounds() {
+        let digraph = kattis_shortestpath1_usize();
+
+        drop(BidirectionalDijkstra::new(&digraph).shortest_path(0, 4));
+    }
+
+    proptest! {
+        #[test]
+        fn shortest_path_weight_eq_dijkstra_dist(
+            order in 1..8_usize,
+            arcs in prop::collection::vec(
+                (0..8_usize, 0..8_usize, 0..10_usize),
+                0..24,
+            ),
+            s in 0..8_usize,
+            t in 0..8_usize,
+        ) {
+            let digraph = digraph_weighted(order, &arcs);
+            let (s, t) = (s % order, t % order);
+            let dist = DijkstraDist::new(&digraph, once(s)).distances();
+
+            let path = BidirectionalDijkstra::new(&digraph)
+                .shortest_path(s, t);
+
+            if dist[t] == usize::MAX {
+                assert_eq!(path, None);
+            } else {
+                let path = path.unwrap();
+
+                assert_eq!(path.first(), Some(&s));
+                assert_eq!(path.last(), Some(&t));
+
+                let weight = path
+                    .windows(2)
+                    .map(|w| *digraph.arc_weight(w[0], w[1]).unwrap())
+                    .sum::<usize>();
+
+                assert_eq!(weight, dist[t]);
+            }
+        }
+    }
+}
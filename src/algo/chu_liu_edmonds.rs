@@ -0,0 +1,593 @@
+//! Chu-Liu/Edmonds minimum arborescence.
+//!
+//! The Chu-Liu/Edmonds algorithm finds a minimum-weight arborescence, a
+//! spanning tree whose arcs point away from a root, in an arc-weighted
+//! digraph.[^1][^2] Each vertex except the root picks its cheapest incoming
+//! arc. If the picked arcs contain a circuit, the algorithm contracts it into
+//! a single vertex, lowers the weights of the arcs entering it, and repeats.
+//! Expanding the contractions in reverse breaks each circuit at the arc that
+//! enters it.
+//!
+//! The time complexity is `O(v a)`, where `v` is the digraph's order and `a`
+//! is the digraph's size.
+//!
+//! # Examples
+//!
+//! ```
+//! use graaf::{
+//!     AddArcWeighted,
+//!     AdjacencyListWeighted,
+//!     ChuLiuEdmonds,
+//!     Empty,
+//! };
+//!
+//! let mut digraph = AdjacencyListWeighted::<usize>::empty(4);
+//!
+//! digraph.add_arc_weighted(0, 1, 10);
+//! digraph.add_arc_weighted(0, 2, 5);
+//! digraph.add_arc_weighted(0, 3, 10);
+//! digraph.add_arc_weighted(1, 2, 1);
+//! digraph.add_arc_weighted(2, 1, 1);
+//! digraph.add_arc_weighted(2, 3, 4);
+//! digraph.add_arc_weighted(3, 1, 8);
+//!
+//! let arborescence =
+//!     ChuLiuEdmonds::new(&digraph, 0).min_arborescence().unwrap();
+//!
+//! assert!(arborescence.pred.into_iter().eq([
+//!     None,
+//!     Some(2),
+//!     Some(0),
+//!     Some(2)
+//! ]));
+//!
+//! assert_eq!(arborescence.weight, 10);
+//! ```
+//!
+//! [^1]: Yoeng-Jin Chu and Tseng-Hong Liu. 1965. On the shortest arborescence
+//!   of a directed graph. Scientia Sinica 14 (1965), 1396–1400.
+//!
+//! [^2]: Jack Edmonds. 1967. Optimum branchings. Journal of Research of the
+//!   National Bureau of Standards 71B, 4 (1967), 233–240.
+//!   <https://doi.org/10.6028/jres.071B.032>
+
+use {
+    crate::{
+        ArcsWeighted,
+        Order,
+        PredecessorTree,
+    },
+    std::{
+        borrow::Cow,
+        collections::VecDeque,
+        ops::{
+            Add,
+            Sub,
+        },
+    },
+};
+
+/// A minimum-weight arborescence.
+///
+/// # Examples
+///
+/// ```
+/// use graaf::{
+///     AddArcWeighted,
+///     AdjacencyListWeighted,
+///     ChuLiuEdmonds,
+///     Empty,
+/// };
+///
+/// let mut digraph = AdjacencyListWeighted::<usize>::empty(3);
+///
+/// digraph.add_arc_weighted(0, 1, 4);
+/// digraph.add_arc_weighted(0, 2, 1);
+/// digraph.add_arc_weighted(2, 1, 2);
+///
+/// let arborescence =
+///     ChuLiuEdmonds::new(&digraph, 0).min_arborescence().unwrap();
+///
+/// assert_eq!(arborescence.pred.search(1, 0), Some(vec![1, 2, 0]));
+/// assert_eq!(arborescence.weight, 3);
+/// ```
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[non_exhaustive]
+pub struct Arborescence<W> {
+    /// The predecessor of each vertex in the arborescence.
+    pub pred: PredecessorTree,
+    /// The total weight of the arborescence.
+    pub weight: W,
+}
+
+/// Vertices unreachable from the root.
+///
+/// No arborescence spans a digraph with a vertex unreachable from the root.
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[non_exhaustive]
+pub struct Unreachable {
+    /// The vertices unreachable from the root, in ascending order.
+    pub vertices: Vec<usize>,
+}
+
+/// Chu-Liu/Edmonds minimum arborescence.
+///
+/// # Examples
+///
+/// ```
+/// use graaf::{
+///     AddArcWeighted,
+///     AdjacencyListWeighted,
+///     ChuLiuEdmonds,
+///     Empty,
+/// };
+///
+/// let mut digraph = AdjacencyListWeighted::<usize>::empty(4);
+///
+/// digraph.add_arc_weighted(0, 1, 10);
+/// digraph.add_arc_weighted(0, 2, 5);
+/// digraph.add_arc_weighted(0, 3, 10);
+/// digraph.add_arc_weighted(1, 2, 1);
+/// digraph.add_arc_weighted(2, 1, 1);
+/// digraph.add_arc_weighted(2, 3, 4);
+/// digraph.add_arc_weighted(3, 1, 8);
+///
+/// let arborescence =
+///     ChuLiuEdmonds::new(&digraph, 0).min_arborescence().unwrap();
+///
+/// assert!(arborescence.pred.into_iter().eq([
+///     None,
+///     Some(2),
+///     Some(0),
+///     Some(2)
+/// ]));
+///
+/// assert_eq!(arborescence.weight, 10);
+/// ```
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ChuLiuEdmonds<'a, D> {
+    digraph: &'a D,
+    root: usize,
+}
+
+impl<'a, D> ChuLiuEdmonds<'a, D> {
+    /// Initialize the Chu-Liu/Edmonds algorithm.
+    ///
+    /// # Arguments
+    ///
+    /// * `digraph`: The digraph.
+    /// * `root`: The root vertex.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `root` isn't in the digraph.
+    #[must_use]
+    pub fn new(digraph: &'a D, root: usize) -> Self
+    where
+        D: Order,
+    {
+        assert!(root < digraph.order(), "root = {root} isn't in the digraph");
+
+        Self { digraph, root }
+    }
+
+    /// Find a minimum-weight arborescence rooted at the root.
+    ///
+    /// # Errors
+    ///
+    /// Returns the vertices that are [`Unreachable`] from the root if there
+    /// are any.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use graaf::{
+    ///     AddArcWeighted,
+    ///     AdjacencyListWeighted,
+    ///     ChuLiuEdmonds,
+    ///     Empty,
+    /// };
+    ///
+    /// let mut digraph = AdjacencyListWeighted::<isize>::empty(4);
+    ///
+    /// digraph.add_arc_weighted(0, 1, 3);
+    /// digraph.add_arc_weighted(1, 2, -1);
+    /// digraph.add_arc_weighted(3, 2, 1);
+    ///
+    /// let unreachable = ChuLiuEdmonds::new(&digraph, 0)
+    ///     .min_arborescence()
+    ///     .unwrap_err();
+    ///
+    /// assert_eq!(unreachable.vertices, vec![3]);
+    ///
+    /// digraph.add_arc_weighted(2, 3, 2);
+    ///
+    /// let arborescence =
+    ///     ChuLiuEdmonds::new(&digraph, 0).min_arborescence().unwrap();
+    ///
+    /// assert_eq!(arborescence.weight, 4);
+    /// ```
+    pub fn min_arborescence<W>(&self) -> Result<Arborescence<W>, Unreachable>
+    where
+        D: ArcsWeighted<Weight = W> + Order,
+        W: Add<Output = W> + Copy + Default + Ord + Sub<Output = W>,
+    {
+        let order = self.digraph.order();
+        let root = self.root;
+
+        let arcs = self
+            .digraph
+            .arcs_weighted()
+            .filter(|&(u, v, _)| u != v)
+            .map(|(u, v, &w)| (u, v, w))
+            .collect::<Vec<_>>();
+
+        let mut out = vec![Vec::new(); order];
+
+        for &(u, v, _) in &arcs {
+            out[u].push(v);
+        }
+
+        let mut visited = vec![false; order];
+        let mut queue = VecDeque::from([root]);
+
+        visited[root] = true;
+
+        while let Some(u) = queue.pop_front() {
+            for &v in &out[u] {
+                if !visited[v] {
+                    visited[v] = true;
+                    queue.push_back(v);
+                }
+            }
+        }
+
+        let unreachable =
+            (0..order).filter(|&v| !visited[v]).collect::<Vec<_>>();
+
+        if !unreachable.is_empty() {
+            return Err(Unreachable {
+                vertices: unreachable,
+            });
+        }
+
+        let chosen = contract(order, root, &arcs);
+        let mut pred = PredecessorTree::new(order);
+        let mut weight = W::default();
+
+        for (v, &i) in chosen.iter().enumerate() {
+            if v != root {
+                let (u, _, w) = arcs[i];
+
+                pred[v] = Some(u);
+                weight = weight + w;
+            }
+        }
+
+        Ok(Arborescence { pred, weight })
+    }
+}
+
+/// A contracted digraph.
+struct Level {
+    heads: Vec<usize>,
+    cheapest: Vec<usize>,
+    origin: Vec<usize>,
+}
+
+/// Choose the incoming arc of each vertex except the root. Every vertex must
+/// be reachable from the root.
+fn contract<W>(
+    mut order: usize,
+    mut root: usize,
+    arcs: &[(usize, usize, W)],
+) -> Vec<usize>
+where
+    W: Copy + Ord + Sub<Output = W>,
+{
+    let mut arcs = Cow::Borrowed(arcs);
+    let mut levels = Vec::<Level>::new();
+
+    loop {
+        let mut cheapest = vec![usize::MAX; order];
+
+        for (i, &(_, v, w)) in arcs.iter().enumerate() {
+            if v != root
+                && (cheapest[v] == usize::MAX || w < arcs[cheapest[v]].2)
+            {
+                cheapest[v] = i;
+            }
+        }
+
+        // Find the circuits among the cheapest incoming arcs.
+        let mut component = vec![usize::MAX; order];
+        let mut mark = vec![usize::MAX; order];
+        let mut count = 0;
+
+        for v in 0..order {
+            let mut u = v;
+
+            while u != root && mark[u] == usize::MAX {
+                mark[u] = v;
+                u = arcs[cheapest[u]].0;
+            }
+
+            if u != root && mark[u] == v {
+                let mut x = u;
+
+                loop {
+                    component[x] = count;
+                    x = arcs[cheapest[x]].0;
+
+                    if x == u {
+                        break;
+                    }
+                }
+
+                count += 1;
+            }
+        }
+
+        let heads = arcs.iter().map(|&(_, v, _)| v).collect();
+
+        if count == 0 {
+            levels.push(Level {
+                heads,
+                cheapest,
+                origin: Vec::new(),
+            });
+
+            break;
+        }
+
+        for c in &mut component {
+            if *c == usize::MAX {
+                *c = count;
+                count += 1;
+            }
+        }
+
+        let mut contracted = Vec::new();
+        let mut origin = Vec::new();
+
+        for (i, &(u, v, w)) in arcs.iter().enumerate() {
+            // Arcs into the root are never chosen.
+            if v != root && component[u] != component[v] {
+                contracted.push((
+                    component[u],
+                    component[v],
+                    w - arcs[cheapest[v]].2,
+                ));
+
+                origin.push(i);
+            }
+        }
+
+        levels.push(Level {
+            heads,
+            cheapest,
+            origin,
+        });
+
+        order = count;
+        root = component[root];
+        arcs = Cow::Owned(contracted);
+    }
+
+    // Expand the contractions. The arc that enters a contracted circuit
+    // replaces the circuit arc into the same vertex.
+    let mut chosen = Vec::<usize>::new();
+
+    while let Some(level) = levels.pop() {
+        let mut expanded = level.cheapest;
+
+        for &i in &chosen {
+            if i != usize::MAX {
+                let j = level.origin[i];
+
+                expanded[level.heads[j]] = j;
+            }
+        }
+
+        chosen = expanded;
+    }
+
+    chosen
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::*,
+        crate::{
+            AdjacencyListWeighted,
+            ArcWeight,
+            Empty,
+            proptest_strategy::digraph_weighted,
+            repr::adjacency_list_weighted::fixture::{
+                bang_jensen_96_usize,
+                kattis_crosscountry_usize,
+            },
+        },
+        proptest::prelude::*,
+    };
+
+    /// Find the minimum weight by trying every choice of incoming arcs.
+    fn brute_force(
+        digraph: &AdjacencyListWeighted<isize>,
+        root: usize,
+        v: usize,
+        pred: &mut Vec<Option<usize>>,
+    ) -> Option<isize> {
+        let order = digraph.order();
+
+        if v == order {
+            // Every vertex must lead to the root.
+            let is_arborescence = (0..order).all(|u| {
+                let mut x = u;
+
+                for _ in 0..order {
+                    match pred[x] {
+                        Some(p) => x = p,
+                        None => break,
+                    }
+                }
+
+                x == root
+            });
+
+            return is_arborescence.then_some(0);
+        }
+
+        if v == root {
+            return brute_force(digraph, root, v + 1, pred);
+        }
+
+        let mut min = None;
+
+        for (u, _, &w) in digraph.arcs_weighted().filter(|&(_, x, _)| x == v) {
+            pred[v] = Some(u);
+
+            if let Some(rest) = brute_force(digraph, root, v + 1, pred) {
+                min = Some(min.map_or(w + rest, |m: isize| m.min(w + rest)));
+            }
+        }
+
+        pred[v] = None;
+
+        min
+    }
+
+    #[test]
+    fn min_arborescence_bang_jensen_96() {
+        let digraph = bang_jensen_96_usize();
+
+        let arborescence =
+            ChuLiuEdmonds::new(&digraph, 0).min_arborescence().unwrap();
+
+        assert!(arborescence.pred.into_iter().eq([
+            None,
+            Some(2),
+            Some(0),
+            Some(1),
+            Some(2),
+            Some(3)
+        ]));
+
+        assert_eq!(arborescence.weight, 9);
+    }
+
+    #[test]
+    fn min_arborescence_kattis_crosscountry() {
+        let digraph = kattis_crosscountry_usize();
+
+        let arborescence =
+            ChuLiuEdmonds::new(&digraph, 0).min_arborescence().unwrap();
+
+        assert!(arborescence.pred.into_iter().eq([
+            None,
+            Some(0),
+            Some(0),
+            Some(2)
+        ]));
+
+        assert_eq!(arborescence.weight, 11);
+    }
+
+    #[test]
+    fn min_arborescence_nested_circuits() {
+        let digraph = digraph_weighted(
+            5,
+            &[
+                (0, 1, 10),
+                (1, 2, 1),
+                (2, 1, 1),
+                (2, 3, 1),
+                (3, 4, 1),
+                (4, 2, 1),
+                (0, 4, 6),
+            ],
+        );
+
+        let arborescence =
+            ChuLiuEdmonds::new(&digraph, 0).min_arborescence().unwrap();
+
+        assert!(arborescence.pred.into_iter().eq([
+            None,
+            Some(2),
+            Some(4),
+            Some(2),
+            Some(0)
+        ]));
+
+        assert_eq!(arborescence.weight, 9);
+    }
+
+    #[test]
+    fn min_arborescence_trivial() {
+        let digraph = AdjacencyListWeighted::<isize>::trivial();
+
+        let arborescence =
+            ChuLiuEdmonds::new(&digraph, 0).min_arborescence().unwrap();
+
+        assert!(arborescence.pred.into_iter().eq([None]));
+        assert_eq!(arborescence.weight, 0);
+    }
+
+    #[test]
+    fn min_arborescence_unreachable_circuit() {
+        let digraph =
+            digraph_weighted(4, &[(0, 1, 1), (2, 3, 1), (3, 2, 1), (3, 1, 1)]);
+
+        let unreachable = ChuLiuEdmonds::new(&digraph, 0)
+            .min_arborescence()
+            .unwrap_err();
+
+        assert_eq!(unreachable.vertices, vec![2, 3]);
+    }
+
+    #[test]
+    #[should_panic(expected = "root = 2 isn't in the digraph")]
+    fn new_root_out_of_bounds() {
+        let digraph = digraph_weighted(2, &[(0, 1, 1)]);
+
+        let _ = ChuLiuEdmonds::new(&digraph, 2);
+    }
+
+    proptest! {
+        #[test]
+        fn min_arborescence_eq_brute_force(
+            order in 1..6_usize,
+            root in 0..6_usize,
+            arcs in prop::collection::vec(
+                (0..6_usize, 0..6_usize, -10..10_isize),
+                0..16,
+            ),
+        ) {
+            let root = root % order;
+            let digraph = digraph_weighted(order, &arcs);
+            let result = ChuLiuEdmonds::new(&digraph, root).min_arborescence();
+            let expected =
+                brute_force(&digraph, root, 0, &mut vec![None; order]);
+
+            match result {
+                Ok(arborescence) => {
+                    assert_eq!(Some(arborescence.weight), expected);
+
+                    let weight = (0..order)
+                        .filter_map(|v| {
+                            arborescence.pred[v]
+                                .map(|u| *digraph.arc_weight(u, v).unwrap())
+                        })
+                        .sum::<isize>();
+
+                    assert_eq!(weight, arborescence.weight);
+                }
+                Err(unreachable) => {
+                    assert!(expected.is_none());
+                    assert!(!unreachable.vertices.is_empty());
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,361 @@
+//! Dial's algorithm with distances.
+//!
+//! Dial's algorithm finds the shortest paths in an arc-weighted digraph with
+//! small integer weights.[^1] It replaces the binary heap of Dijkstra's
+//! algorithm with a queue of buckets, one per distance. The queue holds at
+//! most `c + 1` buckets, where `c` is the largest arc weight.
+//!
+//! The time complexity is `O(v + a + d)`, where `v` is the digraph's order,
+//! `a` is the digraph's size, and `d` is the largest distance.
+//!
+//! # Examples
+//!
+//! ```
+//! use {
+//!     graaf::{
+//!         AddArcWeighted,
+//!         AdjacencyListWeighted,
+//!         DialDist,
+//!         Empty,
+//!     },
+//!     std::iter::once,
+//! };
+//!
+//! let mut digraph = AdjacencyListWeighted::<usize>::empty(7);
+//!
+//! digraph.add_arc_weighted(0, 1, 1);
+//! digraph.add_arc_weighted(1, 2, 1);
+//! digraph.add_arc_weighted(1, 6, 6);
+//! digraph.add_arc_weighted(2, 4, 1);
+//! digraph.add_arc_weighted(3, 0, 2);
+//! digraph.add_arc_weighted(4, 5, 2);
+//! digraph.add_arc_weighted(5, 6, 1);
+//!
+//! let mut dial = DialDist::new(&digraph, once(0));
+//!
+//! assert!(dial.eq([(0, 0), (1, 1), (2, 2), (4, 3), (5, 5), (6, 6)]));
+//! ```
+//!
+//! [^1]: Robert B. Dial. 1969. Algorithm 360: shortest-path forest with
+//!   topological ordering. Commun. ACM 12, 11 (November 1969), 632–633.
+//!   <https://doi.org/10.1145/363269.363610>
+
+use {
+    crate::{
+        Order,
+        OutNeighborsWeighted,
+    },
+    std::collections::VecDeque,
+};
+
+type Step = (usize, usize);
+
+/// Dial's algorithm with distances.
+///
+/// # Examples
+///
+/// ```
+/// use {
+///     graaf::{
+///         AddArcWeighted,
+///         AdjacencyListWeighted,
+///         DialDist,
+///         Empty,
+///     },
+///     std::iter::once,
+/// };
+///
+/// let mut digraph = AdjacencyListWeighted::<usize>::empty(7);
+///
+/// digraph.add_arc_weighted(0, 1, 1);
+/// digraph.add_arc_weighted(1, 2, 1);
+/// digraph.add_arc_weighted(1, 6, 6);
+/// digraph.add_arc_weighted(2, 4, 1);
+/// digraph.add_arc_weighted(3, 0, 2);
+/// digraph.add_arc_weighted(4, 5, 2);
+/// digraph.add_arc_weighted(5, 6, 1);
+///
+/// let mut dial = DialDist::new(&digraph, once(0));
+///
+/// assert!(dial.eq([(0, 0), (1, 1), (2, 2), (4, 3), (5, 5), (6, 6)]));
+/// ```
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct DialDist<'a, D> {
+    digraph: &'a D,
+    dist: Vec<usize>,
+    buckets: VecDeque<Vec<usize>>,
+    distance: usize,
+}
+
+impl<'a, D> DialDist<'a, D>
+where
+    D: Order,
+{
+    /// Initialize Dial's algorithm.
+    ///
+    /// # Arguments
+    ///
+    /// * `digraph`: The digraph.
+    /// * `sources`: The source vertices.
+    ///
+    /// # Panics
+    ///
+    /// Panics if a source vertex isn't in the digraph.
+    #[must_use]
+    pub fn new<T>(digraph: &'a D, sources: T) -> Self
+    where
+        T: Iterator<Item = usize>,
+    {
+        let mut dist = vec![usize::MAX; digraph.order()];
+        let mut bucket = Vec::new();
+
+        for u in sources {
+            // Each distinct source is settled once.
+            if dist[u] == 0 {
+                continue;
+            }
+
+            dist[u] = 0;
+            bucket.push(u);
+        }
+
+        Self {
+            digraph,
+            dist,
+            buckets: VecDeque::from([bucket]),
+            distance: 0,
+        }
+    }
+
+    /// Find the distances from the source vertices to all other vertices.
+    ///
+    /// # Panics
+    ///
+    /// Panics if a successor vertex isn't in the digraph.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use graaf::{
+    ///     AddArcWeighted,
+    ///     AdjacencyListWeighted,
+    ///     DialDist,
+    ///     Empty,
+    /// };
+    ///
+    /// let mut digraph = AdjacencyListWeighted::<usize>::empty(7);
+    ///
+    /// digraph.add_arc_weighted(0, 1, 1);
+    /// digraph.add_arc_weighted(1, 2, 1);
+    /// digraph.add_arc_weighted(1, 6, 6);
+    /// digraph.add_arc_weighted(2, 4, 1);
+    /// digraph.add_arc_weighted(3, 0, 2);
+    /// digraph.add_arc_weighted(3, 5, 1);
+    /// digraph.add_arc_weighted(4, 5, 1);
+    /// digraph.add_arc_weighted(5, 6, 3);
+    ///
+    /// assert!(
+    ///     DialDist::new(&digraph, [0, 3].into_iter())
+    ///         .distances()
+    ///         .eq(&[0, 1, 2, 0, 3, 1, 4])
+    /// );
+    /// ```
+    #[must_use]
+    pub fn distances(&mut self) -> Vec<usize>
+    where
+        D: OutNeighborsWeighted<Weight = usize>,
+    {
+        let mut dist = vec![usize::MAX; self.digraph.order()];
+
+        for (v, d) in self {
+            dist[v] = d;
+        }
+
+        dist
+    }
+}
+
+impl<D> Iterator for DialDist<'_, D>
+where
+    D: Order + OutNeighborsWeighted<Weight = usize>,
+{
+    type Item = Step;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let bucket = self.buckets.front_mut()?;
+
+            let Some(u) = bucket.pop() else {
+                drop(self.buckets.pop_front());
+                self.distance += 1;
+
+                continue;
+            };
+
+            let distance = self.distance;
+
+            if distance != self.dist[u] {
+                continue;
+            }
+
+            for (v, &w) in self.digraph.out_neighbors_weighted(u) {
+                if distance + w < self.dist[v] {
+                    self.dist[v] = distance + w;
+
+                    if self.buckets.len() <= w {
+                        self.buckets.resize_with(w + 1, Vec::new);
+                    }
+
+                    self.buckets[w].push(v);
+                }
+            }
+
+            return Some((u, distance));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::*,
+        crate::{
+            AddArcWeighted,
+            AdjacencyListWeighted,
+            DijkstraDist,
+            Empty,
+            proptest_strategy::digraph_weighted,
+            repr::adjacency_list_weighted::fixture::{
+                bang_jensen_94_usize,
+                bang_jensen_96_usize,
+                kattis_bryr_1_usize,
+                kattis_bryr_2_usize,
+                kattis_bryr_3_usize,
+                kattis_crosscountry_usize,
+                kattis_shortestpath1_usize,
+            },
+        },
+        proptest::prelude::*,
+        std::iter::once,
+    };
+
+    #[test]
+    fn distances_bang_jensen_94() {
+        let digraph = bang_jensen_94_usize();
+
+        assert!(
+            DialDist::new(&digraph, once(0))
+                .distances()
+                .eq(&[0, 1, 1, 2, 2, 2, 3])
+        );
+    }
+
+    #[test]
+    fn distances_bang_jensen_96() {
+        let digraph = bang_jensen_96_usize();
+
+        assert!(
+            DialDist::new(&digraph, once(0))
+                .distances()
+                .eq(&[0, 5, 3, 6, 4, 7])
+        );
+    }
+
+    #[test]
+    fn distances_kattis_bryr_1() {
+        let digraph = kattis_bryr_1_usize();
+
+        assert!(DialDist::new(&digraph, once(0)).distances().eq(&[0, 1, 1]));
+    }
+
+    #[test]
+    fn distances_kattis_bryr_2() {
+        let digraph = kattis_bryr_2_usize();
+
+        assert!(
+            DialDist::new(&digraph, once(0))
+                .distances()
+                .eq(&[0, 1, 2, 1, 2, 3])
+        );
+    }
+
+    #[test]
+    fn distances_kattis_bryr_3() {
+        let digraph = kattis_bryr_3_usize();
+
+        assert!(
+            DialDist::new(&digraph, once(0))
+                .distances()
+                .eq(&[0, 0, 1, 0, 0, 0, 1, 0, 0, 1])
+        );
+    }
+
+    #[test]
+    fn distances_kattis_crosscountry() {
+        let digraph = kattis_crosscountry_usize();
+
+        assert!(
+            DialDist::new(&digraph, once(0))
+                .distances()
+                .eq(&[0, 1, 3, 10])
+        );
+    }
+
+    #[test]
+    fn distances_kattis_shortestpath1() {
+        let digraph = kattis_shortestpath1_usize();
+
+        assert!(DialDist::new(&digraph, once(0)).distances().eq(&[
+            0,
+            2,
+            4,
+            usize::MAX
+        ]));
+    }
+
+    #[test]
+    fn iter_duplicate_sources() {
+        let digraph = kattis_shortestpath1_usize();
+
+        assert!(DialDist::new(&digraph, [0, 0].into_iter()).eq([
+            (0, 0),
+            (1, 2),
+            (2, 4)
+        ]));
+    }
+
+    #[test]
+    fn iter_stale_entry() {
+        let mut digraph = AdjacencyListWeighted::<usize>::empty(4);
+
+        digraph.add_arc_weighted(0, 1, 10);
+        digraph.add_arc_weighted(0, 2, 1);
+        digraph.add_arc_weighted(1, 3, 20);
+        digraph.add_arc_weighted(2, 1, 1);
+
+        assert!(DialDist::new(&digraph, once(0)).eq([
+            (0, 0),
+            (2, 1),
+            (1, 2),
+            (3, 22),
+        ]));
+    }
+
+    proptest! {
+        #[test]
+        fn distances_eq_dijkstra_dist(
+            order in 1..8_usize,
+            arcs in prop::collection::vec(
+                (0..8_usize, 0..8_usize, 0..10_usize),
+                0..24,
+            ),
+        ) {
+            let digraph = digraph_weighted(order, &arcs);
+
+            assert_eq!(
+                DialDist::new(&digraph, once(0)).distances(),
+                DijkstraDist::new(&digraph, once(0)).distances()
+            );
+        }
+    }
+}
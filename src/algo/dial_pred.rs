@@ -0,0 +1,490 @@
+//! Dial's algorithm with predecessors.
+//!
+//! Dial's algorithm finds the shortest paths in an arc-weighted digraph with
+//! small integer weights.[^1] It replaces the binary heap of Dijkstra's
+//! algorithm with a queue of buckets, one per distance. The queue holds at
+//! most `c + 1` buckets, where `c` is the largest arc weight.
+//!
+//! The time complexity is `O(v + a + d)`, where `v` is the digraph's order,
+//! `a` is the digraph's size, and `d` is the largest distance.
+//!
+//! # Examples
+//!
+//! ```
+//! use {
+//!     graaf::{
+//!         AddArcWeighted,
+//!         AdjacencyListWeighted,
+//!         DialPred,
+//!         Empty,
+//!     },
+//!     std::iter::once,
+//! };
+//!
+//! let mut digraph = AdjacencyListWeighted::<usize>::empty(7);
+//!
+//! digraph.add_arc_weighted(0, 1, 1);
+//! digraph.add_arc_weighted(1, 2, 1);
+//! digraph.add_arc_weighted(1, 6, 6);
+//! digraph.add_arc_weighted(2, 4, 1);
+//! digraph.add_arc_weighted(3, 0, 2);
+//! digraph.add_arc_weighted(4, 5, 2);
+//! digraph.add_arc_weighted(5, 6, 1);
+//!
+//! let mut dial = DialPred::new(&digraph, once(0));
+//!
+//! assert!(dial.eq([
+//!     (None, 0),
+//!     (Some(0), 1),
+//!     (Some(1), 2),
+//!     (Some(2), 4),
+//!     (Some(4), 5),
+//!     (Some(5), 6),
+//! ]));
+//! ```
+//!
+//! [^1]: Robert B. Dial. 1969. Algorithm 360: shortest-path forest with
+//!   topological ordering. Commun. ACM 12, 11 (November 1969), 632–633.
+//!   <https://doi.org/10.1145/363269.363610>
+
+use {
+    crate::{
+        Order,
+        OutNeighborsWeighted,
+        PredecessorTree,
+    },
+    std::collections::VecDeque,
+};
+
+type Step = (Option<usize>, usize);
+
+/// Dial's algorithm with predecessors.
+///
+/// # Examples
+///
+/// ```
+/// use {
+///     graaf::{
+///         AddArcWeighted,
+///         AdjacencyListWeighted,
+///         DialPred,
+///         Empty,
+///     },
+///     std::iter::once,
+/// };
+///
+/// let mut digraph = AdjacencyListWeighted::<usize>::empty(7);
+///
+/// digraph.add_arc_weighted(0, 1, 1);
+/// digraph.add_arc_weighted(1, 2, 1);
+/// digraph.add_arc_weighted(1, 6, 6);
+/// digraph.add_arc_weighted(2, 4, 1);
+/// digraph.add_arc_weighted(3, 0, 2);
+/// digraph.add_arc_weighted(4, 5, 2);
+/// digraph.add_arc_weighted(5, 6, 1);
+///
+/// let mut dial = DialPred::new(&digraph, once(0));
+///
+/// assert!(dial.eq([
+///     (None, 0),
+///     (Some(0), 1),
+///     (Some(1), 2),
+///     (Some(2), 4),
+///     (Some(4), 5),
+///     (Some(5), 6),
+/// ]));
+/// ```
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct DialPred<'a, D> {
+    digraph: &'a D,
+    dist: Vec<usize>,
+    buckets: VecDeque<Vec<Step>>,
+    distance: usize,
+}
+
+impl<'a, D> DialPred<'a, D>
+where
+    D: Order,
+{
+    /// Initialize Dial's algorithm.
+    ///
+    /// # Arguments
+    ///
+    /// * `digraph`: The digraph.
+    /// * `sources`: The source vertices.
+    ///
+    /// # Panics
+    ///
+    /// Panics if a source vertex isn't in the digraph.
+    #[must_use]
+    pub fn new<T>(digraph: &'a D, sources: T) -> Self
+    where
+        T: Iterator<Item = usize>,
+    {
+        let mut dist = vec![usize::MAX; digraph.order()];
+        let mut bucket = Vec::new();
+
+        for u in sources {
+            // Each distinct source is settled once.
+            if dist[u] == 0 {
+                continue;
+            }
+
+            dist[u] = 0;
+            bucket.push((None, u));
+        }
+
+        Self {
+            digraph,
+            dist,
+            buckets: VecDeque::from([bucket]),
+            distance: 0,
+        }
+    }
+
+    /// Find the predecessor tree.
+    ///
+    /// # Panics
+    ///
+    /// Panics if a successor vertex isn't in the digraph.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use graaf::{
+    ///     AddArcWeighted,
+    ///     AdjacencyListWeighted,
+    ///     DialPred,
+    ///     Empty,
+    /// };
+    ///
+    /// let mut digraph = AdjacencyListWeighted::<usize>::empty(7);
+    ///
+    /// digraph.add_arc_weighted(0, 1, 1);
+    /// digraph.add_arc_weighted(1, 2, 1);
+    /// digraph.add_arc_weighted(1, 6, 5);
+    /// digraph.add_arc_weighted(2, 4, 1);
+    /// digraph.add_arc_weighted(3, 0, 2);
+    /// digraph.add_arc_weighted(3, 5, 1);
+    /// digraph.add_arc_weighted(4, 5, 1);
+    /// digraph.add_arc_weighted(5, 6, 3);
+    ///
+    /// assert!(
+    ///     DialPred::new(&digraph, [0, 3].into_iter())
+    ///         .predecessors()
+    ///         .into_iter()
+    ///         .eq([None, Some(0), Some(1), None, Some(2), Some(3), Some(5)])
+    /// );
+    /// ```
+    #[must_use]
+    pub fn predecessors(&mut self) -> PredecessorTree
+    where
+        D: OutNeighborsWeighted<Weight = usize>,
+    {
+        let mut pred = PredecessorTree::new(self.digraph.order());
+
+        for (u, v) in self {
+            pred[v] = u;
+        }
+
+        pred
+    }
+
+    /// Find the shortest path from the source vertices to a target vertex.
+    ///
+    /// # Arguments
+    ///
+    /// * `is_target`: The function determining if the vertex is a target.
+    ///
+    /// # Returns
+    ///
+    /// If a target vertex is reachable, the function returns the shortest
+    /// path to the nearest target vertex. Otherwise, it returns `None`.
+    ///
+    /// # Panics
+    ///
+    /// * Panics if `is_target` panics.
+    /// * Panics if a successor vertex isn't in the digraph.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use {
+    ///     graaf::{
+    ///         AddArcWeighted,
+    ///         AdjacencyListWeighted,
+    ///         DialPred,
+    ///         Empty,
+    ///     },
+    ///     std::iter::once,
+    /// };
+    ///
+    /// let mut digraph = AdjacencyListWeighted::<usize>::empty(7);
+    ///
+    /// digraph.add_arc_weighted(0, 1, 1);
+    /// digraph.add_arc_weighted(1, 2, 1);
+    /// digraph.add_arc_weighted(1, 6, 6);
+    /// digraph.add_arc_weighted(2, 4, 1);
+    /// digraph.add_arc_weighted(3, 0, 2);
+    /// digraph.add_arc_weighted(4, 5, 2);
+    /// digraph.add_arc_weighted(5, 6, 1);
+    ///
+    /// assert!(
+    ///     DialPred::new(&digraph, once(0))
+    ///         .shortest_path(|v| v > 4)
+    ///         .unwrap()
+    ///         .eq(&[0, 1, 2, 4, 5])
+    /// );
+    /// ```
+    #[must_use]
+    pub fn shortest_path<P>(&mut self, is_target: P) -> Option<Vec<usize>>
+    where
+        D: OutNeighborsWeighted<Weight = usize>,
+        P: Fn(usize) -> bool,
+    {
+        let mut pred = PredecessorTree::new(self.digraph.order());
+
+        for (u, v) in self {
+            pred[v] = u;
+
+            if is_target(v) {
+                return pred.search_by(v, |_, b| b.is_none()).map(
+                    |mut path| {
+                        path.reverse();
+
+                        path
+                    },
+                );
+            }
+        }
+
+        None
+    }
+}
+
+impl<D> Iterator for DialPred<'_, D>
+where
+    D: Order + OutNeighborsWeighted<Weight = usize>,
+{
+    type Item = Step;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let bucket = self.buckets.front_mut()?;
+
+            let Some(step @ (_, u)) = bucket.pop() else {
+                drop(self.buckets.pop_front());
+                self.distance += 1;
+
+                continue;
+            };
+
+            let distance = self.distance;
+
+            if distance != self.dist[u] {
+                continue;
+            }
+
+            for (v, &w) in self.digraph.out_neighbors_weighted(u) {
+                if distance + w < self.dist[v] {
+                    self.dist[v] = distance + w;
+
+                    if self.buckets.len() <= w {
+                        self.buckets.resize_with(w + 1, Vec::new);
+                    }
+
+                    self.buckets[w].push((Some(u), v));
+                }
+            }
+
+            return Some(step);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::*,
+        crate::{
+            AddArcWeighted,
+            AdjacencyListWeighted,
+            ArcWeight,
+            DijkstraDist,
+            Empty,
+            proptest_strategy::digraph_weighted,
+            repr::adjacency_list_weighted::fixture::{
+                bang_jensen_94_usize,
+                bang_jensen_96_usize,
+                kattis_bryr_1_usize,
+                kattis_bryr_2_usize,
+                kattis_crosscountry_usize,
+                kattis_shortestpath1_usize,
+            },
+        },
+        proptest::prelude::*,
+        std::iter::once,
+    };
+
+    #[test]
+    fn iter_duplicate_sources() {
+        let digraph = kattis_shortestpath1_usize();
+
+        assert!(DialPred::new(&digraph, [0, 0].into_iter()).eq([
+            (None, 0),
+            (Some(0), 1),
+            (Some(1), 2),
+        ]));
+    }
+
+    #[test]
+    fn iter_stale_entry() {
+        let mut digraph = AdjacencyListWeighted::<usize>::empty(4);
+
+        digraph.add_arc_weighted(0, 1, 10);
+        digraph.add_arc_weighted(0, 2, 1);
+        digraph.add_arc_weighted(1, 3, 20);
+        digraph.add_arc_weighted(2, 1, 1);
+
+        assert!(DialPred::new(&digraph, once(0)).eq([
+            (None, 0),
+            (Some(0), 2),
+            (Some(2), 1),
+            (Some(1), 3),
+        ]));
+    }
+
+    #[test]
+    fn predecessors_bang_jensen_94() {
+        let digraph = bang_jensen_94_usize();
+
+        assert!(
+            DialPred::new(&digraph, once(0))
+                .predecessors()
+                .into_iter()
+                .eq([
+                    None,
+                    Some(0),
+                    Some(0),
+                    Some(2),
+                    Some(2),
+                    Some(2),
+                    Some(4)
+                ])
+        );
+    }
+
+    #[test]
+    fn predecessors_bang_jensen_96() {
+        let digraph = bang_jensen_96_usize();
+
+        assert!(
+            DialPred::new(&digraph, once(0))
+                .predecessors()
+                .into_iter()
+                .eq([None, Some(2), Some(0), Some(4), Some(2), Some(3)])
+        );
+    }
+
+    #[test]
+    fn predecessors_kattis_bryr_1() {
+        let digraph = kattis_bryr_1_usize();
+
+        assert!(
+            DialPred::new(&digraph, once(0))
+                .predecessors()
+                .into_iter()
+                .eq([None, Some(0), Some(0)])
+        );
+    }
+
+    #[test]
+    fn predecessors_kattis_bryr_2() {
+        let digraph = kattis_bryr_2_usize();
+
+        assert!(
+            DialPred::new(&digraph, once(0))
+                .predecessors()
+                .into_iter()
+                .eq([None, Some(0), Some(3), Some(0), Some(3), Some(4)])
+        );
+    }
+
+    #[test]
+    fn predecessors_kattis_crosscountry() {
+        let digraph = kattis_crosscountry_usize();
+
+        assert!(
+            DialPred::new(&digraph, once(0))
+                .predecessors()
+                .into_iter()
+                .eq([None, Some(0), Some(0), Some(2)])
+        );
+    }
+
+    #[test]
+    fn predecessors_kattis_shortestpath1() {
+        let digraph = kattis_shortestpath1_usize();
+
+        assert!(
+            DialPred::new(&digraph, once(0))
+                .predecessors()
+                .into_iter()
+                .eq([None, Some(0), Some(1), None])
+        );
+    }
+
+    #[test]
+    fn shortest_path_bang_jensen_96() {
+        let digraph = bang_jensen_96_usize();
+
+        assert!(
+            DialPred::new(&digraph, once(0))
+                .shortest_path(|v| v == 5)
+                .unwrap()
+                .eq(&[0, 2, 4, 3, 5])
+        );
+    }
+
+    #[test]
+    fn shortest_path_kattis_shortestpath1() {
+        let digraph = kattis_shortestpath1_usize();
+
+        assert!(
+            DialPred::new(&digraph, once(0))
+                .shortest_path(|v| v == 3)
+                .is_none()
+        );
+    }
+
+    proptest! {
+        #[test]
+        fn shortest_path_weight_eq_dijkstra_dist(
+            order in 1..8_usize,
+            arcs in prop::collection::vec(
+                (0..8_usize, 0..8_usize, 0..10_usize),
+                0..24,
+            ),
+            t in 0..8_usize,
+        ) {
+            let digraph = digraph_weighted(order, &arcs);
+            let t = t % order;
+            let dist = DijkstraDist::new(&digraph, once(0)).distances();
+
+            let path = DialPred::new(&digraph, once(0))
+                .shortest_path(|v| v == t);
+
+            if dist[t] == usize::MAX {
+                assert_eq!(path, None);
+            } else {
+                let weight = path
+                    .unwrap()
+                    .windows(2)
+                    .map(|w| *digraph.arc_weight(w[0], w[1]).unwrap())
+                    .sum::<usize>();
+
+                assert_eq!(weight, dist[t]);
+            }
+        }
+    }
+}
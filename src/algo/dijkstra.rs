@@ -150,9 +150,7 @@ use {
 /// ```
 #[derive(Clone, Debug)]
 pub struct Dijkstra<'a, D> {
-    digraph: &'a D,
-    dist: Vec<usize>,
-    heap: BinaryHeap<(Reverse<usize>, usize)>,
+    search: Search<'a, D, usize>,
 }
 
 impl<'a, D> Dijkstra<'a, D>
@@ -171,22 +169,156 @@ where
         D: Order,
         T: Iterator<Item = usize> + Clone,
     {
-        let order = digraph.order();
-        let mut dist = vec![usize::MAX; order];
-        let mut heap = BinaryHeap::with_capacity(order);
-        let dist_ptr = dist.as_mut_ptr();
+        Self {
+            search: Search::new(digraph, sources),
+        }
+    }
 
-        for u in sources {
-            unsafe { *dist_ptr.add(u) = 0 };
+    /// Skip the vertices farther than `max_distance` from the sources.
+    ///
+    /// # Arguments
+    ///
+    /// * `max_distance`: The maximum distance from the sources.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use {
+    ///     graaf::{
+    ///         AddArcWeighted,
+    ///         AdjacencyListWeighted,
+    ///         Dijkstra,
+    ///         Empty,
+    ///     },
+    ///     std::iter::once,
+    /// };
+    ///
+    /// let mut digraph = AdjacencyListWeighted::<usize>::empty(7);
+    ///
+    /// digraph.add_arc_weighted(0, 1, 1);
+    /// digraph.add_arc_weighted(1, 2, 1);
+    /// digraph.add_arc_weighted(1, 6, 6);
+    /// digraph.add_arc_weighted(2, 4, 1);
+    /// digraph.add_arc_weighted(3, 0, 2);
+    /// digraph.add_arc_weighted(4, 5, 2);
+    /// digraph.add_arc_weighted(5, 6, 1);
+    ///
+    /// assert!(
+    ///     Dijkstra::new(&digraph, once(0))
+    ///         .max_distance(3)
+    ///         .eq([0, 1, 2, 4])
+    /// );
+    /// ```
+    #[must_use]
+    pub const fn max_distance(mut self, max_distance: usize) -> Self {
+        self.search.set_max_distance(max_distance);
 
-            heap.push((Reverse(0), u));
-        }
+        self
+    }
 
-        Self {
-            digraph,
-            dist,
-            heap,
-        }
+    /// Stop after visiting all target vertices.
+    ///
+    /// # Arguments
+    ///
+    /// * `targets`: The target vertices.
+    ///
+    /// # Panics
+    ///
+    /// Panics if a target vertex isn't in the digraph.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use {
+    ///     graaf::{
+    ///         AddArcWeighted,
+    ///         AdjacencyListWeighted,
+    ///         Dijkstra,
+    ///         Empty,
+    ///     },
+    ///     std::iter::once,
+    /// };
+    ///
+    /// let mut digraph = AdjacencyListWeighted::<usize>::empty(7);
+    ///
+    /// digraph.add_arc_weighted(0, 1, 1);
+    /// digraph.add_arc_weighted(1, 2, 1);
+    /// digraph.add_arc_weighted(1, 6, 6);
+    /// digraph.add_arc_weighted(2, 4, 1);
+    /// digraph.add_arc_weighted(3, 0, 2);
+    /// digraph.add_arc_weighted(4, 5, 2);
+    /// digraph.add_arc_weighted(5, 6, 1);
+    ///
+    /// assert!(
+    ///     Dijkstra::new(&digraph, once(0))
+    ///         .targets([1, 4].into_iter())
+    ///         .eq([0, 1, 2, 4])
+    /// );
+    /// ```
+    #[must_use]
+    pub fn targets<T>(mut self, targets: T) -> Self
+    where
+        T: Iterator<Item = usize>,
+    {
+        self.search.set_targets(targets);
+
+        self
+    }
+
+    /// Restart the search from new source vertices.
+    ///
+    /// The search keeps its maximum distance, its target vertices, and its
+    /// allocations. Only the vertices visited by the previous search are
+    /// reset.
+    ///
+    /// # Arguments
+    ///
+    /// * `sources`: The source vertices.
+    ///
+    /// # Panics
+    ///
+    /// Panics if a source vertex isn't in the digraph.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use {
+    ///     graaf::{
+    ///         AddArcWeighted,
+    ///         AdjacencyListWeighted,
+    ///         Dijkstra,
+    ///         Empty,
+    ///     },
+    ///     std::iter::once,
+    /// };
+    ///
+    /// let mut digraph = AdjacencyListWeighted::<usize>::empty(7);
+    ///
+    /// digraph.add_arc_weighted(0, 1, 1);
+    /// digraph.add_arc_weighted(1, 2, 1);
+    /// digraph.add_arc_weighted(1, 6, 6);
+    /// digraph.add_arc_weighted(2, 4, 1);
+    /// digraph.add_arc_weighted(3, 0, 2);
+    /// digraph.add_arc_weighted(4, 5, 2);
+    /// digraph.add_arc_weighted(5, 6, 1);
+    ///
+    /// let mut dijkstra = Dijkstra::new(&digraph, once(0)).max_distance(2);
+    ///
+    /// assert!(dijkstra.by_ref().eq([0, 1, 2]));
+    ///
+    /// dijkstra.reset(once(4));
+    ///
+    /// assert!(dijkstra.by_ref().eq([4, 5]));
+    ///
+    /// dijkstra.reset(once(3));
+    ///
+    /// assert!(dijkstra.eq([3, 0]));
+    /// ```
+    pub fn reset<T>(&mut self, sources: T)
+    where
+        T: Iterator<Item = usize>,
+    {
+        self.search.reset(sources);
     }
 }
 
@@ -197,27 +329,199 @@ where
     type Item = usize;
 
     fn next(&mut self) -> Option<Self::Item> {
-        let (Reverse(w_prev), u) = self.heap.pop()?;
-        let dist_ptr = self.dist.as_mut_ptr();
+        self.search.next().map(|(_, u)| u)
+    }
+}
 
-        for (v, w) in self.digraph.out_neighbors_weighted(u) {
-            let w_next = w + w_prev;
-            let dist_v = unsafe { dist_ptr.add(v) };
+/// A heap entry of [`Search`].
+///
+/// [`Dijkstra`] and [`DijkstraDist`](crate::DijkstraDist) store the vertex.
+/// [`DijkstraPred`](crate::DijkstraPred) stores the predecessor and the
+/// vertex, which also breaks ties between equally distant vertices.
+pub(crate) trait Entry: Copy + Ord {
+    /// The entry of a source vertex.
+    fn source(u: usize) -> Self;
 
-            unsafe {
-                if w_next < *dist_v {
-                    *dist_v = w_next;
+    /// The entry of vertex `v` reached along the arc `(u, v)`.
+    fn arc(u: usize, v: usize) -> Self;
 
-                    self.heap.push((Reverse(w_next), v));
-                }
+    /// The entry's vertex.
+    fn vertex(self) -> usize;
+}
+
+impl Entry for usize {
+    fn source(u: usize) -> Self {
+        u
+    }
+
+    fn arc(_: usize, v: usize) -> Self {
+        v
+    }
+
+    fn vertex(self) -> usize {
+        self
+    }
+}
+
+impl Entry for (Option<usize>, usize) {
+    fn source(u: usize) -> Self {
+        (None, u)
+    }
+
+    fn arc(u: usize, v: usize) -> Self {
+        (Some(u), v)
+    }
+
+    fn vertex(self) -> usize {
+        self.1
+    }
+}
+
+/// The search shared by [`Dijkstra`], [`DijkstraDist`](crate::DijkstraDist),
+/// and [`DijkstraPred`](crate::DijkstraPred).
+#[derive(Clone, Debug)]
+pub(crate) struct Search<'a, D, E> {
+    digraph: &'a D,
+    dist: Vec<usize>,
+    heap: BinaryHeap<(Reverse<usize>, E)>,
+    max_distance: usize,
+    targets: Option<Vec<bool>>,
+    target_count: usize,
+    remaining: usize,
+    touched: Vec<usize>,
+}
+
+impl<'a, D, E> Search<'a, D, E>
+where
+    E: Entry,
+{
+    pub(crate) fn new<T>(digraph: &'a D, sources: T) -> Self
+    where
+        D: Order,
+        T: Iterator<Item = usize>,
+    {
+        let order = digraph.order();
+
+        let mut search = Self {
+            digraph,
+            dist: vec![usize::MAX; order],
+            heap: BinaryHeap::with_capacity(order),
+            max_distance: usize::MAX,
+            targets: None,
+            target_count: 0,
+            remaining: 0,
+            touched: Vec::new(),
+        };
+
+        search.push_sources(sources);
+
+        search
+    }
+
+    /// The digraph's order.
+    pub(crate) fn order(&self) -> usize {
+        self.dist.len()
+    }
+
+    pub(crate) const fn set_max_distance(&mut self, max_distance: usize) {
+        self.max_distance = max_distance;
+    }
+
+    pub(crate) fn set_targets<T>(&mut self, targets: T)
+    where
+        T: Iterator<Item = usize>,
+    {
+        let mut is_target = vec![false; self.dist.len()];
+        let mut target_count = 0;
+
+        for t in targets {
+            if !is_target[t] {
+                is_target[t] = true;
+                target_count += 1;
+            }
+        }
+
+        self.targets = Some(is_target);
+        self.target_count = target_count;
+        self.remaining = target_count;
+    }
+
+    pub(crate) fn reset<T>(&mut self, sources: T)
+    where
+        T: Iterator<Item = usize>,
+    {
+        for u in self.touched.drain(..) {
+            self.dist[u] = usize::MAX;
+        }
+
+        self.heap.clear();
+        self.remaining = self.target_count;
+        self.push_sources(sources);
+    }
+
+    fn push_sources<T>(&mut self, sources: T)
+    where
+        T: Iterator<Item = usize>,
+    {
+        for u in sources {
+            // Each distinct source is settled once.
+            if self.dist[u] == 0 {
+                continue;
             }
+
+            self.touched.push(u);
+            self.dist[u] = 0;
+            self.heap.push((Reverse(0), E::source(u)));
         }
+    }
 
-        if unsafe { *dist_ptr.add(u) } == w_prev {
-            return Some(u);
+    /// Visit the next vertex and return its distance and heap entry.
+    pub(crate) fn next(&mut self) -> Option<(usize, E)>
+    where
+        D: OutNeighborsWeighted<Weight = usize>,
+    {
+        if self.targets.is_some() && self.remaining == 0 {
+            return None;
         }
 
-        None
+        let dist_ptr = self.dist.as_mut_ptr();
+
+        loop {
+            let (Reverse(w_prev), entry) = self.heap.pop()?;
+            let u = entry.vertex();
+
+            if unsafe { *dist_ptr.add(u) } != w_prev {
+                continue;
+            }
+
+            for (v, w) in self.digraph.out_neighbors_weighted(u) {
+                let w_next = w + w_prev;
+
+                if w_next > self.max_distance {
+                    continue;
+                }
+
+                let dist_v = unsafe { dist_ptr.add(v) };
+
+                unsafe {
+                    if w_next < *dist_v {
+                        if *dist_v == usize::MAX {
+                            self.touched.push(v);
+                        }
+
+                        *dist_v = w_next;
+
+                        self.heap.push((Reverse(w_next), E::arc(u, v)));
+                    }
+                }
+            }
+
+            if self.targets.as_ref().is_some_and(|targets| targets[u]) {
+                self.remaining -= 1;
+            }
+
+            return Some((w_prev, entry));
+        }
     }
 }
 
@@ -225,16 +529,24 @@ where
 mod tests {
     use {
         super::*,
-        crate::repr::adjacency_list_weighted::fixture::{
-            bang_jensen_94_usize,
-            bang_jensen_96_usize,
-            kattis_bryr_1_usize,
-            kattis_bryr_2_usize,
-            kattis_bryr_3_usize,
-            kattis_crosscountry_usize,
-            kattis_shortestpath1_usize,
+        crate::{
+            AddArcWeighted,
+            AdjacencyListWeighted,
+            Empty,
+            repr::adjacency_list_weighted::fixture::{
+                bang_jensen_94_usize,
+                bang_jensen_96_usize,
+                kattis_bryr_1_usize,
+                kattis_bryr_2_usize,
+                kattis_bryr_3_usize,
+                kattis_crosscountry_usize,
+                kattis_shortestpath1_usize,
+            },
+        },
+        std::iter::{
+            empty,
+            once,
         },
-        std::iter::once,
     };
 
     #[test]
@@ -251,6 +563,16 @@ mod tests {
         assert!(Dijkstra::new(&digraph, once(0)).eq([0, 2, 4, 1, 3, 5]));
     }
 
+    #[test]
+    fn iter_duplicate_sources() {
+        let digraph = bang_jensen_94_usize();
+
+        assert!(
+            Dijkstra::new(&digraph, [0, 0].into_iter())
+                .eq([0, 2, 1, 5, 4, 3, 6])
+        );
+    }
+
     #[test]
     fn iter_kattis_bryr_1() {
         let digraph = kattis_bryr_1_usize();
@@ -288,4 +610,120 @@ mod tests {
 
         assert!(Dijkstra::new(&digraph, once(0)).eq([0, 1, 2]));
     }
+
+    #[test]
+    fn iter_stale_entry() {
+        let mut digraph = AdjacencyListWeighted::<usize>::empty(4);
+
+        digraph.add_arc_weighted(0, 1, 10);
+        digraph.add_arc_weighted(0, 2, 1);
+        digraph.add_arc_weighted(1, 3, 20);
+        digraph.add_arc_weighted(2, 1, 1);
+
+        assert!(Dijkstra::new(&digraph, once(0)).eq([0, 2, 1, 3]));
+    }
+
+    #[test]
+    fn max_distance_kattis_crosscountry() {
+        let digraph = kattis_crosscountry_usize();
+
+        assert!(
+            Dijkstra::new(&digraph, once(0))
+                .max_distance(3)
+                .eq([0, 1, 2])
+        );
+    }
+
+    #[test]
+    fn max_distance_zero() {
+        let digraph = kattis_bryr_3_usize();
+
+        let mut vertices = Dijkstra::new(&digraph, once(0))
+            .max_distance(0)
+            .collect::<Vec<_>>();
+
+        vertices.sort_unstable();
+
+        assert!(vertices.eq(&[0, 1, 3, 4, 5, 7, 8]));
+    }
+
+    #[test]
+    fn reset_eq_new() {
+        let digraph = bang_jensen_94_usize();
+        let mut dijkstra = Dijkstra::new(&digraph, once(0));
+
+        for s in 0..7 {
+            dijkstra.reset(once(s));
+
+            assert!(dijkstra.by_ref().eq(Dijkstra::new(&digraph, once(s))));
+        }
+    }
+
+    #[test]
+    fn reset_max_distance() {
+        let digraph = bang_jensen_96_usize();
+        let mut dijkstra = Dijkstra::new(&digraph, once(0)).max_distance(3);
+
+        assert!(dijkstra.by_ref().eq([0, 2]));
+
+        dijkstra.reset(once(4));
+
+        assert!(dijkstra.by_ref().eq([4, 3, 2, 5]));
+    }
+
+    #[test]
+    fn reset_targets() {
+        let digraph = bang_jensen_94_usize();
+        let mut dijkstra =
+            Dijkstra::new(&digraph, once(0)).targets([2, 4].into_iter());
+
+        assert!(dijkstra.by_ref().eq([0, 2, 1, 5, 4]));
+
+        dijkstra.reset(once(2));
+
+        assert!(dijkstra.by_ref().eq([2, 5, 4]));
+    }
+
+    #[test]
+    fn targets_duplicate() {
+        let digraph = bang_jensen_94_usize();
+
+        assert!(
+            Dijkstra::new(&digraph, once(0))
+                .targets([1, 1, 1].into_iter())
+                .eq([0, 2, 1])
+        );
+    }
+
+    #[test]
+    fn targets_duplicate_sources() {
+        let digraph = bang_jensen_94_usize();
+
+        assert!(
+            Dijkstra::new(&digraph, [0, 0].into_iter())
+                .targets([0, 4].into_iter())
+                .eq([0, 2, 1, 5, 4])
+        );
+    }
+
+    #[test]
+    fn targets_empty() {
+        let digraph = bang_jensen_94_usize();
+
+        assert_eq!(
+            Dijkstra::new(&digraph, once(0)).targets(empty()).next(),
+            None
+        );
+    }
+
+    #[test]
+    fn targets_unreachable() {
+        let digraph = kattis_shortestpath1_usize();
+
+        assert!(
+            Dijkstra::new(&digraph, once(0))
+                .targets(once(3))
+                .eq([0, 1, 2])
+        );
+    }
 }
@@ -83,12 +83,11 @@
 //!   <https://doi.org/10.1007/BF01386390>
 
 use {
+    super::dijkstra::Search,
     crate::{
         Order,
         OutNeighborsWeighted,
     },
-    core::cmp::Reverse,
-    std::collections::BinaryHeap,
 };
 
 type Step = (usize, usize);
@@ -172,9 +171,7 @@ type Step = (usize, usize);
 ///   <https://doi.org/10.1007/BF01386390>
 #[derive(Clone, Debug)]
 pub struct DijkstraDist<'a, D> {
-    digraph: &'a D,
-    dist: Vec<usize>,
-    heap: BinaryHeap<(Reverse<usize>, usize)>,
+    search: Search<'a, D, usize>,
 }
 
 impl<'a, D> DijkstraDist<'a, D>
@@ -193,22 +190,157 @@ where
         D: Order,
         T: Iterator<Item = usize> + Clone,
     {
-        let order = digraph.order();
-        let mut heap = BinaryHeap::with_capacity(order);
-        let mut dist = vec![usize::MAX; order];
-        let dist_ptr = dist.as_mut_ptr();
+        Self {
+            search: Search::new(digraph, sources),
+        }
+    }
 
-        for u in sources {
-            unsafe { *dist_ptr.add(u) = 0 };
+    /// Skip the vertices farther than `max_distance` from the sources.
+    ///
+    /// # Arguments
+    ///
+    /// * `max_distance`: The maximum distance from the sources.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use {
+    ///     graaf::{
+    ///         AddArcWeighted,
+    ///         AdjacencyListWeighted,
+    ///         DijkstraDist,
+    ///         Empty,
+    ///     },
+    ///     std::iter::once,
+    /// };
+    ///
+    /// let mut digraph = AdjacencyListWeighted::<usize>::empty(7);
+    ///
+    /// digraph.add_arc_weighted(0, 1, 1);
+    /// digraph.add_arc_weighted(1, 2, 1);
+    /// digraph.add_arc_weighted(1, 6, 6);
+    /// digraph.add_arc_weighted(2, 4, 1);
+    /// digraph.add_arc_weighted(3, 0, 2);
+    /// digraph.add_arc_weighted(4, 5, 2);
+    /// digraph.add_arc_weighted(5, 6, 1);
+    ///
+    /// assert!(
+    ///     DijkstraDist::new(&digraph, once(0))
+    ///         .max_distance(3)
+    ///         .distances()
+    ///         .eq(&[0, 1, 2, usize::MAX, 3, usize::MAX, usize::MAX])
+    /// );
+    /// ```
+    #[must_use]
+    pub const fn max_distance(mut self, max_distance: usize) -> Self {
+        self.search.set_max_distance(max_distance);
 
-            heap.push((Reverse(0), u));
-        }
+        self
+    }
 
-        Self {
-            digraph,
-            dist,
-            heap,
-        }
+    /// Stop after visiting all target vertices.
+    ///
+    /// # Arguments
+    ///
+    /// * `targets`: The target vertices.
+    ///
+    /// # Panics
+    ///
+    /// Panics if a target vertex isn't in the digraph.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use {
+    ///     graaf::{
+    ///         AddArcWeighted,
+    ///         AdjacencyListWeighted,
+    ///         DijkstraDist,
+    ///         Empty,
+    ///     },
+    ///     std::iter::once,
+    /// };
+    ///
+    /// let mut digraph = AdjacencyListWeighted::<usize>::empty(7);
+    ///
+    /// digraph.add_arc_weighted(0, 1, 1);
+    /// digraph.add_arc_weighted(1, 2, 1);
+    /// digraph.add_arc_weighted(1, 6, 6);
+    /// digraph.add_arc_weighted(2, 4, 1);
+    /// digraph.add_arc_weighted(3, 0, 2);
+    /// digraph.add_arc_weighted(4, 5, 2);
+    /// digraph.add_arc_weighted(5, 6, 1);
+    ///
+    /// assert!(
+    ///     DijkstraDist::new(&digraph, once(0))
+    ///         .targets([1, 4].into_iter())
+    ///         .eq([(0, 0), (1, 1), (2, 2), (4, 3)])
+    /// );
+    /// ```
+    #[must_use]
+    pub fn targets<T>(mut self, targets: T) -> Self
+    where
+        T: Iterator<Item = usize>,
+    {
+        self.search.set_targets(targets);
+
+        self
+    }
+
+    /// Restart the search from new source vertices.
+    ///
+    /// The search keeps its maximum distance, its target vertices, and its
+    /// allocations. Only the vertices visited by the previous search are
+    /// reset.
+    ///
+    /// # Arguments
+    ///
+    /// * `sources`: The source vertices.
+    ///
+    /// # Panics
+    ///
+    /// Panics if a source vertex isn't in the digraph.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use {
+    ///     graaf::{
+    ///         AddArcWeighted,
+    ///         AdjacencyListWeighted,
+    ///         DijkstraDist,
+    ///         Empty,
+    ///     },
+    ///     std::iter::once,
+    /// };
+    ///
+    /// let mut digraph = AdjacencyListWeighted::<usize>::empty(7);
+    ///
+    /// digraph.add_arc_weighted(0, 1, 1);
+    /// digraph.add_arc_weighted(1, 2, 1);
+    /// digraph.add_arc_weighted(1, 6, 6);
+    /// digraph.add_arc_weighted(2, 4, 1);
+    /// digraph.add_arc_weighted(3, 0, 2);
+    /// digraph.add_arc_weighted(4, 5, 2);
+    /// digraph.add_arc_weighted(5, 6, 1);
+    ///
+    /// let mut search = DijkstraDist::new(&digraph, once(0)).max_distance(2);
+    ///
+    /// assert!(search.by_ref().eq([(0, 0), (1, 1), (2, 2)]));
+    ///
+    /// search.reset(once(4));
+    ///
+    /// assert!(search.by_ref().eq([(4, 0), (5, 2)]));
+    ///
+    /// search.reset(once(3));
+    ///
+    /// assert!(search.eq([(3, 0), (0, 2)]));
+    /// ```
+    pub fn reset<T>(&mut self, sources: T)
+    where
+        T: Iterator<Item = usize>,
+    {
+        self.search.reset(sources);
     }
 
     /// Find the distances from the source vertices to all other vertices.
@@ -297,7 +429,7 @@ where
     where
         D: OutNeighborsWeighted<Weight = usize>,
     {
-        let mut dist = vec![usize::MAX; self.digraph.order()];
+        let mut dist = vec![usize::MAX; self.search.order()];
         let ptr = dist.as_mut_ptr();
 
         for u in self {
@@ -315,25 +447,7 @@ where
     type Item = Step;
 
     fn next(&mut self) -> Option<Self::Item> {
-        let (Reverse(w_prev), u) = self.heap.pop()?;
-        let dist_ptr = self.dist.as_mut_ptr();
-
-        for (v, w) in self.digraph.out_neighbors_weighted(u) {
-            let w_next = w_prev + w;
-            let dist_v = unsafe { *dist_ptr.add(v) };
-
-            if w_next < dist_v {
-                unsafe { *dist_ptr.add(v) = w_next };
-
-                self.heap.push((Reverse(w_next), v));
-            }
-        }
-
-        if w_prev == unsafe { *dist_ptr.add(u) } {
-            return Some((u, w_prev));
-        }
-
-        None
+        self.search.next().map(|(distance, u)| (u, distance))
     }
 }
 
@@ -341,14 +455,19 @@ where
 mod tests {
     use {
         super::*,
-        crate::repr::adjacency_list_weighted::fixture::{
-            bang_jensen_94_usize,
-            bang_jensen_96_usize,
-            kattis_bryr_1_usize,
-            kattis_bryr_2_usize,
-            kattis_bryr_3_usize,
-            kattis_crosscountry_usize,
-            kattis_shortestpath1_usize,
+        crate::{
+            AddArcWeighted,
+            AdjacencyListWeighted,
+            Empty,
+            repr::adjacency_list_weighted::fixture::{
+                bang_jensen_94_usize,
+                bang_jensen_96_usize,
+                kattis_bryr_1_usize,
+                kattis_bryr_2_usize,
+                kattis_bryr_3_usize,
+                kattis_crosscountry_usize,
+                kattis_shortestpath1_usize,
+            },
         },
         std::iter::once,
     };
@@ -531,4 +650,71 @@ mod tests {
                 .eq(&[0, 2, 4, usize::MAX])
         );
     }
+
+    #[test]
+    fn iter_stale_entry() {
+        let mut digraph = AdjacencyListWeighted::<usize>::empty(4);
+
+        digraph.add_arc_weighted(0, 1, 10);
+        digraph.add_arc_weighted(0, 2, 1);
+        digraph.add_arc_weighted(1, 3, 20);
+        digraph.add_arc_weighted(2, 1, 1);
+
+        assert!(DijkstraDist::new(&digraph, once(0)).eq([
+            (0, 0),
+            (2, 1),
+            (1, 2),
+            (3, 22)
+        ]));
+    }
+
+    #[test]
+    fn max_distance_kattis_crosscountry() {
+        let digraph = kattis_crosscountry_usize();
+
+        assert!(
+            DijkstraDist::new(&digraph, once(0))
+                .max_distance(3)
+                .distances()
+                .iter()
+                .eq(&[0, 1, 3, usize::MAX])
+        );
+    }
+
+    #[test]
+    fn reset_eq_new() {
+        let digraph = bang_jensen_94_usize();
+        let mut search = DijkstraDist::new(&digraph, once(0));
+
+        for s in 0..7 {
+            search.reset(once(s));
+
+            assert!(search.by_ref().eq(DijkstraDist::new(&digraph, once(s))));
+        }
+    }
+
+    #[test]
+    fn reset_targets() {
+        let digraph = bang_jensen_94_usize();
+        let mut search =
+            DijkstraDist::new(&digraph, once(0)).targets([2, 4].into_iter());
+
+        assert!(search.by_ref().map(|(u, _)| u).eq([0, 2, 1, 5, 4]));
+
+        search.reset(once(2));
+
+        assert!(search.by_ref().map(|(u, _)| u).eq([2, 5, 4]));
+    }
+
+    #[test]
+    fn targets_duplicate_sources() {
+        let digraph = bang_jensen_94_usize();
+
+        assert!(
+            DijkstraDist::new(&digraph, [0, 0].into_iter())
+                .targets([0, 4].into_iter())
+                .map(|(u, _)| u)
+                .eq([0, 2, 1, 5, 4])
+        );
+    }
 }
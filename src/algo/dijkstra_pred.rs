@@ -90,13 +90,12 @@
 //!   <https://doi.org/10.1007/BF01386390>
 
 use {
+    super::dijkstra::Search,
     crate::{
         Order,
         OutNeighborsWeighted,
         PredecessorTree,
     },
-    core::cmp::Reverse,
-    std::collections::BinaryHeap,
 };
 
 type Step = (Option<usize>, usize);
@@ -187,9 +186,7 @@ type Step = (Option<usize>, usize);
 ///   <https://doi.org/10.1007/BF01386390>
 #[derive(Clone, Debug)]
 pub struct DijkstraPred<'a, D> {
-    digraph: &'a D,
-    dist: Vec<usize>,
-    heap: BinaryHeap<(Reverse<usize>, Step)>,
+    search: Search<'a, D, (Option<usize>, usize)>,
 }
 
 impl<'a, D> DijkstraPred<'a, D>
@@ -208,22 +205,158 @@ where
         D: Order,
         T: Iterator<Item = usize> + Clone,
     {
-        let order = digraph.order();
-        let mut heap = BinaryHeap::with_capacity(order);
-        let mut dist = vec![usize::MAX; order];
-        let dist_ptr = dist.as_mut_ptr();
+        Self {
+            search: Search::new(digraph, sources),
+        }
+    }
 
-        for u in sources {
-            unsafe { *dist_ptr.add(u) = 0 };
+    /// Skip the vertices farther than `max_distance` from the sources.
+    ///
+    /// # Arguments
+    ///
+    /// * `max_distance`: The maximum distance from the sources.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use {
+    ///     graaf::{
+    ///         AddArcWeighted,
+    ///         AdjacencyListWeighted,
+    ///         DijkstraPred,
+    ///         Empty,
+    ///     },
+    ///     std::iter::once,
+    /// };
+    ///
+    /// let mut digraph = AdjacencyListWeighted::<usize>::empty(7);
+    ///
+    /// digraph.add_arc_weighted(0, 1, 1);
+    /// digraph.add_arc_weighted(1, 2, 1);
+    /// digraph.add_arc_weighted(1, 6, 6);
+    /// digraph.add_arc_weighted(2, 4, 1);
+    /// digraph.add_arc_weighted(3, 0, 2);
+    /// digraph.add_arc_weighted(4, 5, 2);
+    /// digraph.add_arc_weighted(5, 6, 1);
+    ///
+    /// assert!(
+    ///     DijkstraPred::new(&digraph, once(0))
+    ///         .max_distance(3)
+    ///         .predecessors()
+    ///         .into_iter()
+    ///         .eq([None, Some(0), Some(1), None, Some(2), None, None])
+    /// );
+    /// ```
+    #[must_use]
+    pub const fn max_distance(mut self, max_distance: usize) -> Self {
+        self.search.set_max_distance(max_distance);
 
-            heap.push((Reverse(0), (None, u)));
-        }
+        self
+    }
 
-        Self {
-            digraph,
-            dist,
-            heap,
-        }
+    /// Stop after visiting all target vertices.
+    ///
+    /// # Arguments
+    ///
+    /// * `targets`: The target vertices.
+    ///
+    /// # Panics
+    ///
+    /// Panics if a target vertex isn't in the digraph.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use {
+    ///     graaf::{
+    ///         AddArcWeighted,
+    ///         AdjacencyListWeighted,
+    ///         DijkstraPred,
+    ///         Empty,
+    ///     },
+    ///     std::iter::once,
+    /// };
+    ///
+    /// let mut digraph = AdjacencyListWeighted::<usize>::empty(7);
+    ///
+    /// digraph.add_arc_weighted(0, 1, 1);
+    /// digraph.add_arc_weighted(1, 2, 1);
+    /// digraph.add_arc_weighted(1, 6, 6);
+    /// digraph.add_arc_weighted(2, 4, 1);
+    /// digraph.add_arc_weighted(3, 0, 2);
+    /// digraph.add_arc_weighted(4, 5, 2);
+    /// digraph.add_arc_weighted(5, 6, 1);
+    ///
+    /// assert!(
+    ///     DijkstraPred::new(&digraph, once(0))
+    ///         .targets([1, 4].into_iter())
+    ///         .eq([(None, 0), (Some(0), 1), (Some(1), 2), (Some(2), 4)])
+    /// );
+    /// ```
+    #[must_use]
+    pub fn targets<T>(mut self, targets: T) -> Self
+    where
+        T: Iterator<Item = usize>,
+    {
+        self.search.set_targets(targets);
+
+        self
+    }
+
+    /// Restart the search from new source vertices.
+    ///
+    /// The search keeps its maximum distance, its target vertices, and its
+    /// allocations. Only the vertices visited by the previous search are
+    /// reset.
+    ///
+    /// # Arguments
+    ///
+    /// * `sources`: The source vertices.
+    ///
+    /// # Panics
+    ///
+    /// Panics if a source vertex isn't in the digraph.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use {
+    ///     graaf::{
+    ///         AddArcWeighted,
+    ///         AdjacencyListWeighted,
+    ///         DijkstraPred,
+    ///         Empty,
+    ///     },
+    ///     std::iter::once,
+    /// };
+    ///
+    /// let mut digraph = AdjacencyListWeighted::<usize>::empty(7);
+    ///
+    /// digraph.add_arc_weighted(0, 1, 1);
+    /// digraph.add_arc_weighted(1, 2, 1);
+    /// digraph.add_arc_weighted(1, 6, 6);
+    /// digraph.add_arc_weighted(2, 4, 1);
+    /// digraph.add_arc_weighted(3, 0, 2);
+    /// digraph.add_arc_weighted(4, 5, 2);
+    /// digraph.add_arc_weighted(5, 6, 1);
+    ///
+    /// let mut search = DijkstraPred::new(&digraph, once(0)).max_distance(2);
+    ///
+    /// assert!(search.by_ref().eq([(None, 0), (Some(0), 1), (Some(1), 2)]));
+    ///
+    /// search.reset(once(4));
+    ///
+    /// assert!(search.by_ref().eq([(None, 4), (Some(4), 5)]));
+    ///
+    /// search.reset(once(3));
+    ///
+    /// assert!(search.eq([(None, 3), (Some(3), 0)]));
+    /// ```
+    pub fn reset<T>(&mut self, sources: T)
+    where
+        T: Iterator<Item = usize>,
+    {
+        self.search.reset(sources);
     }
 
     /// Find the predecessor tree.
@@ -319,7 +452,7 @@ where
     where
         D: Order + OutNeighborsWeighted<Weight = usize>,
     {
-        let mut pred = PredecessorTree::new(self.digraph.order());
+        let mut pred = PredecessorTree::new(self.search.order());
         let pred_ptr = pred.pred.as_mut_ptr();
 
         for (u, v) in self {
@@ -436,7 +569,7 @@ where
         D: Order + OutNeighborsWeighted<Weight = usize>,
         P: Fn(usize) -> bool,
     {
-        let mut pred = PredecessorTree::new(self.digraph.order());
+        let mut pred = PredecessorTree::new(self.search.order());
         let pred_ptr = pred.pred.as_mut_ptr();
 
         for (u, v) in self {
@@ -464,25 +597,7 @@ where
     type Item = Step;
 
     fn next(&mut self) -> Option<Self::Item> {
-        let (Reverse(distance), step @ (_, v)) = self.heap.pop()?;
-        let dist_ptr = self.dist.as_mut_ptr();
-
-        for (x, w) in self.digraph.out_neighbors_weighted(v) {
-            let distance = distance + w;
-            let dist_x = unsafe { dist_ptr.add(x) };
-
-            if distance < unsafe { *dist_x } {
-                unsafe { *dist_x = distance };
-
-                self.heap.push((Reverse(distance), (Some(v), x)));
-            }
-        }
-
-        if distance == unsafe { *dist_ptr.add(v) } {
-            return Some(step);
-        }
-
-        None
+        self.search.next().map(|(_, step)| step)
     }
 }
 
@@ -490,14 +605,19 @@ where
 mod tests {
     use {
         super::*,
-        crate::repr::adjacency_list_weighted::fixture::{
-            bang_jensen_94_usize,
-            bang_jensen_96_usize,
-            kattis_bryr_1_usize,
-            kattis_bryr_2_usize,
-            kattis_bryr_3_usize,
-            kattis_crosscountry_usize,
-            kattis_shortestpath1_usize,
+        crate::{
+            AddArcWeighted,
+            AdjacencyListWeighted,
+            Empty,
+            repr::adjacency_list_weighted::fixture::{
+                bang_jensen_94_usize,
+                bang_jensen_96_usize,
+                kattis_bryr_1_usize,
+                kattis_bryr_2_usize,
+                kattis_bryr_3_usize,
+                kattis_crosscountry_usize,
+                kattis_shortestpath1_usize,
+            },
         },
         std::iter::once,
     };
@@ -782,4 +902,71 @@ mod tests {
                 .is_none()
         );
     }
+
+    #[test]
+    fn iter_stale_entry() {
+        let mut digraph = AdjacencyListWeighted::<usize>::empty(4);
+
+        digraph.add_arc_weighted(0, 1, 10);
+        digraph.add_arc_weighted(0, 2, 1);
+        digraph.add_arc_weighted(1, 3, 20);
+        digraph.add_arc_weighted(2, 1, 1);
+
+        assert!(DijkstraPred::new(&digraph, once(0)).eq([
+            (None, 0),
+            (Some(0), 2),
+            (Some(2), 1),
+            (Some(1), 3),
+        ]));
+    }
+
+    #[test]
+    fn max_distance_kattis_crosscountry() {
+        let digraph = kattis_crosscountry_usize();
+
+        assert!(
+            DijkstraPred::new(&digraph, once(0))
+                .max_distance(3)
+                .predecessors()
+                .into_iter()
+                .eq([None, Some(0), Some(0), None])
+        );
+    }
+
+    #[test]
+    fn reset_eq_new() {
+        let digraph = bang_jensen_94_usize();
+        let mut search = DijkstraPred::new(&digraph, once(0));
+
+        for s in 0..7 {
+            search.reset(once(s));
+
+            assert!(search.by_ref().eq(DijkstraPred::new(&digraph, once(s))));
+        }
+    }
+
+    #[test]
+    fn reset_targets() {
+        let digraph = bang_jensen_94_usize();
+        let mut search =
+            DijkstraPred::new(&digraph, once(0)).targets([2, 4].into_iter());
+
+        assert!(search.by_ref().map(|(_, u)| u).eq([0, 2, 1, 5, 4]));
+
+        search.reset(once(2));
+
+        assert!(search.by_ref().map(|(_, u)| u).eq([2, 5, 4]));
+    }
+
+    #[test]
+    fn targets_duplicate_sources() {
+        let digraph = bang_jensen_94_usize();
+
+        assert!(
+            DijkstraPred::new(&digraph, [0, 0].into_iter())
+                .targets([0, 4].into_iter())
+                .map(|(_, u)| u)
+                .eq([0, 2, 1, 5, 4])
+        );
+    }
 }
@@ -46,7 +46,7 @@
 //! digraph.add_arc_weighted(6, 2, 5);
 //!
 //! let mut floyd_warshall = FloydWarshall::new(&digraph);
-//! let dist = floyd_warshall.distances();
+//! let dist = floyd_warshall.distances().unwrap();
 //!
 //! assert!(dist[0..7].eq(&[0, 5, 3, 2, 3, 3, 4]));
 //! assert!(dist[7..14].eq(&[3, 0, 5, 1, 2, 2, 3]));
@@ -63,7 +63,9 @@ use std::{
         Greater,
         Less,
     },
+    iter::Sum,
     ops::{
+        Add,
         Index,
         IndexMut,
         Range,
@@ -118,7 +120,7 @@ use std::{
 /// digraph.add_arc_weighted(6, 2, 5);
 ///
 /// let mut floyd_warshall = FloydWarshall::new(&digraph);
-/// let dist = floyd_warshall.distances();
+/// let dist = floyd_warshall.distances().unwrap();
 ///
 /// assert!(dist[0..7].eq(&[0, 5, 3, 2, 3, 3, 4]));
 /// assert!(dist[7..14].eq(&[3, 0, 5, 1, 2, 2, 3]));
@@ -234,6 +236,7 @@ impl<W> DistanceMatrix<W> {
     /// assert!(
     ///     FloydWarshall::new(&digraph)
     ///         .distances()
+    ///         .unwrap()
     ///         .center()
     ///         .iter()
     ///         .eq(&[0, 1])
@@ -303,7 +306,10 @@ impl<W> DistanceMatrix<W> {
     /// digraph.add_arc_weighted(6, 1, 8);
     /// digraph.add_arc_weighted(6, 2, 5);
     ///
-    /// assert_eq!(FloydWarshall::new(&digraph).distances().diameter(), &17);
+    /// assert_eq!(
+    ///     FloydWarshall::new(&digraph).distances().unwrap().diameter(),
+    ///     &17
+    /// );
     /// ```
     #[must_use]
     pub fn diameter(&self) -> &W
@@ -351,6 +357,7 @@ impl<W> DistanceMatrix<W> {
     /// assert!(
     ///     FloydWarshall::new(&digraph)
     ///         .distances()
+    ///         .unwrap()
     ///         .eccentricities()
     ///         .eq(&[5, 5, 14, 11, 17, 11, 10])
     /// );
@@ -364,6 +371,65 @@ impl<W> DistanceMatrix<W> {
             .map(|row| row.iter().max().unwrap_or(&self.infinity))
     }
 
+    /// Return a digraph's girth.
+    ///
+    /// A digraph's girth is the weight of its shortest circuit. The girth is
+    /// the distance matrix's infinity if the digraph is acyclic. The arc
+    /// weights must be non-negative.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use graaf::{
+    ///     AddArcWeighted,
+    ///     AdjacencyListWeighted,
+    ///     DistanceMatrix,
+    ///     Empty,
+    ///     FloydWarshall,
+    /// };
+    ///
+    /// let mut digraph = AdjacencyListWeighted::<isize>::empty(7);
+    ///
+    /// digraph.add_arc_weighted(0, 1, 5);
+    /// digraph.add_arc_weighted(0, 2, 3);
+    /// digraph.add_arc_weighted(0, 3, 2);
+    /// digraph.add_arc_weighted(0, 4, 4);
+    /// digraph.add_arc_weighted(1, 0, 3);
+    /// digraph.add_arc_weighted(1, 3, 1);
+    /// digraph.add_arc_weighted(1, 4, 2);
+    /// digraph.add_arc_weighted(2, 6, 4);
+    /// digraph.add_arc_weighted(3, 4, 1);
+    /// digraph.add_arc_weighted(3, 5, 1);
+    /// digraph.add_arc_weighted(4, 2, 3);
+    /// digraph.add_arc_weighted(5, 6, 1);
+    /// digraph.add_arc_weighted(6, 0, 9);
+    /// digraph.add_arc_weighted(6, 1, 8);
+    /// digraph.add_arc_weighted(6, 2, 5);
+    ///
+    /// assert_eq!(FloydWarshall::new(&digraph).distances().unwrap().girth(), 8);
+    /// ```
+    #[must_use]
+    pub fn girth(&self) -> W
+    where
+        W: Add<Output = W> + Copy + Ord,
+    {
+        let mut girth = self.infinity;
+
+        // Every circuit passes through two distinct vertices.
+        for u in 0..self.order {
+            for v in u + 1..self.order {
+                let a = self[(u, v)];
+                let b = self[(v, u)];
+
+                if a != self.infinity && b != self.infinity {
+                    girth = girth.min(a + b);
+                }
+            }
+        }
+
+        girth
+    }
+
     /// Check whether the distance matrix is connected.
     ///
     /// A distance matrix is connected if the eccentricity of every vertex is
@@ -398,7 +464,12 @@ impl<W> DistanceMatrix<W> {
     /// digraph.add_arc_weighted(6, 1, 8);
     /// digraph.add_arc_weighted(6, 2, 5);
     ///
-    /// assert!(FloydWarshall::new(&digraph).distances().is_connected());
+    /// assert!(
+    ///     FloydWarshall::new(&digraph)
+    ///         .distances()
+    ///         .unwrap()
+    ///         .is_connected()
+    /// );
     /// ```
     #[must_use]
     pub fn is_connected(&self) -> bool
@@ -442,7 +513,13 @@ impl<W> DistanceMatrix<W> {
     /// digraph.add_arc_weighted(6, 1, 8);
     /// digraph.add_arc_weighted(6, 2, 5);
     ///
-    /// assert!(FloydWarshall::new(&digraph).distances().periphery().eq([4]));
+    /// assert!(
+    ///     FloydWarshall::new(&digraph)
+    ///         .distances()
+    ///         .unwrap()
+    ///         .periphery()
+    ///         .eq([4])
+    /// );
     /// ```
     pub fn periphery(&self) -> impl Iterator<Item = usize>
     where
@@ -454,6 +531,373 @@ impl<W> DistanceMatrix<W> {
         ecc.enumerate()
             .filter_map(move |(i, e)| (e == diameter).then_some(i))
     }
+
+    /// Return a digraph's radius.
+    ///
+    /// A digraph's radius is its minimum eccentricity.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use graaf::{
+    ///     AddArcWeighted,
+    ///     AdjacencyListWeighted,
+    ///     DistanceMatrix,
+    ///     Empty,
+    ///     FloydWarshall,
+    /// };
+    ///
+    /// let mut digraph = AdjacencyListWeighted::<isize>::empty(7);
+    ///
+    /// digraph.add_arc_weighted(0, 1, 5);
+    /// digraph.add_arc_weighted(0, 2, 3);
+    /// digraph.add_arc_weighted(0, 3, 2);
+    /// digraph.add_arc_weighted(0, 4, 4);
+    /// digraph.add_arc_weighted(1, 0, 3);
+    /// digraph.add_arc_weighted(1, 3, 1);
+    /// digraph.add_arc_weighted(1, 4, 2);
+    /// digraph.add_arc_weighted(2, 6, 4);
+    /// digraph.add_arc_weighted(3, 4, 1);
+    /// digraph.add_arc_weighted(3, 5, 1);
+    /// digraph.add_arc_weighted(4, 2, 3);
+    /// digraph.add_arc_weighted(5, 6, 1);
+    /// digraph.add_arc_weighted(6, 0, 9);
+    /// digraph.add_arc_weighted(6, 1, 8);
+    /// digraph.add_arc_weighted(6, 2, 5);
+    ///
+    /// assert_eq!(
+    ///     FloydWarshall::new(&digraph).distances().unwrap().radius(),
+    ///     &5
+    /// );
+    /// ```
+    #[must_use]
+    pub fn radius(&self) -> &W
+    where
+        W: Copy + Ord,
+    {
+        self.eccentricities().min().unwrap_or(&self.infinity)
+    }
+
+    /// Return the number of vertices reachable from each vertex.
+    ///
+    /// A vertex doesn't count itself.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use graaf::{
+    ///     AddArcWeighted,
+    ///     AdjacencyListWeighted,
+    ///     Empty,
+    ///     FloydWarshall,
+    /// };
+    ///
+    /// let mut digraph = AdjacencyListWeighted::<isize>::empty(4);
+    ///
+    /// digraph.add_arc_weighted(0, 1, 2);
+    /// digraph.add_arc_weighted(1, 2, 2);
+    /// digraph.add_arc_weighted(3, 0, 2);
+    ///
+    /// assert!(
+    ///     FloydWarshall::new(&digraph)
+    ///         .distances()
+    ///         .unwrap()
+    ///         .reachability_counts()
+    ///         .eq([2, 1, 0, 3])
+    /// );
+    /// ```
+    pub fn reachability_counts(&self) -> impl Iterator<Item = usize> + '_
+    where
+        W: PartialEq,
+    {
+        self.dist.chunks(self.order).enumerate().map(|(u, row)| {
+            row.iter()
+                .enumerate()
+                .filter(|&(v, d)| v != u && d != &self.infinity)
+                .count()
+        })
+    }
+
+    /// Return a digraph's Wiener index.
+    ///
+    /// A digraph's Wiener index is the sum of the distances between all
+    /// ordered pairs of distinct vertices. The Wiener index is the distance
+    /// matrix's infinity if the digraph isn't strongly connected.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use graaf::{
+    ///     AddArcWeighted,
+    ///     AdjacencyListWeighted,
+    ///     DistanceMatrix,
+    ///     Empty,
+    ///     FloydWarshall,
+    /// };
+    ///
+    /// let mut digraph = AdjacencyListWeighted::<isize>::empty(7);
+    ///
+    /// digraph.add_arc_weighted(0, 1, 5);
+    /// digraph.add_arc_weighted(0, 2, 3);
+    /// digraph.add_arc_weighted(0, 3, 2);
+    /// digraph.add_arc_weighted(0, 4, 4);
+    /// digraph.add_arc_weighted(1, 0, 3);
+    /// digraph.add_arc_weighted(1, 3, 1);
+    /// digraph.add_arc_weighted(1, 4, 2);
+    /// digraph.add_arc_weighted(2, 6, 4);
+    /// digraph.add_arc_weighted(3, 4, 1);
+    /// digraph.add_arc_weighted(3, 5, 1);
+    /// digraph.add_arc_weighted(4, 2, 3);
+    /// digraph.add_arc_weighted(5, 6, 1);
+    /// digraph.add_arc_weighted(6, 0, 9);
+    /// digraph.add_arc_weighted(6, 1, 8);
+    /// digraph.add_arc_weighted(6, 2, 5);
+    ///
+    /// assert_eq!(
+    ///     FloydWarshall::new(&digraph)
+    ///         .distances()
+    ///         .unwrap()
+    ///         .wiener_index(),
+    ///     307
+    /// );
+    /// ```
+    #[doc(alias = "wiener")]
+    #[must_use]
+    pub fn wiener_index(&self) -> W
+    where
+        W: Copy + Ord + Sum,
+    {
+        let distances = self
+            .dist
+            .iter()
+            .enumerate()
+            .filter(|&(i, _)| i % (self.order + 1) != 0)
+            .map(|(_, &d)| d);
+
+        if distances.clone().any(|d| d == self.infinity) {
+            return self.infinity;
+        }
+
+        distances.sum()
+    }
+}
+
+/// Convert a count or a positive distance to `f64`.
+///
+/// Values above `2^53` lose precision, which doesn't matter for the ratios
+/// computed from them.
+#[allow(clippy::cast_precision_loss)]
+const fn to_f64(n: usize) -> f64 {
+    n as f64
+}
+
+impl DistanceMatrix<isize> {
+    /// Return a digraph's average path length.
+    ///
+    /// A digraph's average path length is its Wiener index divided by the
+    /// number of ordered pairs of distinct vertices. The average path length
+    /// is infinite if the digraph isn't strongly connected.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use graaf::{
+    ///     AddArcWeighted,
+    ///     AdjacencyListWeighted,
+    ///     DistanceMatrix,
+    ///     Empty,
+    ///     FloydWarshall,
+    /// };
+    ///
+    /// let mut digraph = AdjacencyListWeighted::<isize>::empty(7);
+    ///
+    /// digraph.add_arc_weighted(0, 1, 5);
+    /// digraph.add_arc_weighted(0, 2, 3);
+    /// digraph.add_arc_weighted(0, 3, 2);
+    /// digraph.add_arc_weighted(0, 4, 4);
+    /// digraph.add_arc_weighted(1, 0, 3);
+    /// digraph.add_arc_weighted(1, 3, 1);
+    /// digraph.add_arc_weighted(1, 4, 2);
+    /// digraph.add_arc_weighted(2, 6, 4);
+    /// digraph.add_arc_weighted(3, 4, 1);
+    /// digraph.add_arc_weighted(3, 5, 1);
+    /// digraph.add_arc_weighted(4, 2, 3);
+    /// digraph.add_arc_weighted(5, 6, 1);
+    /// digraph.add_arc_weighted(6, 0, 9);
+    /// digraph.add_arc_weighted(6, 1, 8);
+    /// digraph.add_arc_weighted(6, 2, 5);
+    ///
+    /// let dist = FloydWarshall::new(&digraph).distances().unwrap().clone();
+    ///
+    /// assert!((dist.average_path_length() - 307.0 / 42.0).abs() < 1e-9);
+    /// ```
+    #[must_use]
+    pub fn average_path_length(&self) -> f64 {
+        let wiener_index = self.wiener_index();
+
+        if wiener_index == self.infinity {
+            return f64::INFINITY;
+        }
+
+        if self.order == 1 {
+            return 0.0;
+        }
+
+        let average = to_f64(wiener_index.unsigned_abs())
+            / to_f64(self.order * (self.order - 1));
+
+        if wiener_index < 0 { -average } else { average }
+    }
+
+    /// Return the vertices' closeness centrality.
+    ///
+    /// A vertex's closeness centrality is the reciprocal of its average
+    /// distance to the vertices it reaches, scaled by the fraction of
+    /// vertices it reaches[^1].
+    ///
+    /// The distances should be non-negative. The closeness centrality of a
+    /// vertex is `0` if its distances to the vertices it reaches sum to zero
+    /// or less, for example if it reaches no other vertex or if negative
+    /// distances cancel out the positive ones.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use graaf::{
+    ///     AddArcWeighted,
+    ///     AdjacencyListWeighted,
+    ///     DistanceMatrix,
+    ///     Empty,
+    ///     FloydWarshall,
+    /// };
+    ///
+    /// let mut digraph = AdjacencyListWeighted::<isize>::empty(7);
+    ///
+    /// digraph.add_arc_weighted(0, 1, 5);
+    /// digraph.add_arc_weighted(0, 2, 3);
+    /// digraph.add_arc_weighted(0, 3, 2);
+    /// digraph.add_arc_weighted(0, 4, 4);
+    /// digraph.add_arc_weighted(1, 0, 3);
+    /// digraph.add_arc_weighted(1, 3, 1);
+    /// digraph.add_arc_weighted(1, 4, 2);
+    /// digraph.add_arc_weighted(2, 6, 4);
+    /// digraph.add_arc_weighted(3, 4, 1);
+    /// digraph.add_arc_weighted(3, 5, 1);
+    /// digraph.add_arc_weighted(4, 2, 3);
+    /// digraph.add_arc_weighted(5, 6, 1);
+    /// digraph.add_arc_weighted(6, 0, 9);
+    /// digraph.add_arc_weighted(6, 1, 8);
+    /// digraph.add_arc_weighted(6, 2, 5);
+    ///
+    /// let dist = FloydWarshall::new(&digraph).distances().unwrap().clone();
+    ///
+    /// let closeness = dist.closeness_centrality().collect::<Vec<_>>();
+    ///
+    /// assert_eq!(closeness.len(), 7);
+    ///
+    /// assert!(
+    ///     closeness
+    ///         .iter()
+    ///         .zip([
+    ///             6.0 / 20.0,
+    ///             6.0 / 16.0,
+    ///             6.0 / 70.0,
+    ///             6.0 / 29.0,
+    ///             6.0 / 74.0,
+    ///             6.0 / 47.0,
+    ///             6.0 / 51.0
+    ///         ])
+    ///         .all(|(a, b)| (a - b).abs() < 1e-9)
+    /// );
+    /// ```
+    ///
+    /// [^1]: Stanley Wasserman and Katherine Faust. 1994. Social Network
+    ///   Analysis: Methods and Applications. Cambridge University Press.
+    ///   <https://doi.org/10.1017/CBO9780511815478>
+    pub fn closeness_centrality(&self) -> impl Iterator<Item = f64> + '_ {
+        let order = self.order;
+
+        self.dist.chunks(order).enumerate().map(move |(u, row)| {
+            let (reach, total) = row
+                .iter()
+                .enumerate()
+                .filter(|&(v, &d)| v != u && d != self.infinity)
+                .fold((0_usize, 0), |(reach, total), (_, &d)| {
+                    (reach + 1, total + d)
+                });
+
+            if total <= 0 {
+                return 0.0;
+            }
+
+            let reach = to_f64(reach);
+
+            reach / to_f64(total.unsigned_abs()) * reach / to_f64(order - 1)
+        })
+    }
+
+    /// Return the vertices' harmonic centrality.
+    ///
+    /// A vertex's harmonic centrality is the sum of the reciprocals of its
+    /// distances to the other vertices[^1].
+    ///
+    /// The distances should be positive. Unreachable vertices and
+    /// non-positive distances don't contribute, so a vertex that reaches no
+    /// other vertex has harmonic centrality `0`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use graaf::{
+    ///     AddArcWeighted,
+    ///     AdjacencyListWeighted,
+    ///     DistanceMatrix,
+    ///     Empty,
+    ///     FloydWarshall,
+    /// };
+    ///
+    /// let mut digraph = AdjacencyListWeighted::<isize>::empty(7);
+    ///
+    /// digraph.add_arc_weighted(0, 1, 5);
+    /// digraph.add_arc_weighted(0, 2, 3);
+    /// digraph.add_arc_weighted(0, 3, 2);
+    /// digraph.add_arc_weighted(0, 4, 4);
+    /// digraph.add_arc_weighted(1, 0, 3);
+    /// digraph.add_arc_weighted(1, 3, 1);
+    /// digraph.add_arc_weighted(1, 4, 2);
+    /// digraph.add_arc_weighted(2, 6, 4);
+    /// digraph.add_arc_weighted(3, 4, 1);
+    /// digraph.add_arc_weighted(3, 5, 1);
+    /// digraph.add_arc_weighted(4, 2, 3);
+    /// digraph.add_arc_weighted(5, 6, 1);
+    /// digraph.add_arc_weighted(6, 0, 9);
+    /// digraph.add_arc_weighted(6, 1, 8);
+    /// digraph.add_arc_weighted(6, 2, 5);
+    ///
+    /// let dist = FloydWarshall::new(&digraph).distances().unwrap().clone();
+    /// let expected =
+    ///     1.0 / 3.0 + 1.0 + 1.0 / 5.0 + 1.0 / 2.0 + 1.0 / 2.0 + 1.0 / 3.0;
+    ///
+    /// assert!(
+    ///     (dist.harmonic_centrality().nth(1).unwrap() - expected).abs() < 1e-9
+    /// );
+    /// ```
+    ///
+    /// [^1]: Paolo Boldi and Sebastiano Vigna. 2014. Axioms for centrality.
+    ///   Internet Mathematics 10, 3–4 (2014), 222–262.
+    ///   <https://doi.org/10.1080/15427951.2013.865686>
+    pub fn harmonic_centrality(&self) -> impl Iterator<Item = f64> + '_ {
+        self.dist
+            .chunks(self.order)
+            .enumerate()
+            .map(move |(u, row)| {
+                row.iter()
+                    .enumerate()
+                    .filter(|&(v, &d)| v != u && d > 0 && d != self.infinity)
+                    .map(|(_, &d)| 1.0 / to_f64(d.unsigned_abs()))
+                    .sum()
+            })
+    }
 }
 
 impl<W> Index<usize> for DistanceMatrix<W> {
@@ -496,45 +940,116 @@ impl<W> IndexMut<RangeFull> for DistanceMatrix<W> {
     fn index_mut(&mut self, _: RangeFull) -> &mut Self::Output {
         &mut self.dist
     }
-}
+}
+
+impl<W> Index<Range<usize>> for DistanceMatrix<W> {
+    type Output = [W];
+
+    fn index(&self, index: Range<usize>) -> &Self::Output {
+        &self.dist[index]
+    }
+}
+
+impl<W> IndexMut<Range<usize>> for DistanceMatrix<W> {
+    fn index_mut(&mut self, index: Range<usize>) -> &mut Self::Output {
+        &mut self.dist[index]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::*,
+        crate::{
+            AdjacencyListWeighted,
+            Empty,
+            FloydWarshall,
+            RemoveArc,
+            proptest_strategy::digraph_weighted,
+            repr::adjacency_list_weighted::fixture::{
+                kattis_bryr_1_isize,
+                kattis_bryr_2_isize,
+                kattis_bryr_3_isize,
+                kattis_crosscountry_isize,
+                kattis_shortestpath1_isize,
+            },
+        },
+    };
+
+    fn assert_close<I>(actual: I, expected: &[f64])
+    where
+        I: Iterator<Item = f64>,
+    {
+        let actual = actual.collect::<Vec<_>>();
+
+        assert_eq!(actual.len(), expected.len());
+        assert!(
+            actual
+                .iter()
+                .zip(expected)
+                .all(|(a, b)| (a - b).abs() < 1e-9)
+        );
+    }
+
+    #[test]
+    fn average_path_length_kattis_bryr_1() {
+        let digraph = kattis_bryr_1_isize();
+        let mut floyd_warshall = FloydWarshall::new(&digraph);
+        let dist = floyd_warshall.distances().unwrap();
+
+        assert!((dist.average_path_length() - 1.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn average_path_length_kattis_bryr_2() {
+        let digraph = kattis_bryr_2_isize();
+        let mut floyd_warshall = FloydWarshall::new(&digraph);
+        let dist = floyd_warshall.distances().unwrap();
+
+        assert!((dist.average_path_length() - 58.0 / 30.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn average_path_length_kattis_bryr_3() {
+        let digraph = kattis_bryr_3_isize();
+        let mut floyd_warshall = FloydWarshall::new(&digraph);
+        let dist = floyd_warshall.distances().unwrap();
+
+        assert!((dist.average_path_length() - 44.0 / 90.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn average_path_length_kattis_crosscountry() {
+        let digraph = kattis_crosscountry_isize();
+        let mut floyd_warshall = FloydWarshall::new(&digraph);
+        let dist = floyd_warshall.distances().unwrap();
+
+        assert!((dist.average_path_length() - 58.0 / 12.0).abs() < 1e-9);
+    }
 
-impl<W> Index<Range<usize>> for DistanceMatrix<W> {
-    type Output = [W];
+    #[test]
+    fn average_path_length_kattis_shortestpath1() {
+        let digraph = kattis_shortestpath1_isize();
+        let mut floyd_warshall = FloydWarshall::new(&digraph);
+        let dist = floyd_warshall.distances().unwrap();
 
-    fn index(&self, index: Range<usize>) -> &Self::Output {
-        &self.dist[index]
+        assert!(dist.average_path_length().is_infinite());
     }
-}
 
-impl<W> IndexMut<Range<usize>> for DistanceMatrix<W> {
-    fn index_mut(&mut self, index: Range<usize>) -> &mut Self::Output {
-        &mut self.dist[index]
-    }
-}
+    #[test]
+    fn average_path_length_trivial() {
+        let digraph = AdjacencyListWeighted::<isize>::trivial();
+        let mut floyd_warshall = FloydWarshall::new(&digraph);
+        let dist = floyd_warshall.distances().unwrap();
 
-#[cfg(test)]
-mod tests {
-    use {
-        super::*,
-        crate::{
-            AdjacencyListWeighted,
-            Empty,
-            FloydWarshall,
-            RemoveArc,
-            repr::adjacency_list_weighted::fixture::{
-                kattis_bryr_1_isize,
-                kattis_bryr_2_isize,
-                kattis_bryr_3_isize,
-                kattis_crosscountry_isize,
-            },
-        },
-    };
+        assert!(dist.average_path_length().abs() < 1e-9);
+    }
 
     #[test]
     fn center_kattis_bryr_1() {
         let digraph = kattis_bryr_1_isize();
         let mut floyd_warshall = FloydWarshall::new(&digraph);
-        let dist = floyd_warshall.distances();
+        let dist = floyd_warshall.distances().unwrap();
 
         assert!(dist.center().iter().eq(&[0, 1, 2]));
     }
@@ -543,7 +1058,7 @@ mod tests {
     fn center_kattis_bryr_2() {
         let digraph = kattis_bryr_2_isize();
         let mut floyd_warshall = FloydWarshall::new(&digraph);
-        let dist = floyd_warshall.distances();
+        let dist = floyd_warshall.distances().unwrap();
 
         assert!(dist.center().iter().eq(&[3]));
     }
@@ -552,7 +1067,7 @@ mod tests {
     fn center_kattis_bryr_3() {
         let digraph = kattis_bryr_3_isize();
         let mut floyd_warshall = FloydWarshall::new(&digraph);
-        let dist = floyd_warshall.distances();
+        let dist = floyd_warshall.distances().unwrap();
 
         assert!(dist.center().iter().eq(&[0, 1, 2, 3, 4, 5, 6, 7, 8, 9]));
     }
@@ -561,7 +1076,7 @@ mod tests {
     fn center_kattis_crosscountry() {
         let digraph = kattis_crosscountry_isize();
         let mut floyd_warshall = FloydWarshall::new(&digraph);
-        let dist = floyd_warshall.distances();
+        let dist = floyd_warshall.distances().unwrap();
 
         assert!(dist.center().iter().eq(&[3]));
     }
@@ -570,16 +1085,118 @@ mod tests {
     fn center_trivial() {
         let digraph = AdjacencyListWeighted::<isize>::trivial();
         let mut floyd_warshall = FloydWarshall::new(&digraph);
-        let dist = floyd_warshall.distances();
+        let dist = floyd_warshall.distances().unwrap();
 
         assert!(dist.center().iter().eq(&[0]));
     }
 
+    #[test]
+    fn closeness_centrality_kattis_bryr_1() {
+        let digraph = kattis_bryr_1_isize();
+        let mut floyd_warshall = FloydWarshall::new(&digraph);
+        let dist = floyd_warshall.distances().unwrap();
+
+        assert_close(dist.closeness_centrality(), &[1.0, 1.0, 1.0]);
+    }
+
+    #[test]
+    fn closeness_centrality_kattis_bryr_2() {
+        let digraph = kattis_bryr_2_isize();
+        let mut floyd_warshall = FloydWarshall::new(&digraph);
+        let dist = floyd_warshall.distances().unwrap();
+
+        assert_close(
+            dist.closeness_centrality(),
+            &[
+                5.0 / 9.0,
+                5.0 / 11.0,
+                5.0 / 9.0,
+                5.0 / 7.0,
+                5.0 / 9.0,
+                5.0 / 13.0,
+            ],
+        );
+    }
+
+    #[test]
+    fn closeness_centrality_kattis_bryr_3() {
+        let digraph = kattis_bryr_3_isize();
+        let mut floyd_warshall = FloydWarshall::new(&digraph);
+        let dist = floyd_warshall.distances().unwrap();
+
+        assert_close(
+            dist.closeness_centrality(),
+            &[
+                3.0,
+                3.0,
+                9.0 / 8.0,
+                3.0,
+                3.0,
+                3.0,
+                9.0 / 8.0,
+                3.0,
+                3.0,
+                9.0 / 7.0,
+            ],
+        );
+    }
+
+    #[test]
+    fn closeness_centrality_kattis_crosscountry() {
+        let digraph = kattis_crosscountry_isize();
+        let mut floyd_warshall = FloydWarshall::new(&digraph);
+        let dist = floyd_warshall.distances().unwrap();
+
+        assert_close(
+            dist.closeness_centrality(),
+            &[3.0 / 14.0, 3.0 / 17.0, 3.0 / 14.0, 3.0 / 13.0],
+        );
+    }
+
+    #[test]
+    fn closeness_centrality_kattis_shortestpath1() {
+        let digraph = kattis_shortestpath1_isize();
+        let mut floyd_warshall = FloydWarshall::new(&digraph);
+        let dist = floyd_warshall.distances().unwrap();
+
+        assert_close(
+            dist.closeness_centrality(),
+            &[2.0 / 9.0, 1.0 / 6.0, 0.0, 1.0 / 4.0],
+        );
+    }
+
+    #[test]
+    fn closeness_centrality_negative_weights() {
+        let digraph = digraph_weighted(3, &[(0, 1, -2), (1, 2, 3)]);
+        let mut floyd_warshall = FloydWarshall::new(&digraph);
+        let dist = floyd_warshall.distances().unwrap();
+
+        assert_close(dist.closeness_centrality(), &[0.0, 1.0 / 6.0, 0.0]);
+    }
+
+    #[test]
+    fn closeness_centrality_no_reachable_vertices() {
+        let digraph = digraph_weighted(3, &[(0, 1, 2)]);
+        let mut floyd_warshall = FloydWarshall::new(&digraph);
+        let dist = floyd_warshall.distances().unwrap();
+
+        assert_close(dist.closeness_centrality(), &[1.0 / 4.0, 0.0, 0.0]);
+    }
+
+    #[test]
+    fn closeness_centrality_trivial() {
+        let digraph = AdjacencyListWeighted::<isize>::trivial();
+        let mut floyd_warshall = FloydWarshall::new(&digraph);
+        let dist = floyd_warshall.distances().unwrap();
+
+        assert!(dist.closeness_centrality().eq([0.0]));
+    }
+
     #[test]
     fn diameter_kattis_bryr_1() {
         let digraph = kattis_bryr_1_isize();
         let mut floyd_warshall = FloydWarshall::new(&digraph);
-        let dist = floyd_warshall.distances();
+        let dist = floyd_warshall.distances().unwrap();
 
         assert_eq!(dist.diameter(), &1);
     }
@@ -588,7 +1205,7 @@ mod tests {
     fn diameter_kattis_bryr_2() {
         let digraph = kattis_bryr_2_isize();
         let mut floyd_warshall = FloydWarshall::new(&digraph);
-        let dist = floyd_warshall.distances();
+        let dist = floyd_warshall.distances().unwrap();
 
         assert_eq!(dist.diameter(), &4);
     }
@@ -597,7 +1214,7 @@ mod tests {
     fn diameter_kattis_bryr_3() {
         let digraph = kattis_bryr_3_isize();
         let mut floyd_warshall = FloydWarshall::new(&digraph);
-        let dist = floyd_warshall.distances();
+        let dist = floyd_warshall.distances().unwrap();
 
         assert_eq!(dist.diameter(), &1);
     }
@@ -606,7 +1223,7 @@ mod tests {
     fn diameter_kattis_crosscountry() {
         let digraph = kattis_crosscountry_isize();
         let mut floyd_warshall = FloydWarshall::new(&digraph);
-        let dist = floyd_warshall.distances();
+        let dist = floyd_warshall.distances().unwrap();
 
         assert_eq!(dist.diameter(), &11);
     }
@@ -615,7 +1232,7 @@ mod tests {
     fn diameter_trivial() {
         let digraph = AdjacencyListWeighted::<isize>::trivial();
         let mut floyd_warshall = FloydWarshall::new(&digraph);
-        let dist = floyd_warshall.distances();
+        let dist = floyd_warshall.distances().unwrap();
 
         assert_eq!(dist.diameter(), &0);
     }
@@ -624,7 +1241,7 @@ mod tests {
     fn eccentricities_kattis_bryr_1() {
         let digraph = kattis_bryr_1_isize();
         let mut floyd_warshall = FloydWarshall::new(&digraph);
-        let dist = floyd_warshall.distances();
+        let dist = floyd_warshall.distances().unwrap();
 
         assert!(dist.eccentricities().eq(&[1, 1, 1]));
     }
@@ -633,7 +1250,7 @@ mod tests {
     fn eccentricities_kattis_bryr_2() {
         let digraph = kattis_bryr_2_isize();
         let mut floyd_warshall = FloydWarshall::new(&digraph);
-        let dist = floyd_warshall.distances();
+        let dist = floyd_warshall.distances().unwrap();
 
         assert!(dist.eccentricities().eq(&[3, 4, 3, 2, 3, 4]));
     }
@@ -642,7 +1259,7 @@ mod tests {
     fn eccentricities_kattis_bryr_3() {
         let digraph = kattis_bryr_3_isize();
         let mut floyd_warshall = FloydWarshall::new(&digraph);
-        let dist = floyd_warshall.distances();
+        let dist = floyd_warshall.distances().unwrap();
 
         assert!(dist.eccentricities().eq(&[1, 1, 1, 1, 1, 1, 1, 1, 1, 1]));
     }
@@ -651,7 +1268,7 @@ mod tests {
     fn eccentricities_kattis_crosscountry() {
         let digraph = kattis_crosscountry_isize();
         let mut floyd_warshall = FloydWarshall::new(&digraph);
-        let dist = floyd_warshall.distances();
+        let dist = floyd_warshall.distances().unwrap();
 
         assert!(dist.eccentricities().eq(&[10, 11, 7, 6]));
     }
@@ -660,11 +1277,161 @@ mod tests {
     fn eccentricities_trivial() {
         let digraph = AdjacencyListWeighted::<isize>::trivial();
         let mut floyd_warshall = FloydWarshall::new(&digraph);
-        let dist = floyd_warshall.distances();
+        let dist = floyd_warshall.distances().unwrap();
 
         assert!(dist.eccentricities().eq(&[0]));
     }
 
+    #[test]
+    fn girth_kattis_bryr_1() {
+        let digraph = kattis_bryr_1_isize();
+        let mut floyd_warshall = FloydWarshall::new(&digraph);
+        let dist = floyd_warshall.distances().unwrap();
+
+        assert_eq!(dist.girth(), 2);
+    }
+
+    #[test]
+    fn girth_kattis_bryr_2() {
+        let digraph = kattis_bryr_2_isize();
+        let mut floyd_warshall = FloydWarshall::new(&digraph);
+        let dist = floyd_warshall.distances().unwrap();
+
+        assert_eq!(dist.girth(), 2);
+    }
+
+    #[test]
+    fn girth_kattis_bryr_3() {
+        let digraph = kattis_bryr_3_isize();
+        let mut floyd_warshall = FloydWarshall::new(&digraph);
+        let dist = floyd_warshall.distances().unwrap();
+
+        assert_eq!(dist.girth(), 0);
+    }
+
+    #[test]
+    fn girth_kattis_crosscountry() {
+        let digraph = kattis_crosscountry_isize();
+        let mut floyd_warshall = FloydWarshall::new(&digraph);
+        let dist = floyd_warshall.distances().unwrap();
+
+        assert_eq!(dist.girth(), 3);
+    }
+
+    #[test]
+    fn girth_kattis_shortestpath1() {
+        let digraph = kattis_shortestpath1_isize();
+        let mut floyd_warshall = FloydWarshall::new(&digraph);
+        let dist = floyd_warshall.distances().unwrap();
+
+        assert_eq!(dist.girth(), isize::MAX);
+    }
+
+    #[test]
+    fn girth_trivial() {
+        let digraph = AdjacencyListWeighted::<isize>::trivial();
+        let mut floyd_warshall = FloydWarshall::new(&digraph);
+        let dist = floyd_warshall.distances().unwrap();
+
+        assert_eq!(dist.girth(), isize::MAX);
+    }
+
+    #[test]
+    fn harmonic_centrality_kattis_bryr_1() {
+        let digraph = kattis_bryr_1_isize();
+        let mut floyd_warshall = FloydWarshall::new(&digraph);
+        let dist = floyd_warshall.distances().unwrap();
+
+        assert_close(dist.harmonic_centrality(), &[2.0, 2.0, 2.0]);
+    }
+
+    #[test]
+    fn harmonic_centrality_kattis_bryr_2() {
+        let digraph = kattis_bryr_2_isize();
+        let mut floyd_warshall = FloydWarshall::new(&digraph);
+        let dist = floyd_warshall.distances().unwrap();
+
+        assert_close(
+            dist.harmonic_centrality(),
+            &[
+                10.0 / 3.0,
+                37.0 / 12.0,
+                10.0 / 3.0,
+                4.0,
+                10.0 / 3.0,
+                29.0 / 12.0,
+            ],
+        );
+    }
+
+    #[test]
+    fn harmonic_centrality_kattis_bryr_3() {
+        let digraph = kattis_bryr_3_isize();
+        let mut floyd_warshall = FloydWarshall::new(&digraph);
+        let dist = floyd_warshall.distances().unwrap();
+
+        assert_close(
+            dist.harmonic_centrality(),
+            &[3.0, 3.0, 8.0, 3.0, 3.0, 3.0, 8.0, 3.0, 3.0, 7.0],
+        );
+    }
+
+    #[test]
+    fn harmonic_centrality_kattis_crosscountry() {
+        let digraph = kattis_crosscountry_isize();
+        let mut floyd_warshall = FloydWarshall::new(&digraph);
+        let dist = floyd_warshall.distances().unwrap();
+
+        assert_close(
+            dist.harmonic_centrality(),
+            &[
+                1.0 + 1.0 / 3.0 + 1.0 / 10.0,
+                1.0 / 2.0 + 1.0 / 4.0 + 1.0 / 11.0,
+                1.0 / 3.0 + 1.0 / 4.0 + 1.0 / 7.0,
+                1.0 / 5.0 + 1.0 / 6.0 + 1.0 / 2.0,
+            ],
+        );
+    }
+
+    #[test]
+    fn harmonic_centrality_kattis_shortestpath1() {
+        let digraph = kattis_shortestpath1_isize();
+        let mut floyd_warshall = FloydWarshall::new(&digraph);
+        let dist = floyd_warshall.distances().unwrap();
+
+        assert_close(
+            dist.harmonic_centrality(),
+            &[3.0 / 4.0, 1.0 / 2.0, 0.0, 11.0 / 12.0],
+        );
+    }
+
+    #[test]
+    fn harmonic_centrality_negative_weights() {
+        let digraph = digraph_weighted(3, &[(0, 1, -2), (1, 2, 3)]);
+        let mut floyd_warshall = FloydWarshall::new(&digraph);
+        let dist = floyd_warshall.distances().unwrap();
+
+        assert_close(dist.harmonic_centrality(), &[1.0, 1.0 / 3.0, 0.0]);
+    }
+
+    #[test]
+    fn harmonic_centrality_no_reachable_vertices() {
+        let digraph = digraph_weighted(3, &[(0, 1, 2)]);
+        let mut floyd_warshall = FloydWarshall::new(&digraph);
+        let dist = floyd_warshall.distances().unwrap();
+
+        assert_close(dist.harmonic_centrality(), &[1.0 / 2.0, 0.0, 0.0]);
+    }
+
+    #[test]
+    fn harmonic_centrality_trivial() {
+        let digraph = AdjacencyListWeighted::<isize>::trivial();
+        let mut floyd_warshall = FloydWarshall::new(&digraph);
+        let dist = floyd_warshall.distances().unwrap();
+
+        assert!(dist.harmonic_centrality().eq([0.0]));
+    }
+
     #[test]
     fn index_usize() {
         let dist = DistanceMatrix::new(4, isize::MAX);
@@ -784,14 +1551,14 @@ mod tests {
     fn is_connected_kattis_bryr_1() {
         let mut digraph = kattis_bryr_1_isize();
         let mut floyd_warshall = FloydWarshall::new(&digraph);
-        let dist = floyd_warshall.distances();
+        let dist = floyd_warshall.distances().unwrap();
 
         assert!(dist.is_connected());
         assert!(digraph.remove_arc(1, 0));
         assert!(digraph.remove_arc(2, 0));
 
         let mut floyd_warshall = FloydWarshall::new(&digraph);
-        let dist = floyd_warshall.distances();
+        let dist = floyd_warshall.distances().unwrap();
 
         assert!(!dist.is_connected());
     }
@@ -800,13 +1567,13 @@ mod tests {
     fn is_connected_kattis_bryr_2() {
         let mut digraph = kattis_bryr_2_isize();
         let mut floyd_warshall = FloydWarshall::new(&digraph);
-        let dist = floyd_warshall.distances();
+        let dist = floyd_warshall.distances().unwrap();
 
         assert!(dist.is_connected());
         assert!(digraph.remove_arc(3, 4));
 
         let mut floyd_warshall = FloydWarshall::new(&digraph);
-        let dist = floyd_warshall.distances();
+        let dist = floyd_warshall.distances().unwrap();
 
         assert!(!dist.is_connected());
     }
@@ -815,14 +1582,14 @@ mod tests {
     fn is_connected_kattis_bryr_3() {
         let mut digraph = kattis_bryr_3_isize();
         let mut floyd_warshall = FloydWarshall::new(&digraph);
-        let dist = floyd_warshall.distances();
+        let dist = floyd_warshall.distances().unwrap();
 
         assert!(dist.is_connected());
         assert!(digraph.remove_arc(0, 3));
         assert!(digraph.remove_arc(3, 0));
 
         let mut floyd_warshall = FloydWarshall::new(&digraph);
-        let dist = floyd_warshall.distances();
+        let dist = floyd_warshall.distances().unwrap();
 
         assert!(!dist.is_connected());
     }
@@ -831,7 +1598,7 @@ mod tests {
     fn is_connected_kattis_crosscountry() {
         let mut digraph = kattis_crosscountry_isize();
         let mut floyd_warshall = FloydWarshall::new(&digraph);
-        let dist = floyd_warshall.distances();
+        let dist = floyd_warshall.distances().unwrap();
 
         assert!(dist.is_connected());
         assert!(digraph.remove_arc(0, 1));
@@ -839,7 +1606,7 @@ mod tests {
         assert!(digraph.remove_arc(3, 1));
 
         let mut floyd_warshall = FloydWarshall::new(&digraph);
-        let dist = floyd_warshall.distances();
+        let dist = floyd_warshall.distances().unwrap();
 
         assert!(!dist.is_connected());
     }
@@ -848,7 +1615,7 @@ mod tests {
     fn is_connected_trivial() {
         let digraph = AdjacencyListWeighted::<isize>::trivial();
         let mut floyd_warshall = FloydWarshall::new(&digraph);
-        let dist = floyd_warshall.distances();
+        let dist = floyd_warshall.distances().unwrap();
 
         assert!(dist.is_connected());
     }
@@ -874,7 +1641,7 @@ mod tests {
     fn periphery_kattis_bryr_1() {
         let digraph = kattis_bryr_1_isize();
         let mut floyd_warshall = FloydWarshall::new(&digraph);
-        let dist = floyd_warshall.distances();
+        let dist = floyd_warshall.distances().unwrap();
 
         assert!(dist.periphery().eq([0, 1, 2]));
     }
@@ -883,7 +1650,7 @@ mod tests {
     fn periphery_kattis_bryr_2() {
         let digraph = kattis_bryr_2_isize();
         let mut floyd_warshall = FloydWarshall::new(&digraph);
-        let dist = floyd_warshall.distances();
+        let dist = floyd_warshall.distances().unwrap();
 
         assert!(dist.periphery().eq([1, 5]));
     }
@@ -892,7 +1659,7 @@ mod tests {
     fn periphery_kattis_bryr_3() {
         let digraph = kattis_bryr_3_isize();
         let mut floyd_warshall = FloydWarshall::new(&digraph);
-        let dist = floyd_warshall.distances();
+        let dist = floyd_warshall.distances().unwrap();
 
         assert!(dist.periphery().eq([0, 1, 2, 3, 4, 5, 6, 7, 8, 9]));
     }
@@ -901,7 +1668,7 @@ mod tests {
     fn periphery_kattis_crosscountry() {
         let digraph = kattis_crosscountry_isize();
         let mut floyd_warshall = FloydWarshall::new(&digraph);
-        let dist = floyd_warshall.distances();
+        let dist = floyd_warshall.distances().unwrap();
 
         assert!(dist.periphery().eq([1]));
     }
@@ -910,8 +1677,164 @@ mod tests {
     fn periphery_trivial() {
         let digraph = AdjacencyListWeighted::<isize>::trivial();
         let mut floyd_warshall = FloydWarshall::new(&digraph);
-        let dist = floyd_warshall.distances();
+        let dist = floyd_warshall.distances().unwrap();
 
         assert!(dist.periphery().eq([0]));
     }
+
+    #[test]
+    fn radius_kattis_bryr_1() {
+        let digraph = kattis_bryr_1_isize();
+        let mut floyd_warshall = FloydWarshall::new(&digraph);
+        let dist = floyd_warshall.distances().unwrap();
+
+        assert_eq!(dist.radius(), &1);
+    }
+
+    #[test]
+    fn radius_kattis_bryr_2() {
+        let digraph = kattis_bryr_2_isize();
+        let mut floyd_warshall = FloydWarshall::new(&digraph);
+        let dist = floyd_warshall.distances().unwrap();
+
+        assert_eq!(dist.radius(), &2);
+    }
+
+    #[test]
+    fn radius_kattis_bryr_3() {
+        let digraph = kattis_bryr_3_isize();
+        let mut floyd_warshall = FloydWarshall::new(&digraph);
+        let dist = floyd_warshall.distances().unwrap();
+
+        assert_eq!(dist.radius(), &1);
+    }
+
+    #[test]
+    fn radius_kattis_crosscountry() {
+        let digraph = kattis_crosscountry_isize();
+        let mut floyd_warshall = FloydWarshall::new(&digraph);
+        let dist = floyd_warshall.distances().unwrap();
+
+        assert_eq!(dist.radius(), &6);
+    }
+
+    #[test]
+    fn radius_trivial() {
+        let digraph = AdjacencyListWeighted::<isize>::trivial();
+        let mut floyd_warshall = FloydWarshall::new(&digraph);
+        let dist = floyd_warshall.distances().unwrap();
+
+        assert_eq!(dist.radius(), &0);
+    }
+
+    #[test]
+    fn reachability_counts_kattis_bryr_1() {
+        let digraph = kattis_bryr_1_isize();
+        let mut floyd_warshall = FloydWarshall::new(&digraph);
+        let dist = floyd_warshall.distances().unwrap();
+
+        assert!(dist.reachability_counts().eq([2, 2, 2]));
+    }
+
+    #[test]
+    fn reachability_counts_kattis_bryr_2() {
+        let digraph = kattis_bryr_2_isize();
+        let mut floyd_warshall = FloydWarshall::new(&digraph);
+        let dist = floyd_warshall.distances().unwrap();
+
+        assert!(dist.reachability_counts().eq([5, 5, 5, 5, 5, 5]));
+    }
+
+    #[test]
+    fn reachability_counts_kattis_bryr_3() {
+        let digraph = kattis_bryr_3_isize();
+        let mut floyd_warshall = FloydWarshall::new(&digraph);
+        let dist = floyd_warshall.distances().unwrap();
+
+        assert!(
+            dist.reachability_counts()
+                .eq([9, 9, 9, 9, 9, 9, 9, 9, 9, 9])
+        );
+    }
+
+    #[test]
+    fn reachability_counts_kattis_crosscountry() {
+        let digraph = kattis_crosscountry_isize();
+        let mut floyd_warshall = FloydWarshall::new(&digraph);
+        let dist = floyd_warshall.distances().unwrap();
+
+        assert!(dist.reachability_counts().eq([3, 3, 3, 3]));
+    }
+
+    #[test]
+    fn reachability_counts_kattis_shortestpath1() {
+        let digraph = kattis_shortestpath1_isize();
+        let mut floyd_warshall = FloydWarshall::new(&digraph);
+        let dist = floyd_warshall.distances().unwrap();
+
+        assert!(dist.reachability_counts().eq([2, 1, 0, 3]));
+    }
+
+    #[test]
+    fn reachability_counts_trivial() {
+        let digraph = AdjacencyListWeighted::<isize>::trivial();
+        let mut floyd_warshall = FloydWarshall::new(&digraph);
+        let dist = floyd_warshall.distances().unwrap();
+
+        assert!(dist.reachability_counts().eq([0]));
+    }
+
+    #[test]
+    fn wiener_index_kattis_bryr_1() {
+        let digraph = kattis_bryr_1_isize();
+        let mut floyd_warshall = FloydWarshall::new(&digraph);
+        let dist = floyd_warshall.distances().unwrap();
+
+        assert_eq!(dist.wiener_index(), 6);
+    }
+
+    #[test]
+    fn wiener_index_kattis_bryr_2() {
+        let digraph = kattis_bryr_2_isize();
+        let mut floyd_warshall = FloydWarshall::new(&digraph);
+        let dist = floyd_warshall.distances().unwrap();
+
+        assert_eq!(dist.wiener_index(), 58);
+    }
+
+    #[test]
+    fn wiener_index_kattis_bryr_3() {
+        let digraph = kattis_bryr_3_isize();
+        let mut floyd_warshall = FloydWarshall::new(&digraph);
+        let dist = floyd_warshall.distances().unwrap();
+
+        assert_eq!(dist.wiener_index(), 44);
+    }
+
+    #[test]
+    fn wiener_index_kattis_crosscountry() {
+        let digraph = kattis_crosscountry_isize();
+        let mut floyd_warshall = FloydWarshall::new(&digraph);
+        let dist = floyd_warshall.distances().unwrap();
+
+        assert_eq!(dist.wiener_index(), 58);
+    }
+
+    #[test]
+    fn wiener_index_kattis_shortestpath1() {
+        let digraph = kattis_shortestpath1_isize();
+        let mut floyd_warshall = FloydWarshall::new(&digraph);
+        let dist = floyd_warshall.distances().unwrap();
+
+        assert_eq!(dist.wiener_index(), isize::MAX);
+    }
+
+    #[test]
+    fn wiener_index_trivial() {
+        let digraph = AdjacencyListWeighted::<isize>::trivial();
+        let mut floyd_warshall = FloydWarshall::new(&digraph);
+        let dist = floyd_warshall.distances().unwrap();
+
+        assert_eq!(dist.wiener_index(), 0);
+    }
 }
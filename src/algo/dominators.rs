@@ -0,0 +1,879 @@
+//! The Lengauer-Tarjan dominator algorithm.
+//!
+//! A vertex `u` dominates a vertex `v` if every path from the root to `v`
+//! passes through `u`. The immediate dominator of `v` is the unique strict
+//! dominator of `v` that every other strict dominator of `v` dominates. The
+//! Lengauer-Tarjan algorithm[^1] finds the immediate dominator of each vertex
+//! reachable from the root and returns them as a [`DominatorTree`].
+//!
+//! The time complexity is `O(a log v)`, where `v` is the digraph's order and
+//! `a` is the digraph's size.
+//!
+//! # Examples
+//!
+//! ```
+//! use {
+//!     graaf::{
+//!         AddArc,
+//!         AdjacencyList,
+//!         Dominators,
+//!         Empty,
+//!     },
+//!     std::collections::BTreeSet,
+//! };
+//!
+//! let mut digraph = AdjacencyList::empty(6);
+//!
+//! digraph.add_arc(0, 1);
+//! digraph.add_arc(0, 2);
+//! digraph.add_arc(1, 3);
+//! digraph.add_arc(2, 3);
+//! digraph.add_arc(3, 4);
+//! digraph.add_arc(4, 1);
+//!
+//! let dominators = Dominators::new(&digraph, 0);
+//! let tree = dominators.tree();
+//!
+//! assert!((0..6).map(|u| tree.idom(u)).eq([
+//!     None,
+//!     Some(0),
+//!     Some(0),
+//!     Some(0),
+//!     Some(3),
+//!     None
+//! ]));
+//!
+//! assert!(tree.dominates(3, 4));
+//! assert!(!tree.dominates(1, 3));
+//!
+//! assert!(dominators.frontiers().iter().eq(&[
+//!     BTreeSet::new(),
+//!     BTreeSet::from([3]),
+//!     BTreeSet::from([3]),
+//!     BTreeSet::from([1]),
+//!     BTreeSet::from([1]),
+//!     BTreeSet::new(),
+//! ]));
+//! ```
+//!
+//! [^1]: Thomas Lengauer and Robert Endre Tarjan. 1979. A fast algorithm for
+//!   finding dominators in a flowgraph. ACM Trans. Program. Lang. Syst. 1, 1
+//!   (July 1979), 121–141. <https://doi.org/10.1145/357062.357071>
+
+use {
+    crate::{
+        Order,
+        OutNeighbors,
+        PredecessorTree,
+    },
+    std::{
+        collections::BTreeSet,
+        mem::take,
+    },
+};
+
+/// A dominator tree.
+///
+/// A [`DominatorTree`] contains the immediate dominator of each vertex
+/// reachable from the root. Use [`PredecessorTree::from`] to search it like
+/// any other predecessor tree.
+///
+/// # Examples
+///
+/// ```
+/// use graaf::{
+///     AddArc,
+///     AdjacencyList,
+///     Dominators,
+///     Empty,
+///     PredecessorTree,
+/// };
+///
+/// let mut digraph = AdjacencyList::empty(5);
+///
+/// digraph.add_arc(0, 1);
+/// digraph.add_arc(0, 2);
+/// digraph.add_arc(1, 3);
+/// digraph.add_arc(2, 3);
+/// digraph.add_arc(3, 4);
+///
+/// let tree = Dominators::new(&digraph, 0).tree();
+///
+/// assert!(tree.dominates(0, 4));
+/// assert!(tree.dominates(3, 4));
+/// assert!(!tree.dominates(1, 4));
+///
+/// assert_eq!(
+///     PredecessorTree::from(tree).search(4, 0),
+///     Some(vec![4, 3, 0])
+/// );
+/// ```
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct DominatorTree {
+    idom: Vec<Option<usize>>,
+    pre: Vec<usize>,
+    post: Vec<usize>,
+}
+
+impl DominatorTree {
+    /// Construct a dominator tree from the immediate dominators of the
+    /// vertices in depth-first order.
+    fn new(order: usize, vertex: &[usize], idom: &[usize]) -> Self {
+        let reachable = vertex.len();
+        let mut children = vec![Vec::new(); reachable];
+
+        for w in 1..reachable {
+            children[idom[w]].push(w);
+        }
+
+        let mut pre = vec![usize::MAX; order];
+        let mut post = vec![usize::MAX; order];
+        let mut time = 0;
+        let mut stack = vec![(0, 0)];
+
+        while let Some((w, i)) = stack.last_mut() {
+            if *i == 0 {
+                pre[vertex[*w]] = time;
+                time += 1;
+            }
+
+            if let Some(&c) = children[*w].get(*i) {
+                *i += 1;
+
+                stack.push((c, 0));
+            } else {
+                post[vertex[*w]] = time;
+                time += 1;
+
+                let _ = stack.pop();
+            }
+        }
+
+        let mut tree = vec![None; order];
+
+        for w in 1..reachable {
+            tree[vertex[w]] = Some(vertex[idom[w]]);
+        }
+
+        Self {
+            idom: tree,
+            pre,
+            post,
+        }
+    }
+
+    /// Return a vertex's immediate dominator.
+    ///
+    /// # Arguments
+    ///
+    /// * `u`: The vertex.
+    ///
+    /// # Returns
+    ///
+    /// Returns `None` if `u` is the root or isn't reachable from the root.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `u` isn't in the digraph.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use graaf::{
+    ///     AdjacencyList,
+    ///     Dominators,
+    ///     Path,
+    /// };
+    ///
+    /// let digraph = AdjacencyList::path(4);
+    /// let tree = Dominators::new(&digraph, 1).tree();
+    ///
+    /// assert!(
+    ///     (0..4)
+    ///         .map(|u| tree.idom(u))
+    ///         .eq([None, None, Some(1), Some(2)])
+    /// );
+    /// ```
+    #[must_use]
+    pub fn idom(&self, u: usize) -> Option<usize> {
+        self.idom[u]
+    }
+
+    /// Check whether a vertex dominates another vertex.
+    ///
+    /// Every vertex reachable from the root dominates itself.
+    ///
+    /// # Arguments
+    ///
+    /// * `u`: The dominating vertex.
+    /// * `v`: The dominated vertex.
+    ///
+    /// # Returns
+    ///
+    /// Returns `false` if `u` or `v` isn't reachable from the root.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `u` or `v` isn't in the digraph.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use graaf::{
+    ///     AdjacencyList,
+    ///     Dominators,
+    ///     Path,
+    /// };
+    ///
+    /// let digraph = AdjacencyList::path(4);
+    /// let tree = Dominators::new(&digraph, 1).tree();
+    ///
+    /// assert!(tree.dominates(1, 3));
+    /// assert!(tree.dominates(2, 2));
+    /// assert!(!tree.dominates(3, 2));
+    /// assert!(!tree.dominates(0, 3));
+    /// ```
+    #[must_use]
+    pub fn dominates(&self, u: usize, v: usize) -> bool {
+        self.pre[u] != usize::MAX
+            && self.pre[v] != usize::MAX
+            && self.pre[u] <= self.pre[v]
+            && self.post[v] <= self.post[u]
+    }
+}
+
+impl From<DominatorTree> for PredecessorTree {
+    fn from(tree: DominatorTree) -> Self {
+        Self::from(tree.idom)
+    }
+}
+
+/// The Lengauer-Tarjan dominator algorithm.
+///
+/// The Lengauer-Tarjan algorithm[^1] finds the immediate dominator of each
+/// vertex reachable from the root.
+///
+/// # Examples
+///
+/// ```
+/// use {
+///     graaf::{
+///         AddArc,
+///         AdjacencyList,
+///         Dominators,
+///         Empty,
+///     },
+///     std::collections::BTreeSet,
+/// };
+///
+/// let mut digraph = AdjacencyList::empty(6);
+///
+/// digraph.add_arc(0, 1);
+/// digraph.add_arc(0, 2);
+/// digraph.add_arc(1, 3);
+/// digraph.add_arc(2, 3);
+/// digraph.add_arc(3, 4);
+/// digraph.add_arc(4, 1);
+///
+/// let dominators = Dominators::new(&digraph, 0);
+/// let tree = dominators.tree();
+///
+/// assert!((0..6).map(|u| tree.idom(u)).eq([
+///     None,
+///     Some(0),
+///     Some(0),
+///     Some(0),
+///     Some(3),
+///     None
+/// ]));
+///
+/// assert!(tree.dominates(3, 4));
+/// assert!(!tree.dominates(1, 3));
+///
+/// assert!(dominators.frontiers().iter().eq(&[
+///     BTreeSet::new(),
+///     BTreeSet::from([3]),
+///     BTreeSet::from([3]),
+///     BTreeSet::from([1]),
+///     BTreeSet::from([1]),
+///     BTreeSet::new(),
+/// ]));
+/// ```
+///
+/// [^1]: Thomas Lengauer and Robert Endre Tarjan. 1979. A fast algorithm for
+///   finding dominators in a flowgraph. ACM Trans. Program. Lang. Syst. 1, 1
+///   (July 1979), 121–141. <https://doi.org/10.1145/357062.357071>
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Dominators<'a, D> {
+    digraph: &'a D,
+    root: usize,
+}
+
+impl<'a, D> Dominators<'a, D> {
+    /// Construct a new instance of the Lengauer-Tarjan algorithm.
+    ///
+    /// # Arguments
+    ///
+    /// * `digraph`: The digraph.
+    /// * `root`: The root vertex.
+    #[must_use]
+    pub const fn new(digraph: &'a D, root: usize) -> Self
+    where
+        D: Order + OutNeighbors,
+    {
+        Self { digraph, root }
+    }
+
+    /// Find the digraph's dominator tree.
+    ///
+    /// # Panics
+    ///
+    /// * Panics if the root isn't in the digraph.
+    /// * Panics if a successor vertex isn't in the digraph.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use graaf::{
+    ///     AddArc,
+    ///     AdjacencyList,
+    ///     Dominators,
+    ///     Empty,
+    /// };
+    ///
+    /// let mut digraph = AdjacencyList::empty(4);
+    ///
+    /// digraph.add_arc(0, 1);
+    /// digraph.add_arc(0, 2);
+    /// digraph.add_arc(1, 3);
+    /// digraph.add_arc(2, 3);
+    ///
+    /// let tree = Dominators::new(&digraph, 0).tree();
+    ///
+    /// assert!((0..4).map(|u| tree.idom(u)).eq([
+    ///     None,
+    ///     Some(0),
+    ///     Some(0),
+    ///     Some(0)
+    /// ]));
+    /// ```
+    #[must_use]
+    pub fn tree(&self) -> DominatorTree
+    where
+        D: Order + OutNeighbors,
+    {
+        let order = self.digraph.order();
+        let mut number = vec![usize::MAX; order];
+        let mut vertex = vec![self.root];
+        let mut parent = vec![0];
+        let mut stack = vec![(0, self.digraph.out_neighbors(self.root))];
+
+        number[self.root] = 0;
+
+        while let Some((i, neighbors)) = stack.last_mut() {
+            let i = *i;
+
+            if let Some(v) = neighbors.next() {
+                if number[v] == usize::MAX {
+                    number[v] = vertex.len();
+                    stack.push((vertex.len(), self.digraph.out_neighbors(v)));
+                    vertex.push(v);
+                    parent.push(i);
+                }
+            } else {
+                drop(stack.pop());
+            }
+        }
+
+        let reachable = vertex.len();
+        let mut pred = vec![Vec::new(); reachable];
+
+        for (i, &u) in vertex.iter().enumerate() {
+            for v in self.digraph.out_neighbors(u) {
+                pred[number[v]].push(i);
+            }
+        }
+
+        let mut semi = (0..reachable).collect::<Vec<_>>();
+        let mut label = semi.clone();
+        let mut ancestor = vec![usize::MAX; reachable];
+        let mut idom = vec![0; reachable];
+        let mut bucket = vec![Vec::new(); reachable];
+        let mut chain = Vec::new();
+
+        let mut eval = |v: usize,
+                        semi: &[usize],
+                        ancestor: &mut [usize],
+                        label: &mut [usize]| {
+            if ancestor[v] == usize::MAX {
+                return v;
+            }
+
+            let mut x = v;
+
+            while ancestor[ancestor[x]] != usize::MAX {
+                chain.push(x);
+                x = ancestor[x];
+            }
+
+            while let Some(x) = chain.pop() {
+                let a = ancestor[x];
+
+                if semi[label[a]] < semi[label[x]] {
+                    label[x] = label[a];
+                }
+
+                ancestor[x] = ancestor[a];
+            }
+
+            label[v]
+        };
+
+        for w in (1..reachable).rev() {
+            for &v in &pred[w] {
+                let u = eval(v, &semi, &mut ancestor, &mut label);
+
+                semi[w] = semi[w].min(semi[u]);
+            }
+
+            bucket[semi[w]].push(w);
+
+            let p = parent[w];
+
+            ancestor[w] = p;
+
+            for v in take(&mut bucket[p]) {
+                let u = eval(v, &semi, &mut ancestor, &mut label);
+
+                idom[v] = if semi[u] < semi[v] { u } else { p };
+            }
+        }
+
+        for w in 1..reachable {
+            if idom[w] != semi[w] {
+                idom[w] = idom[idom[w]];
+            }
+        }
+
+        DominatorTree::new(order, &vertex, &idom)
+    }
+
+    /// Find the dominance frontier of each vertex.
+    ///
+    /// The dominance frontier of `u` contains each vertex `v` such that `u`
+    /// dominates an in-neighbor of `v` but doesn't strictly dominate `v`.
+    /// Vertices unreachable from the root have empty frontiers.
+    ///
+    /// # Panics
+    ///
+    /// * Panics if the root isn't in the digraph.
+    /// * Panics if a successor vertex isn't in the digraph.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use {
+    ///     graaf::{
+    ///         AddArc,
+    ///         AdjacencyList,
+    ///         Dominators,
+    ///         Empty,
+    ///     },
+    ///     std::collections::BTreeSet,
+    /// };
+    ///
+    /// let mut digraph = AdjacencyList::empty(4);
+    ///
+    /// digraph.add_arc(0, 1);
+    /// digraph.add_arc(0, 2);
+    /// digraph.add_arc(1, 3);
+    /// digraph.add_arc(2, 3);
+    ///
+    /// assert!(Dominators::new(&digraph, 0).frontiers().iter().eq(&[
+    ///     BTreeSet::new(),
+    ///     BTreeSet::from([3]),
+    ///     BTreeSet::from([3]),
+    ///     BTreeSet::new(),
+    /// ]));
+    /// ```
+    #[must_use]
+    pub fn frontiers(&self) -> Vec<BTreeSet<usize>>
+    where
+        D: Order + OutNeighbors,
+    {
+        let tree = self.tree();
+        let mut frontiers = vec![BTreeSet::new(); self.digraph.order()];
+
+        for u in 0..self.digraph.order() {
+            if tree.pre[u] == usize::MAX {
+                continue;
+            }
+
+            for v in self.digraph.out_neighbors(u) {
+                let mut runner = u;
+
+                while Some(runner) != tree.idom[v] {
+                    let _ = frontiers[runner].insert(v);
+
+                    match tree.idom[runner] {
+                        Some(w) => runner = w,
+                        None => break,
+                    }
+                }
+            }
+        }
+
+        frontiers
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::*,
+        crate::{
+            AdjacencyList,
+            Empty,
+            repr::adjacency_list::fixture::{
+                bang_jensen_34,
+                bang_jensen_94,
+                bang_jensen_196,
+                kattis_builddeps,
+                kattis_cantinaofbabel_1,
+                kattis_cantinaofbabel_2,
+                kattis_escapewallmaria_1,
+                kattis_escapewallmaria_2,
+                kattis_escapewallmaria_3,
+            },
+        },
+    };
+
+    fn idoms<D>(digraph: &D, root: usize) -> Vec<Option<usize>>
+    where
+        D: Order + OutNeighbors,
+    {
+        let tree = Dominators::new(digraph, root).tree();
+
+        (0..digraph.order()).map(|u| tree.idom(u)).collect()
+    }
+
+    #[test]
+    fn dominates_bang_jensen_196() {
+        let tree = Dominators::new(&bang_jensen_196(), 0).tree();
+
+        assert!(tree.dominates(0, 6));
+        assert!(tree.dominates(7, 6));
+        assert!(tree.dominates(5, 6));
+        assert!(tree.dominates(2, 3));
+        assert!(!tree.dominates(1, 7));
+        assert!(!tree.dominates(3, 4));
+    }
+
+    #[test]
+    fn dominates_bang_jensen_34() {
+        let tree = Dominators::new(&bang_jensen_34(), 0).tree();
+
+        assert!(tree.dominates(0, 4));
+        assert!(!tree.dominates(0, 1));
+        assert!(!tree.dominates(1, 1));
+    }
+
+    #[test]
+    fn dominates_kattis_escapewallmaria_3() {
+        let tree = Dominators::new(&kattis_escapewallmaria_3(), 5).tree();
+
+        assert!(tree.dominates(5, 12));
+        assert!(tree.dominates(9, 12));
+        assert!(tree.dominates(13, 12));
+        assert!(!tree.dominates(1, 2));
+        assert!(!tree.dominates(5, 0));
+    }
+
+    #[test]
+    fn frontiers_bang_jensen_196() {
+        assert!(
+            Dominators::new(&bang_jensen_196(), 0)
+                .frontiers()
+                .iter()
+                .eq(&[
+                    BTreeSet::from([0]),
+                    BTreeSet::from([0, 2, 7]),
+                    BTreeSet::from([2, 4]),
+                    BTreeSet::from([2, 4]),
+                    BTreeSet::from([2]),
+                    BTreeSet::from([7]),
+                    BTreeSet::from([7]),
+                    BTreeSet::from([7]),
+                ])
+        );
+    }
+
+    #[test]
+    fn frontiers_bang_jensen_34() {
+        assert!(
+            Dominators::new(&bang_jensen_34(), 0)
+                .frontiers()
+                .iter()
+                .all(BTreeSet::is_empty)
+        );
+    }
+
+    #[test]
+    fn frontiers_bang_jensen_94() {
+        assert!(Dominators::new(&bang_jensen_94(), 0).frontiers().iter().eq(
+            &[
+                BTreeSet::new(),
+                BTreeSet::from([3]),
+                BTreeSet::from([1, 3, 5]),
+                BTreeSet::from([5]),
+                BTreeSet::new(),
+                BTreeSet::new(),
+                BTreeSet::new(),
+            ]
+        ));
+    }
+
+    #[test]
+    fn frontiers_kattis_builddeps() {
+        assert!(
+            Dominators::new(&kattis_builddeps(), 0)
+                .frontiers()
+                .iter()
+                .eq(&[
+                    BTreeSet::new(),
+                    BTreeSet::new(),
+                    BTreeSet::new(),
+                    BTreeSet::from([1]),
+                    BTreeSet::from([1]),
+                    BTreeSet::new(),
+                ])
+        );
+    }
+
+    #[test]
+    fn frontiers_kattis_cantinaofbabel_1() {
+        assert!(
+            Dominators::new(&kattis_cantinaofbabel_1(), 0)
+                .frontiers()
+                .iter()
+                .eq(&[
+                    BTreeSet::from([0]),
+                    BTreeSet::from([0, 1]),
+                    BTreeSet::from([1]),
+                    BTreeSet::from([2, 3, 4]),
+                    BTreeSet::from([2, 4]),
+                    BTreeSet::from([6]),
+                    BTreeSet::from([5, 10]),
+                    BTreeSet::from([3]),
+                    BTreeSet::new(),
+                    BTreeSet::from([7, 11]),
+                    BTreeSet::from([6]),
+                    BTreeSet::from([7, 11]),
+                ])
+        );
+    }
+
+    #[test]
+    fn frontiers_kattis_cantinaofbabel_2() {
+        assert!(
+            Dominators::new(&kattis_cantinaofbabel_2(), 0)
+                .frontiers()
+                .iter()
+                .eq(&[
+                    BTreeSet::from([0]),
+                    BTreeSet::from([0]),
+                    BTreeSet::from([0, 7]),
+                    BTreeSet::from([3]),
+                    BTreeSet::from([3]),
+                    BTreeSet::from([5]),
+                    BTreeSet::from([5]),
+                    BTreeSet::from([0, 7]),
+                    BTreeSet::new(),
+                    BTreeSet::new(),
+                    BTreeSet::new(),
+                    BTreeSet::new(),
+                ])
+        );
+    }
+
+    #[test]
+    fn frontiers_kattis_escapewallmaria_1() {
+        let mut frontiers = vec![BTreeSet::new(); 16];
+
+        frontiers[5] = BTreeSet::from([5]);
+        frontiers[6] = BTreeSet::from([5]);
+        frontiers[9] = BTreeSet::from([5, 9]);
+        frontiers[13] = BTreeSet::from([9]);
+
+        assert!(
+            Dominators::new(&kattis_escapewallmaria_1(), 5)
+                .frontiers()
+                .eq(&frontiers)
+        );
+    }
+
+    #[test]
+    fn frontiers_kattis_escapewallmaria_2() {
+        let mut frontiers = vec![BTreeSet::new(); 16];
+
+        frontiers[5] = BTreeSet::from([5]);
+        frontiers[6] = BTreeSet::from([5]);
+        frontiers[9] = BTreeSet::from([5]);
+
+        assert!(
+            Dominators::new(&kattis_escapewallmaria_2(), 5)
+                .frontiers()
+                .eq(&frontiers)
+        );
+    }
+
+    #[test]
+    fn frontiers_kattis_escapewallmaria_3() {
+        let mut frontiers = vec![BTreeSet::new(); 16];
+
+        frontiers[1] = BTreeSet::from([2, 5]);
+        frontiers[2] = BTreeSet::from([1, 6]);
+        frontiers[5] = BTreeSet::from([5]);
+        frontiers[6] = BTreeSet::from([2, 5]);
+        frontiers[9] = BTreeSet::from([5, 9]);
+        frontiers[12] = BTreeSet::from([13]);
+        frontiers[13] = BTreeSet::from([9, 13]);
+
+        assert!(
+            Dominators::new(&kattis_escapewallmaria_3(), 5)
+                .frontiers()
+                .eq(&frontiers)
+        );
+    }
+
+    #[test]
+    fn tree_bang_jensen_196() {
+        assert!(idoms(&bang_jensen_196(), 0).eq(&[
+            None,
+            Some(0),
+            Some(0),
+            Some(2),
+            Some(0),
+            Some(7),
+            Some(5),
+            Some(0)
+        ]));
+    }
+
+    #[test]
+    fn tree_bang_jensen_34() {
+        assert!(idoms(&bang_jensen_34(), 0).eq(&[
+            None,
+            None,
+            None,
+            None,
+            Some(0),
+            None
+        ]));
+    }
+
+    #[test]
+    fn tree_bang_jensen_94() {
+        assert!(idoms(&bang_jensen_94(), 0).eq(&[
+            None,
+            Some(0),
+            Some(0),
+            Some(0),
+            Some(2),
+            Some(0),
+            Some(4)
+        ]));
+    }
+
+    #[test]
+    fn tree_kattis_builddeps() {
+        assert!(idoms(&kattis_builddeps(), 0).eq(&[
+            None,
+            Some(0),
+            None,
+            Some(0),
+            Some(0),
+            None
+        ]));
+    }
+
+    #[test]
+    fn tree_kattis_cantinaofbabel_1() {
+        assert!(idoms(&kattis_cantinaofbabel_1(), 0).eq(&[
+            None,
+            Some(0),
+            Some(1),
+            Some(4),
+            Some(1),
+            Some(3),
+            Some(3),
+            Some(3),
+            None,
+            Some(11),
+            Some(3),
+            Some(3)
+        ]));
+    }
+
+    #[test]
+    fn tree_kattis_cantinaofbabel_2() {
+        assert!(idoms(&kattis_cantinaofbabel_2(), 0).eq(&[
+            None,
+            Some(0),
+            Some(7),
+            Some(5),
+            Some(3),
+            Some(2),
+            Some(5),
+            Some(1),
+            None,
+            None,
+            None,
+            None
+        ]));
+    }
+
+    #[test]
+    fn tree_kattis_escapewallmaria_1() {
+        let mut expected = vec![None; 16];
+
+        expected[6] = Some(5);
+        expected[9] = Some(5);
+        expected[12] = Some(13);
+        expected[13] = Some(9);
+
+        assert!(idoms(&kattis_escapewallmaria_1(), 5).eq(&expected));
+    }
+
+    #[test]
+    fn tree_kattis_escapewallmaria_2() {
+        let mut expected = vec![None; 16];
+
+        expected[6] = Some(5);
+        expected[9] = Some(5);
+
+        assert!(idoms(&kattis_escapewallmaria_2(), 5).eq(&expected));
+    }
+
+    #[test]
+    fn tree_kattis_escapewallmaria_3() {
+        let mut expected = vec![None; 16];
+
+        expected[1] = Some(5);
+        expected[2] = Some(5);
+        expected[6] = Some(5);
+        expected[9] = Some(5);
+        expected[12] = Some(13);
+        expected[13] = Some(9);
+
+        assert!(idoms(&kattis_escapewallmaria_3(), 5).eq(&expected));
+    }
+
+    #[test]
+    fn tree_trivial() {
+        let tree = Dominators::new(&AdjacencyList::trivial(), 0).tree();
+
+        assert_eq!(tree.idom(0), None);
+        assert!(tree.dominates(0, 0));
+    }
+}
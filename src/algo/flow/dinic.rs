@@ -0,0 +1,467 @@
+//! Dinic's algorithm.
+//!
+//! Dinic's algorithm finds a maximum flow from a source to a sink in an
+//! arc-weighted digraph whose weights are the arc capacities.[^1] Each phase
+//! labels the vertices with their distance from the source in the residual
+//! digraph and augments a blocking flow along the arcs that lead one level
+//! deeper.
+//!
+//! The time complexity is `O(v² a)`, where `v` is the digraph's order and `a`
+//! is the digraph's size.
+//!
+//! # Examples
+//!
+//! ```
+//! use graaf::{
+//!     AddArcWeighted,
+//!     AdjacencyListWeighted,
+//!     ArcsWeighted,
+//!     Dinic,
+//!     Empty,
+//! };
+//!
+//! let mut digraph = AdjacencyListWeighted::<usize>::empty(6);
+//!
+//! digraph.add_arc_weighted(0, 1, 16);
+//! digraph.add_arc_weighted(0, 2, 13);
+//! digraph.add_arc_weighted(1, 3, 12);
+//! digraph.add_arc_weighted(2, 1, 4);
+//! digraph.add_arc_weighted(2, 4, 14);
+//! digraph.add_arc_weighted(3, 2, 9);
+//! digraph.add_arc_weighted(3, 5, 20);
+//! digraph.add_arc_weighted(4, 3, 7);
+//! digraph.add_arc_weighted(4, 5, 4);
+//!
+//! let max_flow = Dinic::new(&digraph).max_flow(0, 5);
+//!
+//! assert_eq!(max_flow.value, 23);
+//!
+//! assert!(max_flow.flows.arcs_weighted().eq([
+//!     (0, 1, &12),
+//!     (0, 2, &11),
+//!     (1, 3, &12),
+//!     (2, 1, &0),
+//!     (2, 4, &11),
+//!     (3, 2, &0),
+//!     (3, 5, &19),
+//!     (4, 3, &7),
+//!     (4, 5, &4),
+//! ]));
+//! ```
+//!
+//! [^1]: E. A. Dinic. 1970. Algorithm for solution of a problem of maximum
+//!   flow in networks with power estimation. Soviet Math. Dokl. 11 (1970),
+//!   1277–1280.
+
+use {
+    super::{
+        MaxFlow,
+        Network,
+    },
+    crate::{
+        ArcsWeighted,
+        Order,
+    },
+    std::{
+        collections::VecDeque,
+        ops::{
+            Add,
+            Sub,
+        },
+    },
+};
+
+/// Dinic's algorithm.
+///
+/// # Examples
+///
+/// ```
+/// use graaf::{
+///     AddArcWeighted,
+///     AdjacencyListWeighted,
+///     ArcsWeighted,
+///     Dinic,
+///     Empty,
+/// };
+///
+/// let mut digraph = AdjacencyListWeighted::<usize>::empty(6);
+///
+/// digraph.add_arc_weighted(0, 1, 16);
+/// digraph.add_arc_weighted(0, 2, 13);
+/// digraph.add_arc_weighted(1, 3, 12);
+/// digraph.add_arc_weighted(2, 1, 4);
+/// digraph.add_arc_weighted(2, 4, 14);
+/// digraph.add_arc_weighted(3, 2, 9);
+/// digraph.add_arc_weighted(3, 5, 20);
+/// digraph.add_arc_weighted(4, 3, 7);
+/// digraph.add_arc_weighted(4, 5, 4);
+///
+/// let max_flow = Dinic::new(&digraph).max_flow(0, 5);
+///
+/// assert_eq!(max_flow.value, 23);
+///
+/// assert!(max_flow.flows.arcs_weighted().eq([
+///     (0, 1, &12),
+///     (0, 2, &11),
+///     (1, 3, &12),
+///     (2, 1, &0),
+///     (2, 4, &11),
+///     (3, 2, &0),
+///     (3, 5, &19),
+///     (4, 3, &7),
+///     (4, 5, &4),
+/// ]));
+/// ```
+#[derive(Clone, Debug)]
+pub struct Dinic<'a, D> {
+    digraph: &'a D,
+}
+
+impl<'a, D> Dinic<'a, D> {
+    /// Initialize Dinic's algorithm.
+    ///
+    /// # Arguments
+    ///
+    /// * `digraph`: The digraph.
+    #[must_use]
+    pub const fn new(digraph: &'a D) -> Self {
+        Self { digraph }
+    }
+
+    /// Find a maximum flow.
+    ///
+    /// # Arguments
+    ///
+    /// * `s`: The source vertex.
+    /// * `t`: The sink vertex.
+    ///
+    /// # Panics
+    ///
+    /// * Panics if `s` isn't in the digraph.
+    /// * Panics if `t` isn't in the digraph.
+    /// * Panics if `s` equals `t`.
+    /// * Panics if an arc has a negative weight.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use graaf::{
+    ///     AddArcWeighted,
+    ///     AdjacencyListWeighted,
+    ///     ArcsWeighted,
+    ///     Dinic,
+    ///     Empty,
+    /// };
+    ///
+    /// let mut digraph = AdjacencyListWeighted::<usize>::empty(4);
+    ///
+    /// digraph.add_arc_weighted(0, 1, 2);
+    /// digraph.add_arc_weighted(0, 2, 2);
+    /// digraph.add_arc_weighted(1, 3, 1);
+    /// digraph.add_arc_weighted(2, 3, 3);
+    ///
+    /// let max_flow = Dinic::new(&digraph).max_flow(0, 3);
+    ///
+    /// assert_eq!(max_flow.value, 3);
+    ///
+    /// assert!(max_flow.flows.arcs_weighted().eq([
+    ///     (0, 1, &1),
+    ///     (0, 2, &2),
+    ///     (1, 3, &1),
+    ///     (2, 3, &2),
+    /// ]));
+    /// ```
+    #[must_use]
+    pub fn max_flow<W>(&self, s: usize, t: usize) -> MaxFlow<W>
+    where
+        D: ArcsWeighted<Weight = W> + Order,
+        W: Add<Output = W> + Copy + Default + Ord + Sub<Output = W>,
+    {
+        let mut network = Network::new(self.digraph, s, t);
+        let value = augment(&mut network, s, t);
+
+        network.into_max_flow(s, value)
+    }
+}
+
+/// Augment blocking flows until `t` is unreachable from `s` and return the
+/// flow value.
+pub(crate) fn augment<W>(network: &mut Network<W>, s: usize, t: usize) -> W
+where
+    W: Add<Output = W> + Copy + Default + Ord + Sub<Output = W>,
+{
+    let mut value = W::default();
+
+    while let Some(mut level) = levels(network, s, t) {
+        value = value + blocking_flow(network, &mut level, s, t);
+    }
+
+    value
+}
+
+/// Label the vertices with their distance from `s` in the residual digraph.
+fn levels<W>(network: &Network<W>, s: usize, t: usize) -> Option<Vec<usize>>
+where
+    W: Copy + Default + Ord,
+{
+    let zero = W::default();
+    let mut level = vec![usize::MAX; network.out.len()];
+    let mut queue = VecDeque::from([s]);
+
+    level[s] = 0;
+
+    while let Some(u) = queue.pop_front() {
+        for &e in &network.out[u] {
+            let v = network.heads[e];
+
+            if network.capacities[e] > zero && level[v] == usize::MAX {
+                level[v] = level[u] + 1;
+                queue.push_back(v);
+            }
+        }
+    }
+
+    (level[t] != usize::MAX).then_some(level)
+}
+
+/// Augment a blocking flow along arcs that lead one level deeper.
+fn blocking_flow<W>(
+    network: &mut Network<W>,
+    level: &mut [usize],
+    s: usize,
+    t: usize,
+) -> W
+where
+    W: Add<Output = W> + Copy + Default + Ord + Sub<Output = W>,
+{
+    let zero = W::default();
+    let mut current = vec![0; network.out.len()];
+    let mut path = Vec::new();
+    let mut value = zero;
+    let mut u = s;
+
+    loop {
+        if u == t {
+            let delta = path
+                .iter()
+                .map(|&e| network.capacities[e])
+                .min()
+                .expect("the path has an arc");
+
+            for &e in &path {
+                network.push(e, delta);
+            }
+
+            value = value + delta;
+
+            // Retreat to the tail of the first saturated arc.
+            let i = path
+                .iter()
+                .position(|&e| network.capacities[e] == zero)
+                .expect("an arc is saturated");
+
+            u = network.heads[path[i] ^ 1];
+            path.truncate(i);
+
+            continue;
+        }
+
+        let arc = network.out[u][current[u]..].iter().position(|&e| {
+            network.capacities[e] > zero
+                && level[network.heads[e]] == level[u] + 1
+        });
+
+        if let Some(i) = arc {
+            current[u] += i;
+
+            let e = network.out[u][current[u]];
+
+            path.push(e);
+            u = network.heads[e];
+        } else {
+            // Remove the dead end from the level digraph.
+            current[u] = network.out[u].len();
+            level[u] = usize::MAX;
+
+            let Some(e) = path.pop() else {
+                return value;
+            };
+
+            u = network.heads[e ^ 1];
+            current[u] += 1;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::*,
+        crate::{
+            AddArcWeighted,
+            AdjacencyListWeighted,
+            BfsDist,
+            Empty,
+            Menger,
+            PushRelabel,
+            proptest_strategy::digraph_weighted,
+            repr::adjacency_list_weighted::fixture::{
+                bang_jensen_94_usize,
+                bang_jensen_96_usize,
+                kattis_bryr_2_usize,
+                kattis_crosscountry_usize,
+                kattis_shortestpath1_usize,
+            },
+        },
+        proptest::prelude::*,
+        std::iter::once,
+    };
+
+    #[test]
+    fn max_flow_antiparallel() {
+        let digraph =
+            digraph_weighted(4, &[(0, 1, 3), (1, 2, 2), (2, 1, 2), (2, 3, 4)]);
+        let max_flow = Dinic::new(&digraph).max_flow(0, 3);
+
+        assert_eq!(max_flow.value, 2);
+
+        assert!(max_flow.residual.arcs_weighted().eq([
+            (0, 1, &1),
+            (1, 0, &2),
+            (2, 1, &4),
+            (2, 3, &2),
+            (3, 2, &2),
+        ]));
+    }
+
+    #[test]
+    fn max_flow_bang_jensen_94() {
+        let digraph = bang_jensen_94_usize();
+
+        assert_eq!(Dinic::new(&digraph).max_flow(0, 5).value, 2);
+    }
+
+    #[test]
+    fn max_flow_bang_jensen_96() {
+        let digraph = bang_jensen_96_usize();
+
+        assert_eq!(Dinic::new(&digraph).max_flow(0, 5).value, 2);
+    }
+
+    #[test]
+    fn max_flow_kattis_bryr_2() {
+        let digraph = kattis_bryr_2_usize();
+
+        assert_eq!(Dinic::new(&digraph).max_flow(0, 5).value, 1);
+    }
+
+    #[test]
+    fn max_flow_kattis_crosscountry() {
+        let digraph = kattis_crosscountry_usize();
+
+        assert_eq!(Dinic::new(&digraph).max_flow(0, 3).value, 18);
+    }
+
+    #[test]
+    fn max_flow_kattis_shortestpath1() {
+        let digraph = kattis_shortestpath1_usize();
+        let max_flow = Dinic::new(&digraph).max_flow(0, 3);
+
+        assert_eq!(max_flow.value, 0);
+        assert!(max_flow.flows.arcs_weighted().all(|(_, _, &f)| f == 0));
+    }
+
+    #[test]
+    #[should_panic(expected = "arc (0, 1) has a negative weight")]
+    fn max_flow_negative_weight() {
+        let mut digraph = AdjacencyListWeighted::<isize>::empty(2);
+
+        digraph.add_arc_weighted(0, 1, -1);
+
+        drop(Dinic::new(&digraph).max_flow(0, 1));
+    }
+
+    #[test]
+    #[should_panic(expected = "s and t are the same vertex")]
+    fn max_flow_s_eq_t() {
+        let digraph = digraph_weighted(2, &[(0, 1, 1)]);
+
+        drop(Dinic::new(&digraph).max_flow(1, 1));
+    }
+
+    #[test]
+    #[should_panic(expected = "t = 2 isn't in the digraph")]
+    fn max_flow_t_out_of_bounds() {
+        let digraph = digraph_weighted(2, &[(0, 1, 1)]);
+
+        drop(Dinic::new(&digraph).max_flow(0, 2));
+    }
+
+    proptest! {
+        #[test]
+        fn max_flow_eq_push_relabel(
+            order in 2..8_usize,
+            arcs in prop::collection::vec(
+                (0..8_usize, 0..8_usize, 0..10_usize),
+                0..24,
+            ),
+        ) {
+            let digraph = digraph_weighted(order, &arcs);
+            let t = order - 1;
+
+            assert_eq!(
+                Dinic::new(&digraph).max_flow(0, t).value,
+                PushRelabel::new(&digraph).max_flow(0, t).value
+            );
+        }
+
+        #[test]
+        fn max_flow_unit_eq_menger(
+            order in 2..8_usize,
+            arcs in prop::collection::vec((0..8_usize, 0..8_usize), 0..24),
+        ) {
+            let arcs = arcs.into_iter().map(|(u, v)| (u, v, 1)).collect::<Vec<_>>();
+            let digraph = digraph_weighted(order, &arcs);
+            let t = order - 1;
+
+            assert_eq!(
+                Dinic::new(&digraph).max_flow(0, t).value,
+                Menger::new(&digraph).arc_disjoint_paths(0, t).len()
+            );
+        }
+
+        #[test]
+        fn max_flow_valid(
+            order in 2..8_usize,
+            arcs in prop::collection::vec(
+                (0..8_usize, 0..8_usize, 0..10_usize),
+                0..24,
+            ),
+        ) {
+            let digraph = digraph_weighted(order, &arcs);
+            let t = order - 1;
+            let max_flow = Dinic::new(&digraph).max_flow(0, t);
+            let mut net = vec![0_isize; order];
+
+            for ((u, v, &f), (_, _, &c)) in
+                max_flow.flows.arcs_weighted().zip(digraph.arcs_weighted())
+            {
+                assert!(f <= c);
+
+                net[u] -= isize::try_from(f).unwrap();
+                net[v] += isize::try_from(f).unwrap();
+            }
+
+            let value = isize::try_from(max_flow.value).unwrap();
+
+            assert_eq!(net[0], -value);
+            assert_eq!(net[t], value);
+            assert!(net[1..t].iter().all(|&n| n == 0));
+
+            assert_eq!(
+                BfsDist::new(&max_flow.residual, once(0)).distances()[t],
+                usize::MAX
+            );
+        }
+    }
+}
@@ -0,0 +1,557 @@
+//! Maximum flow.
+//!
+//! A flow in an arc-weighted digraph assigns each arc a value between zero
+//! and the arc's weight, its capacity, such that every vertex other than the
+//! source and the sink has as much flow entering it as leaving it. A maximum
+//! flow has the largest possible net flow out of the source.
+//!
+//! - [`Dinic`](dinic::Dinic) augments blocking flows in a level digraph.
+//! - [`PushRelabel`](push_relabel::PushRelabel) pushes excess flow from the
+//!   highest active vertex.
+//!
+//! Both return a [`MaxFlow`] with the flow value, the flow on each arc, and
+//! the residual digraph. [`MaxFlow::min_cut`] extracts a [`MinCut`] from it.
+//!
+//! A minimum-cost maximum flow is a maximum flow with the smallest total cost
+//! in a digraph whose arc weights are `(capacity, cost)` pairs.
+//!
+//! - [`SuccessiveShortestPaths`](successive_shortest_paths::SuccessiveShortestPaths)
+//!   augments along cheapest paths.
+//! - [`NetworkSimplex`](network_simplex::NetworkSimplex) pivots a spanning
+//!   tree of the digraph.
+//!
+//! Both return a [`MinCostFlow`].
+//!
+//! # Examples
+//!
+//! ```
+//! use graaf::{
+//!     AddArcWeighted,
+//!     AdjacencyListWeighted,
+//!     ArcsWeighted,
+//!     Dinic,
+//!     Empty,
+//!     PushRelabel,
+//! };
+//!
+//! let mut digraph = AdjacencyListWeighted::<usize>::empty(4);
+//!
+//! digraph.add_arc_weighted(0, 1, 3);
+//! digraph.add_arc_weighted(0, 2, 2);
+//! digraph.add_arc_weighted(1, 2, 5);
+//! digraph.add_arc_weighted(1, 3, 2);
+//! digraph.add_arc_weighted(2, 3, 3);
+//!
+//! let max_flow = Dinic::new(&digraph).max_flow(0, 3);
+//!
+//! assert_eq!(max_flow.value, 5);
+//! assert_eq!(PushRelabel::new(&digraph).max_flow(0, 3).value, 5);
+//!
+//! assert!(max_flow.flows.arcs_weighted().eq([
+//!     (0, 1, &3),
+//!     (0, 2, &2),
+//!     (1, 2, &1),
+//!     (1, 3, &2),
+//!     (2, 3, &3),
+//! ]));
+//! ```
+
+pub mod dinic;
+pub mod network_simplex;
+pub mod push_relabel;
+pub mod successive_shortest_paths;
+
+use {
+    crate::{
+        AddArcWeighted,
+        AdjacencyListWeighted,
+        ArcWeight,
+        ArcsWeighted,
+        BellmanFordMoore,
+        Bfs,
+        Empty,
+        NegativeCircuit,
+        Order,
+    },
+    std::{
+        collections::{
+            BTreeMap,
+            BTreeSet,
+        },
+        iter::once,
+        ops::{
+            Add,
+            Sub,
+        },
+    },
+};
+
+/// A maximum flow.
+///
+/// # Examples
+///
+/// ```
+/// use graaf::{
+///     AddArcWeighted,
+///     AdjacencyListWeighted,
+///     ArcsWeighted,
+///     Dinic,
+///     Empty,
+/// };
+///
+/// let mut digraph = AdjacencyListWeighted::<usize>::empty(3);
+///
+/// digraph.add_arc_weighted(0, 1, 4);
+/// digraph.add_arc_weighted(1, 2, 3);
+///
+/// let max_flow = Dinic::new(&digraph).max_flow(0, 2);
+///
+/// assert_eq!(max_flow.value, 3);
+/// assert!(max_flow.flows.arcs_weighted().eq([(0, 1, &3), (1, 2, &3)]));
+///
+/// assert!(max_flow.residual.arcs_weighted().eq([
+///     (0, 1, &1),
+///     (1, 0, &3),
+///     (2, 1, &3),
+/// ]));
+/// ```
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[non_exhaustive]
+pub struct MaxFlow<W> {
+    /// The source vertex.
+    pub source: usize,
+    /// The net flow out of the source.
+    pub value: W,
+    /// The flow on each arc of the digraph.
+    pub flows: AdjacencyListWeighted<W>,
+    /// The arcs with positive residual capacity and their capacity.
+    pub residual: AdjacencyListWeighted<W>,
+}
+
+impl<W> MaxFlow<W> {
+    /// Find a minimum cut.
+    ///
+    /// The source side contains the vertices reachable from the source in the
+    /// residual digraph. A maximum flow saturates every arc leaving the source
+    /// side, so the weight of each cut arc is its flow. Arcs with zero
+    /// capacity aren't cut arcs.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use {
+    ///     graaf::{
+    ///         AddArcWeighted,
+    ///         AdjacencyListWeighted,
+    ///         Dinic,
+    ///         Empty,
+    ///     },
+    ///     std::collections::BTreeSet,
+    /// };
+    ///
+    /// let mut digraph = AdjacencyListWeighted::<usize>::empty(4);
+    ///
+    /// digraph.add_arc_weighted(0, 1, 4);
+    /// digraph.add_arc_weighted(0, 2, 3);
+    /// digraph.add_arc_weighted(1, 2, 5);
+    /// digraph.add_arc_weighted(1, 3, 2);
+    /// digraph.add_arc_weighted(2, 3, 3);
+    ///
+    /// let min_cut = Dinic::new(&digraph).max_flow(0, 3).min_cut();
+    ///
+    /// assert_eq!(min_cut.source_side, BTreeSet::from([0, 1, 2]));
+    /// assert_eq!(min_cut.arcs, vec![(1, 3, 2), (2, 3, 3)]);
+    /// ```
+    #[must_use]
+    pub fn min_cut(&self) -> MinCut<W>
+    where
+        W: Copy + Default + Ord,
+    {
+        let source_side = Bfs::new(&self.residual, once(self.source))
+            .collect::<BTreeSet<_>>();
+
+        let arcs = self
+            .flows
+            .arcs_weighted()
+            .filter(|&(u, v, &w)| {
+                w > W::default()
+                    && source_side.contains(&u)
+                    && !source_side.contains(&v)
+            })
+            .map(|(u, v, &w)| (u, v, w))
+            .collect();
+
+        MinCut { source_side, arcs }
+    }
+}
+
+/// A minimum cut.
+///
+/// # Examples
+///
+/// ```
+/// use {
+///     graaf::{
+///         AddArcWeighted,
+///         AdjacencyListWeighted,
+///         Empty,
+///         PushRelabel,
+///     },
+///     std::collections::BTreeSet,
+/// };
+///
+/// let mut digraph = AdjacencyListWeighted::<usize>::empty(3);
+///
+/// digraph.add_arc_weighted(0, 1, 4);
+/// digraph.add_arc_weighted(1, 2, 3);
+///
+/// let min_cut = PushRelabel::new(&digraph).max_flow(0, 2).min_cut();
+///
+/// assert_eq!(min_cut.source_side, BTreeSet::from([0, 1]));
+/// assert_eq!(min_cut.arcs, vec![(1, 2, 3)]);
+/// ```
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[non_exhaustive]
+pub struct MinCut<W> {
+    /// The vertices on the source side of the cut.
+    pub source_side: BTreeSet<usize>,
+    /// The arcs from the source side to the sink side and their weights.
+    pub arcs: Vec<(usize, usize, W)>,
+}
+
+/// A minimum-cost maximum flow.
+///
+/// # Examples
+///
+/// ```
+/// use graaf::{
+///     AddArcWeighted,
+///     AdjacencyListWeighted,
+///     ArcsWeighted,
+///     Empty,
+///     SuccessiveShortestPaths,
+/// };
+///
+/// let mut digraph = AdjacencyListWeighted::<(usize, isize)>::empty(3);
+///
+/// digraph.add_arc_weighted(0, 1, (2, 5));
+/// digraph.add_arc_weighted(0, 2, (1, 1));
+/// digraph.add_arc_weighted(2, 1, (3, 1));
+///
+/// let min_cost_flow = SuccessiveShortestPaths::new(&digraph)
+///     .min_cost_max_flow(0, 1)
+///     .unwrap();
+///
+/// assert_eq!(min_cost_flow.value, 3);
+/// assert_eq!(min_cost_flow.cost, 12);
+///
+/// assert!(min_cost_flow.flows.arcs_weighted().eq([
+///     (0, 1, &2),
+///     (0, 2, &1),
+///     (2, 1, &1),
+/// ]));
+/// ```
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[non_exhaustive]
+pub struct MinCostFlow {
+    /// The net flow out of the source.
+    pub value: usize,
+    /// The total cost of the flow.
+    pub cost: isize,
+    /// The flow on each arc of the digraph.
+    pub flows: AdjacencyListWeighted<usize>,
+}
+
+/// Return the total cost of the flows in a digraph whose arc weights are
+/// `(capacity, cost)` pairs.
+fn total_cost<D>(digraph: &D, flows: &AdjacencyListWeighted<usize>) -> isize
+where
+    D: ArcsWeighted<Weight = (usize, isize)>,
+{
+    digraph
+        .arcs_weighted()
+        .filter_map(|(u, v, &(_, w))| {
+            let &f = flows.arc_weight(u, v)?;
+
+            Some(w * isize::try_from(f).expect("the flow fits in isize"))
+        })
+        .sum()
+}
+
+/// Return vertex potentials that make the reduced cost of every arc with
+/// positive capacity non-negative in a digraph whose arc weights are
+/// `(capacity, cost)` pairs.
+///
+/// The potentials are the distances from an artificial vertex with an arc of
+/// cost `0` to every vertex.
+///
+/// # Errors
+///
+/// Returns the [`NegativeCircuit`] if the arcs with positive capacity contain
+/// a circuit with negative cost.
+fn potentials<D>(digraph: &D) -> Result<Vec<isize>, NegativeCircuit>
+where
+    D: ArcsWeighted<Weight = (usize, isize)> + Order,
+{
+    let order = digraph.order();
+    let mut residual = AdjacencyListWeighted::<isize>::empty(order + 1);
+
+    for (u, v, &(c, w)) in digraph.arcs_weighted() {
+        if u != v && c > 0 {
+            residual.add_arc_weighted(u, v, w);
+        }
+    }
+
+    for v in 0..order {
+        residual.add_arc_weighted(order, v, 0);
+    }
+
+    Ok(BellmanFordMoore::new(&residual, order).distances()?[..order].to_vec())
+}
+
+/// A residual digraph.
+///
+/// Arc `2 * i` is the `i`-th arc of the digraph and arc `2 * i + 1` its
+/// reverse. The residual capacity of the reverse arc is the flow on the arc.
+pub(crate) struct Network<W> {
+    heads: Vec<usize>,
+    capacities: Vec<W>,
+    out: Vec<Vec<usize>>,
+}
+
+impl<W> Network<W>
+where
+    W: Add<Output = W> + Copy + Default + Ord + Sub<Output = W>,
+{
+    fn new<D>(digraph: &D, s: usize, t: usize) -> Self
+    where
+        D: ArcsWeighted<Weight = W> + Order,
+    {
+        Self::from_arcs(
+            digraph.order(),
+            s,
+            t,
+            digraph.arcs_weighted().map(|(u, v, &c)| (u, v, c)),
+        )
+    }
+
+    /// Build the residual digraph from arcs and their capacities, skipping
+    /// loops.
+    pub(crate) fn from_arcs<T>(
+        order: usize,
+        s: usize,
+        t: usize,
+        arcs: T,
+    ) -> Self
+    where
+        T: Iterator<Item = (usize, usize, W)>,
+    {
+        assert!(s < order, "s = {s} isn't in the digraph");
+        assert!(t < order, "t = {t} isn't in the digraph");
+        assert_ne!(s, t, "s and t are the same vertex");
+
+        let mut network = Self {
+            heads: Vec::new(),
+            capacities: Vec::new(),
+            out: vec![Vec::new(); order],
+        };
+
+        for (u, v, c) in arcs {
+            if u == v {
+                continue;
+            }
+
+            assert!(c >= W::default(), "arc ({u}, {v}) has a negative weight");
+
+            network.out[u].push(network.heads.len());
+            network.heads.push(v);
+            network.capacities.push(c);
+            network.out[v].push(network.heads.len());
+            network.heads.push(u);
+            network.capacities.push(W::default());
+        }
+
+        network
+    }
+
+    /// Push `delta` units of flow along residual arc `e`.
+    fn push(&mut self, e: usize, delta: W) {
+        self.capacities[e] = self.capacities[e] - delta;
+        self.capacities[e ^ 1] = self.capacities[e ^ 1] + delta;
+    }
+
+    /// Collect the flow on each arc and the residual digraph.
+    fn into_max_flow(self, s: usize, value: W) -> MaxFlow<W> {
+        let order = self.out.len();
+        let zero = W::default();
+        let mut flows = AdjacencyListWeighted::empty(order);
+        let mut residual = BTreeMap::new();
+
+        for (e, &c) in self.capacities.iter().enumerate() {
+            let u = self.heads[e ^ 1];
+            let v = self.heads[e];
+
+            if e % 2 == 0 {
+                flows.add_arc_weighted(u, v, self.capacities[e ^ 1]);
+            }
+
+            if c > zero {
+                let _ = residual
+                    .entry((u, v))
+                    .and_modify(|r| *r = *r + c)
+                    .or_insert(c);
+            }
+        }
+
+        let mut residual_digraph = AdjacencyListWeighted::empty(order);
+
+        for ((u, v), c) in residual {
+            residual_digraph.add_arc_weighted(u, v, c);
+        }
+
+        MaxFlow {
+            source: s,
+            value,
+            flows,
+            residual: residual_digraph,
+        }
+    }
+}
+
+impl Network<usize> {
+    /// Decompose `k` units of flow from `s` to `t` into simple paths.
+    pub(crate) fn into_paths(
+        mut self,
+        s: usize,
+        t: usize,
+        k: usize,
+    ) -> Vec<Vec<usize>> {
+        let mut position = vec![usize::MAX; self.out.len()];
+        let mut paths = Vec::with_capacity(k);
+
+        for _ in 0..k {
+            let mut path = vec![s];
+            let mut u = s;
+
+            position[s] = 0;
+
+            while u != t {
+                let e = *self.out[u]
+                    .iter()
+                    .find(|&&e| e % 2 == 0 && self.capacities[e ^ 1] > 0)
+                    .expect("flow is conserved");
+
+                self.capacities[e ^ 1] -= 1;
+                u = self.heads[e];
+
+                // Cut out the cycle closed by a revisited vertex.
+                if position[u] == usize::MAX {
+                    position[u] = path.len();
+                    path.push(u);
+                } else {
+                    for &v in &path[position[u] + 1..] {
+                        position[v] = usize::MAX;
+                    }
+
+                    path.truncate(position[u] + 1);
+                }
+            }
+
+            for &v in &path {
+                position[v] = usize::MAX;
+            }
+
+            paths.push(path);
+        }
+
+        paths
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::*,
+        crate::{
+            ArcWeight,
+            Dinic,
+            PushRelabel,
+            proptest_strategy::digraph_weighted,
+            repr::adjacency_list_weighted::fixture::{
+                bang_jensen_96_usize,
+                kattis_crosscountry_usize,
+                kattis_shortestpath1_usize,
+            },
+        },
+        proptest::prelude::*,
+    };
+
+    #[test]
+    fn min_cut_bang_jensen_96() {
+        let digraph = bang_jensen_96_usize();
+        let min_cut = Dinic::new(&digraph).max_flow(0, 5).min_cut();
+
+        assert_eq!(min_cut.source_side, BTreeSet::from([0, 1, 2, 3]));
+        assert_eq!(min_cut.arcs, vec![(2, 4, 1), (3, 5, 1)]);
+    }
+
+    #[test]
+    fn min_cut_kattis_crosscountry() {
+        let digraph = kattis_crosscountry_usize();
+        let min_cut = Dinic::new(&digraph).max_flow(0, 3).min_cut();
+
+        assert_eq!(min_cut.source_side, BTreeSet::from([0]));
+        assert_eq!(min_cut.arcs, vec![(0, 1, 1), (0, 2, 3), (0, 3, 14)]);
+    }
+
+    #[test]
+    fn min_cut_kattis_shortestpath1() {
+        let digraph = kattis_shortestpath1_usize();
+        let min_cut = Dinic::new(&digraph).max_flow(0, 3).min_cut();
+
+        assert_eq!(min_cut.source_side, BTreeSet::from([0, 1, 2]));
+        assert!(min_cut.arcs.is_empty());
+    }
+
+    #[test]
+    fn min_cut_zero_capacity() {
+        let digraph = digraph_weighted(4, &[(0, 1, 1), (0, 2, 0), (1, 3, 1)]);
+        let min_cut = Dinic::new(&digraph).max_flow(0, 3).min_cut();
+
+        assert_eq!(min_cut.source_side, BTreeSet::from([0]));
+        assert_eq!(min_cut.arcs, vec![(0, 1, 1)]);
+    }
+
+    proptest! {
+        #[test]
+        fn min_cut_weight_eq_max_flow_value(
+            order in 2..8_usize,
+            arcs in prop::collection::vec(
+                (0..8_usize, 0..8_usize, 0..10_usize),
+                0..24,
+            ),
+        ) {
+            let digraph = digraph_weighted(order, &arcs);
+            let t = order - 1;
+
+            for max_flow in [
+                Dinic::new(&digraph).max_flow(0, t),
+                PushRelabel::new(&digraph).max_flow(0, t),
+            ] {
+                let min_cut = max_flow.min_cut();
+
+                assert!(min_cut.source_side.contains(&0));
+                assert!(!min_cut.source_side.contains(&t));
+
+                for &(u, v, w) in &min_cut.arcs {
+                    assert_eq!(digraph.arc_weight(u, v), Some(&w));
+                }
+
+                assert_eq!(
+                    min_cut.arcs.iter().map(|&(_, _, w)| w).sum::<usize>(),
+                    max_flow.value
+                );
+            }
+        }
+    }
+}
@@ -0,0 +1,711 @@
+//! Network simplex.
+//!
+//! The network simplex method finds a minimum-cost maximum flow from a source
+//! to a sink in a digraph whose arc weights are `(capacity, cost)` pairs.[^1]
+//! It maintains a spanning tree of basic arcs and pivots an arc with negative
+//! reduced cost into the tree until none is left. An arc from the sink to the
+//! source with a lexicographically smaller cost makes the flow value the first
+//! objective and the cost the second. Strongly feasible trees rule out
+//! cycling.[^2] Like [`SuccessiveShortestPaths`], the method rejects digraphs
+//! whose arcs with positive capacity contain a circuit with negative cost.
+//!
+//! The time complexity is `O(p (v + a))`, where `p` is the number of pivots,
+//! `v` is the digraph's order, and `a` is the digraph's size. Each pivot
+//! recomputes the whole tree instead of only the subtree that moves.
+//!
+//! # Examples
+//!
+//! ```
+//! use graaf::{
+//!     AddArcWeighted,
+//!     AdjacencyListWeighted,
+//!     ArcsWeighted,
+//!     Empty,
+//!     NetworkSimplex,
+//! };
+//!
+//! let mut digraph = AdjacencyListWeighted::<(usize, isize)>::empty(4);
+//!
+//! digraph.add_arc_weighted(0, 1, (2, 1));
+//! digraph.add_arc_weighted(0, 2, (2, 4));
+//! digraph.add_arc_weighted(1, 2, (1, 1));
+//! digraph.add_arc_weighted(1, 3, (1, 5));
+//! digraph.add_arc_weighted(2, 3, (3, 1));
+//!
+//! let min_cost_flow = NetworkSimplex::new(&digraph)
+//!     .min_cost_max_flow(0, 3)
+//!     .unwrap();
+//!
+//! assert_eq!(min_cost_flow.value, 4);
+//! assert_eq!(min_cost_flow.cost, 19);
+//!
+//! assert!(min_cost_flow.flows.arcs_weighted().eq([
+//!     (0, 1, &2),
+//!     (0, 2, &2),
+//!     (1, 2, &1),
+//!     (1, 3, &1),
+//!     (2, 3, &3),
+//! ]));
+//! ```
+//!
+//! [`SuccessiveShortestPaths`]: super::successive_shortest_paths::SuccessiveShortestPaths
+//!
+//! [^1]: George B. Dantzig. 1951. Application of the simplex method to a
+//!   transportation problem. In Activity Analysis of Production and
+//!   Allocation, T. C. Koopmans (Ed.). Wiley, New York, 359–373.
+//!
+//! [^2]: W. H. Cunningham. 1976. A network simplex method. Mathematical
+//!   Programming 11, 1 (1976), 105–116. <https://doi.org/10.1007/BF01580379>
+
+use {
+    super::{
+        MinCostFlow,
+        potentials,
+        total_cost,
+    },
+    crate::{
+        AddArcWeighted,
+        AdjacencyListWeighted,
+        ArcsWeighted,
+        Empty,
+        NegativeCircuit,
+        Order,
+    },
+    std::collections::VecDeque,
+};
+
+/// A lexicographic cost. The first component prices the flow value and the
+/// second the flow cost.
+type Cost = (isize, isize);
+
+const fn add(a: Cost, b: Cost) -> Cost {
+    (a.0 + b.0, a.1 + b.1)
+}
+
+const fn sub(a: Cost, b: Cost) -> Cost {
+    (a.0 - b.0, a.1 - b.1)
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+enum State {
+    Lower,
+    Tree,
+    Upper,
+}
+
+/// A spanning tree solution.
+///
+/// The tree is rooted at an artificial vertex with an arc from every other
+/// vertex.
+struct Simplex {
+    sources: Vec<usize>,
+    targets: Vec<usize>,
+    capacities: Vec<usize>,
+    costs: Vec<Cost>,
+    flows: Vec<usize>,
+    states: Vec<State>,
+    parent: Vec<usize>,
+    pred: Vec<usize>,
+    up: Vec<bool>,
+    depth: Vec<usize>,
+    potentials: Vec<Cost>,
+}
+
+impl Simplex {
+    fn add_arc(&mut self, u: usize, v: usize, capacity: usize, cost: Cost) {
+        self.sources.push(u);
+        self.targets.push(v);
+        self.capacities.push(capacity);
+        self.costs.push(cost);
+        self.flows.push(0);
+        self.states.push(State::Lower);
+    }
+
+    fn reduced_cost(&self, e: usize) -> Cost {
+        sub(
+            add(self.costs[e], self.potentials[self.sources[e]]),
+            self.potentials[self.targets[e]],
+        )
+    }
+
+    /// Find an arc that improves the solution, searching from `start`.
+    fn entering_arc(&self, start: usize) -> Option<usize> {
+        let size = self.sources.len();
+
+        (start..size)
+            .chain(0..start)
+            .find(|&e| match self.states[e] {
+                State::Lower => {
+                    self.capacities[e] > 0 && self.reduced_cost(e) < (0, 0)
+                }
+                State::Upper => self.reduced_cost(e) > (0, 0),
+                State::Tree => false,
+            })
+    }
+
+    /// Push flow around the circuit that `e` closes in the tree and return
+    /// the arc that leaves the tree.
+    fn augment(&mut self, e: usize) -> usize {
+        let (first, second) = if self.states[e] == State::Lower {
+            (self.sources[e], self.targets[e])
+        } else {
+            (self.targets[e], self.sources[e])
+        };
+
+        let mut u = first;
+        let mut v = second;
+
+        while u != v {
+            if self.depth[u] >= self.depth[v] {
+                u = self.parent[u];
+            } else {
+                v = self.parent[v];
+            }
+        }
+
+        let join = u;
+        let mut delta = self.capacities[e];
+        let mut leaving = e;
+
+        // Of the arcs that block the circuit, the last one from the join
+        // leaves the tree. This keeps the tree strongly feasible.
+        let mut u = first;
+
+        while u != join {
+            let a = self.pred[u];
+
+            let residual = if self.up[u] {
+                self.flows[a]
+            } else {
+                self.capacities[a] - self.flows[a]
+            };
+
+            if residual < delta {
+                delta = residual;
+                leaving = a;
+            }
+
+            u = self.parent[u];
+        }
+
+        let mut v = second;
+
+        while v != join {
+            let a = self.pred[v];
+
+            let residual = if self.up[v] {
+                self.capacities[a] - self.flows[a]
+            } else {
+                self.flows[a]
+            };
+
+            if residual <= delta {
+                delta = residual;
+                leaving = a;
+            }
+
+            v = self.parent[v];
+        }
+
+        if delta > 0 {
+            if self.states[e] == State::Lower {
+                self.flows[e] += delta;
+            } else {
+                self.flows[e] -= delta;
+            }
+
+            let mut u = first;
+
+            while u != join {
+                let a = self.pred[u];
+
+                if self.up[u] {
+                    self.flows[a] -= delta;
+                } else {
+                    self.flows[a] += delta;
+                }
+
+                u = self.parent[u];
+            }
+
+            let mut v = second;
+
+            while v != join {
+                let a = self.pred[v];
+
+                if self.up[v] {
+                    self.flows[a] += delta;
+                } else {
+                    self.flows[a] -= delta;
+                }
+
+                v = self.parent[v];
+            }
+        }
+
+        leaving
+    }
+
+    /// Recompute the parents, depths, and potentials from the tree arcs.
+    ///
+    /// This takes `O(v + a)` time on every pivot.
+    fn rebuild(&mut self, root: usize) {
+        let mut tree = vec![Vec::new(); root + 1];
+
+        for (e, state) in self.states.iter().enumerate() {
+            if *state == State::Tree {
+                tree[self.sources[e]].push(e);
+                tree[self.targets[e]].push(e);
+            }
+        }
+
+        let mut visited = vec![false; root + 1];
+        let mut queue = VecDeque::from([root]);
+
+        visited[root] = true;
+        self.depth[root] = 0;
+        self.potentials[root] = (0, 0);
+
+        while let Some(u) = queue.pop_front() {
+            for &e in &tree[u] {
+                let up = self.targets[e] == u;
+                let v = if up { self.sources[e] } else { self.targets[e] };
+
+                if visited[v] {
+                    continue;
+                }
+
+                visited[v] = true;
+                self.parent[v] = u;
+                self.pred[v] = e;
+                self.up[v] = up;
+                self.depth[v] = self.depth[u] + 1;
+
+                self.potentials[v] = if up {
+                    sub(self.potentials[u], self.costs[e])
+                } else {
+                    add(self.potentials[u], self.costs[e])
+                };
+
+                queue.push_back(v);
+            }
+        }
+    }
+}
+
+/// Network simplex.
+///
+/// # Examples
+///
+/// ```
+/// use graaf::{
+///     AddArcWeighted,
+///     AdjacencyListWeighted,
+///     ArcsWeighted,
+///     Empty,
+///     NetworkSimplex,
+/// };
+///
+/// let mut digraph = AdjacencyListWeighted::<(usize, isize)>::empty(4);
+///
+/// digraph.add_arc_weighted(0, 1, (2, 1));
+/// digraph.add_arc_weighted(0, 2, (2, 4));
+/// digraph.add_arc_weighted(1, 2, (1, 1));
+/// digraph.add_arc_weighted(1, 3, (1, 5));
+/// digraph.add_arc_weighted(2, 3, (3, 1));
+///
+/// let min_cost_flow = NetworkSimplex::new(&digraph)
+///     .min_cost_max_flow(0, 3)
+///     .unwrap();
+///
+/// assert_eq!(min_cost_flow.value, 4);
+/// assert_eq!(min_cost_flow.cost, 19);
+///
+/// assert!(min_cost_flow.flows.arcs_weighted().eq([
+///     (0, 1, &2),
+///     (0, 2, &2),
+///     (1, 2, &1),
+///     (1, 3, &1),
+///     (2, 3, &3),
+/// ]));
+/// ```
+#[derive(Clone, Debug)]
+pub struct NetworkSimplex<'a, D> {
+    digraph: &'a D,
+}
+
+impl<'a, D> NetworkSimplex<'a, D>
+where
+    D: ArcsWeighted<Weight = (usize, isize)> + Order,
+{
+    /// Initialize the network simplex method.
+    ///
+    /// # Arguments
+    ///
+    /// * `digraph`: The digraph. Each arc weight is a `(capacity, cost)` pair.
+    #[must_use]
+    pub const fn new(digraph: &'a D) -> Self {
+        Self { digraph }
+    }
+
+    /// Find a minimum-cost maximum flow.
+    ///
+    /// # Arguments
+    ///
+    /// * `s`: The source vertex.
+    /// * `t`: The sink vertex.
+    ///
+    /// # Errors
+    ///
+    /// Returns the [`NegativeCircuit`] if the arcs with positive capacity
+    /// contain a circuit with negative cost.
+    ///
+    /// # Panics
+    ///
+    /// * Panics if `s` isn't in the digraph.
+    /// * Panics if `t` isn't in the digraph.
+    /// * Panics if `s` equals `t`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use graaf::{
+    ///     AddArcWeighted,
+    ///     AdjacencyListWeighted,
+    ///     Empty,
+    ///     NetworkSimplex,
+    /// };
+    ///
+    /// let mut digraph = AdjacencyListWeighted::<(usize, isize)>::empty(4);
+    ///
+    /// digraph.add_arc_weighted(0, 1, (1, 2));
+    /// digraph.add_arc_weighted(2, 3, (2, -3));
+    ///
+    /// let min_cost_flow = NetworkSimplex::new(&digraph)
+    ///     .min_cost_max_flow(0, 1)
+    ///     .unwrap();
+    ///
+    /// assert_eq!(min_cost_flow.value, 1);
+    /// assert_eq!(min_cost_flow.cost, 2);
+    ///
+    /// digraph.add_arc_weighted(3, 2, (1, 1));
+    ///
+    /// let circuit = NetworkSimplex::new(&digraph)
+    ///     .min_cost_max_flow(0, 1)
+    ///     .unwrap_err();
+    ///
+    /// assert!(circuit.circuit.eq(&[2, 3]));
+    /// assert_eq!(circuit.weight, -2);
+    /// ```
+    pub fn min_cost_max_flow(
+        &self,
+        s: usize,
+        t: usize,
+    ) -> Result<MinCostFlow, NegativeCircuit> {
+        drop(potentials(self.digraph)?);
+
+        Ok(self.pivot(s, t))
+    }
+
+    /// Pivot until no arc improves the solution.
+    fn pivot(&self, s: usize, t: usize) -> MinCostFlow {
+        let order = self.digraph.order();
+
+        assert!(s < order, "s = {s} isn't in the digraph");
+        assert!(t < order, "t = {t} isn't in the digraph");
+        assert_ne!(s, t, "s and t are the same vertex");
+
+        let root = order;
+
+        let mut simplex = Simplex {
+            sources: Vec::new(),
+            targets: Vec::new(),
+            capacities: Vec::new(),
+            costs: Vec::new(),
+            flows: Vec::new(),
+            states: Vec::new(),
+            parent: vec![root; order + 1],
+            pred: vec![0; order + 1],
+            up: vec![true; order + 1],
+            depth: vec![1; order + 1],
+            potentials: vec![(0, 0); order + 1],
+        };
+
+        let mut total = 0;
+
+        for (u, v, &(c, w)) in self.digraph.arcs_weighted() {
+            if u != v {
+                simplex.add_arc(u, v, c, (0, w));
+                total += c;
+            }
+        }
+
+        let size = simplex.sources.len();
+
+        simplex.add_arc(t, s, total, (-1, 0));
+
+        for v in 0..order {
+            simplex.pred[v] = simplex.sources.len();
+            simplex.add_arc(v, root, total, (0, 0));
+            simplex.states[simplex.pred[v]] = State::Tree;
+        }
+
+        simplex.depth[root] = 0;
+
+        let mut start = 0;
+
+        while let Some(e) = simplex.entering_arc(start) {
+            let leaving = simplex.augment(e);
+
+            if leaving == e {
+                simplex.states[e] = if simplex.states[e] == State::Lower {
+                    State::Upper
+                } else {
+                    State::Lower
+                };
+            } else {
+                simplex.states[leaving] = if simplex.flows[leaving] == 0 {
+                    State::Lower
+                } else {
+                    State::Upper
+                };
+
+                simplex.states[e] = State::Tree;
+                simplex.rebuild(root);
+            }
+
+            start = e + 1;
+        }
+
+        let mut flows = AdjacencyListWeighted::empty(order);
+
+        for e in 0..size {
+            flows.add_arc_weighted(
+                simplex.sources[e],
+                simplex.targets[e],
+                simplex.flows[e],
+            );
+        }
+
+        let cost = total_cost(self.digraph, &flows);
+
+        MinCostFlow {
+            value: simplex.flows[size],
+            cost,
+            flows,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::*,
+        crate::{
+            Dinic,
+            proptest_strategy::digraph_weighted,
+            repr::adjacency_list_weighted::fixture::kattis_crosscountry_usize,
+        },
+        proptest::prelude::*,
+    };
+
+    fn capacities(
+        digraph: &AdjacencyListWeighted<(usize, isize)>,
+    ) -> AdjacencyListWeighted<usize> {
+        let mut capacities = AdjacencyListWeighted::empty(digraph.order());
+
+        for (u, v, &(c, _)) in digraph.arcs_weighted() {
+            capacities.add_arc_weighted(u, v, c);
+        }
+
+        capacities
+    }
+
+    #[test]
+    fn min_cost_max_flow_cheaper_longer_path() {
+        let digraph = digraph_weighted(
+            4,
+            &[
+                (0, 1, (1, 1)),
+                (0, 3, (1, 10)),
+                (1, 2, (1, 1)),
+                (2, 3, (1, 1)),
+            ],
+        );
+
+        let min_cost_flow = NetworkSimplex::new(&digraph)
+            .min_cost_max_flow(0, 3)
+            .unwrap();
+
+        assert_eq!(min_cost_flow.value, 2);
+        assert_eq!(min_cost_flow.cost, 13);
+    }
+
+    #[test]
+    fn min_cost_max_flow_kattis_crosscountry() {
+        let capacities = kattis_crosscountry_usize();
+        let mut digraph = AdjacencyListWeighted::empty(capacities.order());
+
+        for (u, v, &c) in capacities.arcs_weighted() {
+            digraph.add_arc_weighted(u, v, (c, 1));
+        }
+
+        let min_cost_flow = NetworkSimplex::new(&digraph)
+            .min_cost_max_flow(0, 3)
+            .unwrap();
+
+        assert_eq!(min_cost_flow.value, 18);
+    }
+
+    #[test]
+    fn min_cost_max_flow_negative_circuit() {
+        let digraph = digraph_weighted(
+            5,
+            &[
+                (0, 1, (2, 1)),
+                (1, 4, (2, 1)),
+                (1, 2, (3, -5)),
+                (2, 3, (3, 1)),
+                (3, 1, (2, 1)),
+            ],
+        );
+
+        assert_eq!(
+            NetworkSimplex::new(&digraph).min_cost_max_flow(0, 4),
+            Err(NegativeCircuit {
+                circuit: vec![1, 2, 3],
+                weight: -3,
+            })
+        );
+    }
+
+    #[test]
+    fn min_cost_max_flow_negative_cost() {
+        let digraph = digraph_weighted(
+            4,
+            &[
+                (0, 1, (2, -3)),
+                (0, 2, (1, 0)),
+                (1, 3, (1, 0)),
+                (2, 3, (2, 0)),
+            ],
+        );
+
+        let min_cost_flow = NetworkSimplex::new(&digraph)
+            .min_cost_max_flow(0, 3)
+            .unwrap();
+
+        assert_eq!(min_cost_flow.value, 2);
+        assert_eq!(min_cost_flow.cost, -3);
+    }
+
+    #[test]
+    fn min_cost_max_flow_reroute() {
+        let digraph = digraph_weighted(
+            4,
+            &[
+                (0, 1, (1, 1)),
+                (0, 2, (1, 3)),
+                (1, 2, (1, 1)),
+                (1, 3, (1, 3)),
+                (2, 3, (1, 1)),
+            ],
+        );
+
+        let min_cost_flow = NetworkSimplex::new(&digraph)
+            .min_cost_max_flow(0, 3)
+            .unwrap();
+
+        assert_eq!(min_cost_flow.value, 2);
+        assert_eq!(min_cost_flow.cost, 8);
+
+        assert!(min_cost_flow.flows.arcs_weighted().eq([
+            (0, 1, &1),
+            (0, 2, &1),
+            (1, 2, &0),
+            (1, 3, &1),
+            (2, 3, &1),
+        ]));
+    }
+
+    #[test]
+    fn min_cost_max_flow_unreachable() {
+        let digraph = digraph_weighted(3, &[(0, 1, (4, 1)), (2, 1, (4, 1))]);
+        let min_cost_flow = NetworkSimplex::new(&digraph)
+            .min_cost_max_flow(0, 2)
+            .unwrap();
+
+        assert_eq!(min_cost_flow.value, 0);
+        assert_eq!(min_cost_flow.cost, 0);
+    }
+
+    #[test]
+    fn min_cost_max_flow_zero_capacity() {
+        let digraph = digraph_weighted(
+            3,
+            &[(0, 1, (0, -5)), (1, 0, (1, 1)), (0, 2, (1, 1))],
+        );
+        let min_cost_flow = NetworkSimplex::new(&digraph)
+            .min_cost_max_flow(0, 2)
+            .unwrap();
+
+        assert_eq!(min_cost_flow.value, 1);
+        assert_eq!(min_cost_flow.cost, 1);
+    }
+
+    #[test]
+    #[should_panic(expected = "t = 2 isn't in the digraph")]
+    fn min_cost_max_flow_t_out_of_bounds() {
+        let digraph = digraph_weighted(2, &[(0, 1, (1, 1))]);
+
+        drop(NetworkSimplex::new(&digraph).min_cost_max_flow(0, 2));
+    }
+
+    proptest! {
+        #[test]
+        fn min_cost_max_flow_valid(
+            order in 2..8_usize,
+            arcs in prop::collection::vec(
+                (0..8_usize, 0..8_usize, (0..10_usize, -10..10_isize)),
+                0..24,
+            ),
+        ) {
+            // Acyclic arcs rule out negative circuits.
+            let arcs = arcs
+                .into_iter()
+                .map(|(u, v, w)| (u.min(v), u.max(v), w))
+                .collect::<Vec<_>>();
+
+            let digraph = digraph_weighted(order, &arcs);
+            let t = order - 1;
+
+            let min_cost_flow = NetworkSimplex::new(&digraph)
+                .min_cost_max_flow(0, t)
+                .unwrap();
+
+            let mut net = vec![0_isize; order];
+
+            for ((u, v, &f), (_, _, &(c, _))) in min_cost_flow
+                .flows
+                .arcs_weighted()
+                .zip(digraph.arcs_weighted())
+            {
+                assert!(f <= c);
+
+                net[u] -= isize::try_from(f).unwrap();
+                net[v] += isize::try_from(f).unwrap();
+            }
+
+            let value = isize::try_from(min_cost_flow.value).unwrap();
+
+            assert_eq!(net[0], -value);
+            assert_eq!(net[t], value);
+            assert!(net[1..t].iter().all(|&n| n == 0));
+
+            assert_eq!(
+                min_cost_flow.value,
+                Dinic::new(&capacities(&digraph)).max_flow(0, t).value
+            );
+        }
+    }
+}
@@ -0,0 +1,360 @@
+//! Highest-label push-relabel algorithm.
+//!
+//! The push-relabel algorithm finds a maximum flow from a source to a sink in
+//! an arc-weighted digraph whose weights are the arc capacities.[^1] It
+//! saturates the arcs out of the source and then repeatedly pushes excess
+//! flow from an active vertex to a lower neighbor, raising the vertex's
+//! height when no such neighbor exists. Selecting the active vertex with the
+//! highest label bounds the number of pushes.[^2]
+//!
+//! The time complexity is `O(v² √a)`, where `v` is the digraph's order and
+//! `a` is the digraph's size.
+//!
+//! # Examples
+//!
+//! ```
+//! use graaf::{
+//!     AddArcWeighted,
+//!     AdjacencyListWeighted,
+//!     Empty,
+//!     PushRelabel,
+//! };
+//!
+//! let mut digraph = AdjacencyListWeighted::<usize>::empty(6);
+//!
+//! digraph.add_arc_weighted(0, 1, 16);
+//! digraph.add_arc_weighted(0, 2, 13);
+//! digraph.add_arc_weighted(1, 3, 12);
+//! digraph.add_arc_weighted(2, 1, 4);
+//! digraph.add_arc_weighted(2, 4, 14);
+//! digraph.add_arc_weighted(3, 2, 9);
+//! digraph.add_arc_weighted(3, 5, 20);
+//! digraph.add_arc_weighted(4, 3, 7);
+//! digraph.add_arc_weighted(4, 5, 4);
+//!
+//! assert_eq!(PushRelabel::new(&digraph).max_flow(0, 5).value, 23);
+//! ```
+//!
+//! [^1]: Andrew V. Goldberg and Robert E. Tarjan. 1988. A new approach to the
+//!   maximum-flow problem. J. ACM 35, 4 (Oct. 1988), 921–940.
+//!   <https://doi.org/10.1145/48014.61051>
+//!
+//! [^2]: J. Cheriyan and S. N. Maheshwari. 1989. Analysis of preflow push
+//!   algorithms for maximum network flow. SIAM J. Comput. 18, 6 (1989),
+//!   1057–1086. <https://doi.org/10.1137/0218072>
+
+use {
+    super::{
+        MaxFlow,
+        Network,
+    },
+    crate::{
+        ArcsWeighted,
+        Order,
+    },
+    std::ops::{
+        Add,
+        Sub,
+    },
+};
+
+/// Highest-label push-relabel algorithm.
+///
+/// # Examples
+///
+/// ```
+/// use graaf::{
+///     AddArcWeighted,
+///     AdjacencyListWeighted,
+///     Empty,
+///     PushRelabel,
+/// };
+///
+/// let mut digraph = AdjacencyListWeighted::<usize>::empty(6);
+///
+/// digraph.add_arc_weighted(0, 1, 16);
+/// digraph.add_arc_weighted(0, 2, 13);
+/// digraph.add_arc_weighted(1, 3, 12);
+/// digraph.add_arc_weighted(2, 1, 4);
+/// digraph.add_arc_weighted(2, 4, 14);
+/// digraph.add_arc_weighted(3, 2, 9);
+/// digraph.add_arc_weighted(3, 5, 20);
+/// digraph.add_arc_weighted(4, 3, 7);
+/// digraph.add_arc_weighted(4, 5, 4);
+///
+/// assert_eq!(PushRelabel::new(&digraph).max_flow(0, 5).value, 23);
+/// ```
+#[derive(Clone, Debug)]
+pub struct PushRelabel<'a, D> {
+    digraph: &'a D,
+}
+
+impl<'a, D> PushRelabel<'a, D> {
+    /// Initialize the push-relabel algorithm.
+    ///
+    /// # Arguments
+    ///
+    /// * `digraph`: The digraph.
+    #[must_use]
+    pub const fn new(digraph: &'a D) -> Self {
+        Self { digraph }
+    }
+
+    /// Find a maximum flow.
+    ///
+    /// # Arguments
+    ///
+    /// * `s`: The source vertex.
+    /// * `t`: The sink vertex.
+    ///
+    /// # Panics
+    ///
+    /// * Panics if `s` isn't in the digraph.
+    /// * Panics if `t` isn't in the digraph.
+    /// * Panics if `s` equals `t`.
+    /// * Panics if an arc has a negative weight.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use graaf::{
+    ///     AddArcWeighted,
+    ///     AdjacencyListWeighted,
+    ///     ArcsWeighted,
+    ///     Empty,
+    ///     PushRelabel,
+    /// };
+    ///
+    /// let mut digraph = AdjacencyListWeighted::<usize>::empty(4);
+    ///
+    /// digraph.add_arc_weighted(0, 1, 2);
+    /// digraph.add_arc_weighted(0, 2, 2);
+    /// digraph.add_arc_weighted(1, 3, 1);
+    /// digraph.add_arc_weighted(2, 3, 3);
+    ///
+    /// let max_flow = PushRelabel::new(&digraph).max_flow(0, 3);
+    ///
+    /// assert_eq!(max_flow.value, 3);
+    ///
+    /// assert!(max_flow.flows.arcs_weighted().eq([
+    ///     (0, 1, &1),
+    ///     (0, 2, &2),
+    ///     (1, 3, &1),
+    ///     (2, 3, &2),
+    /// ]));
+    /// ```
+    #[must_use]
+    pub fn max_flow<W>(&self, s: usize, t: usize) -> MaxFlow<W>
+    where
+        D: ArcsWeighted<Weight = W> + Order,
+        W: Add<Output = W> + Copy + Default + Ord + Sub<Output = W>,
+    {
+        let mut network = Network::new(self.digraph, s, t);
+        let order = network.out.len();
+        let zero = W::default();
+        let mut height = vec![0; order];
+        let mut excess = vec![zero; order];
+        let mut current = vec![0; order];
+        let mut active = vec![Vec::new(); 2 * order];
+        let mut highest = 0;
+
+        height[s] = order;
+
+        for i in 0..network.out[s].len() {
+            let e = network.out[s][i];
+            let c = network.capacities[e];
+            let v = network.heads[e];
+
+            if c > zero {
+                network.push(e, c);
+
+                if v != t && excess[v] == zero {
+                    active[0].push(v);
+                }
+
+                excess[v] = excess[v] + c;
+            }
+        }
+
+        loop {
+            let Some(u) = active[highest].pop() else {
+                if highest == 0 {
+                    break;
+                }
+
+                highest -= 1;
+
+                continue;
+            };
+
+            // Discharge `u`.
+            while excess[u] > zero {
+                let Some(&e) = network.out[u].get(current[u]) else {
+                    height[u] = network.out[u]
+                        .iter()
+                        .filter(|&&e| network.capacities[e] > zero)
+                        .map(|&e| height[network.heads[e]] + 1)
+                        .min()
+                        .expect("a vertex with excess has a residual arc");
+
+                    current[u] = 0;
+
+                    continue;
+                };
+
+                let v = network.heads[e];
+                let c = network.capacities[e];
+
+                if c > zero && height[u] == height[v] + 1 {
+                    let delta = c.min(excess[u]);
+
+                    network.push(e, delta);
+                    excess[u] = excess[u] - delta;
+
+                    if v != s && v != t && excess[v] == zero {
+                        active[height[v]].push(v);
+                    }
+
+                    excess[v] = excess[v] + delta;
+                } else {
+                    current[u] += 1;
+                }
+            }
+
+            highest = highest.max(height[u]);
+        }
+
+        network.into_max_flow(s, excess[t])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::*,
+        crate::{
+            BfsDist,
+            proptest_strategy::digraph_weighted,
+            repr::adjacency_list_weighted::fixture::{
+                bang_jensen_94_usize,
+                bang_jensen_96_usize,
+                kattis_bryr_2_usize,
+                kattis_crosscountry_usize,
+                kattis_shortestpath1_usize,
+            },
+        },
+        proptest::prelude::*,
+        std::iter::once,
+    };
+
+    #[test]
+    fn max_flow_antiparallel() {
+        let digraph =
+            digraph_weighted(4, &[(0, 1, 3), (1, 2, 2), (2, 1, 2), (2, 3, 4)]);
+
+        let max_flow = PushRelabel::new(&digraph).max_flow(0, 3);
+
+        assert_eq!(max_flow.value, 2);
+
+        assert!(max_flow.residual.arcs_weighted().eq([
+            (0, 1, &1),
+            (1, 0, &2),
+            (2, 1, &4),
+            (2, 3, &2),
+            (3, 2, &2),
+        ]));
+    }
+
+    #[test]
+    fn max_flow_bang_jensen_94() {
+        let digraph = bang_jensen_94_usize();
+
+        assert_eq!(PushRelabel::new(&digraph).max_flow(0, 5).value, 2);
+    }
+
+    #[test]
+    fn max_flow_bang_jensen_96() {
+        let digraph = bang_jensen_96_usize();
+
+        assert_eq!(PushRelabel::new(&digraph).max_flow(0, 5).value, 2);
+    }
+
+    #[test]
+    fn max_flow_excess_returns_to_source() {
+        let digraph = digraph_weighted(4, &[(0, 1, 5), (1, 2, 5), (1, 3, 1)]);
+        let max_flow = PushRelabel::new(&digraph).max_flow(0, 3);
+
+        assert_eq!(max_flow.value, 1);
+
+        assert!(max_flow.flows.arcs_weighted().eq([
+            (0, 1, &1),
+            (1, 2, &0),
+            (1, 3, &1),
+        ]));
+    }
+
+    #[test]
+    fn max_flow_kattis_bryr_2() {
+        let digraph = kattis_bryr_2_usize();
+
+        assert_eq!(PushRelabel::new(&digraph).max_flow(0, 5).value, 1);
+    }
+
+    #[test]
+    fn max_flow_kattis_crosscountry() {
+        let digraph = kattis_crosscountry_usize();
+
+        assert_eq!(PushRelabel::new(&digraph).max_flow(0, 3).value, 18);
+    }
+
+    #[test]
+    fn max_flow_kattis_shortestpath1() {
+        let digraph = kattis_shortestpath1_usize();
+
+        assert_eq!(PushRelabel::new(&digraph).max_flow(0, 3).value, 0);
+    }
+
+    #[test]
+    #[should_panic(expected = "s = 2 isn't in the digraph")]
+    fn max_flow_s_out_of_bounds() {
+        let digraph = digraph_weighted(2, &[(0, 1, 1)]);
+
+        drop(PushRelabel::new(&digraph).max_flow(2, 1));
+    }
+
+    proptest! {
+        #[test]
+        fn max_flow_valid(
+            order in 2..8_usize,
+            arcs in prop::collection::vec(
+                (0..8_usize, 0..8_usize, 0..10_usize),
+                0..24,
+            ),
+        ) {
+            let digraph = digraph_weighted(order, &arcs);
+            let t = order - 1;
+            let max_flow = PushRelabel::new(&digraph).max_flow(0, t);
+            let mut net = vec![0_isize; order];
+
+            for ((u, v, &f), (_, _, &c)) in
+                max_flow.flows.arcs_weighted().zip(digraph.arcs_weighted())
+            {
+                assert!(f <= c);
+
+                net[u] -= isize::try_from(f).unwrap();
+                net[v] += isize::try_from(f).unwrap();
+            }
+
+            let value = isize::try_from(max_flow.value).unwrap();
+
+            assert_eq!(net[0], -value);
+            assert_eq!(net[t], value);
+            assert!(net[1..t].iter().all(|&n| n == 0));
+
+            assert_eq!(
+                BfsDist::new(&max_flow.residual, once(0)).distances()[t],
+                usize::MAX
+            );
+        }
+    }
+}
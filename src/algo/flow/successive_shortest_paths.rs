@@ -0,0 +1,499 @@
+//! Successive shortest paths.
+//!
+//! The successive shortest paths algorithm finds a minimum-cost maximum flow
+//! from a source to a sink in a digraph whose arc weights are
+//! `(capacity, cost)` pairs.[^1] It augments flow along a cheapest path in
+//! the residual digraph until the sink is unreachable. Vertex potentials keep
+//! the reduced costs non-negative, so each cheapest path is found with
+//! Dijkstra's algorithm.[^2] [`BellmanFordMoore`] finds the initial
+//! potentials when costs are negative and rejects digraphs whose arcs with
+//! positive capacity contain a circuit with negative cost.
+//!
+//! The time complexity is `O(f (v + a) log v)`, where `f` is the number of
+//! augmentations, `v` is the digraph's order, and `a` is the digraph's size.
+//!
+//! # Examples
+//!
+//! ```
+//! use graaf::{
+//!     AddArcWeighted,
+//!     AdjacencyListWeighted,
+//!     ArcsWeighted,
+//!     Empty,
+//!     SuccessiveShortestPaths,
+//! };
+//!
+//! let mut digraph = AdjacencyListWeighted::<(usize, isize)>::empty(4);
+//!
+//! digraph.add_arc_weighted(0, 1, (2, 1));
+//! digraph.add_arc_weighted(0, 2, (2, 4));
+//! digraph.add_arc_weighted(1, 2, (1, 1));
+//! digraph.add_arc_weighted(1, 3, (1, 5));
+//! digraph.add_arc_weighted(2, 3, (3, 1));
+//!
+//! let min_cost_flow = SuccessiveShortestPaths::new(&digraph)
+//!     .min_cost_max_flow(0, 3)
+//!     .unwrap();
+//!
+//! assert_eq!(min_cost_flow.value, 4);
+//! assert_eq!(min_cost_flow.cost, 19);
+//!
+//! assert!(min_cost_flow.flows.arcs_weighted().eq([
+//!     (0, 1, &2),
+//!     (0, 2, &2),
+//!     (1, 2, &1),
+//!     (1, 3, &1),
+//!     (2, 3, &3),
+//! ]));
+//! ```
+//!
+//! [`BellmanFordMoore`]: crate::BellmanFordMoore
+//!
+//! [^1]: R. G. Busacker and P. J. Gowen. 1960. A procedure for determining a
+//!   family of minimum-cost network flow patterns. ORO Technical Paper 15,
+//!   Operations Research Office, Johns Hopkins University.
+//!
+//! [^2]: Jack Edmonds and Richard M. Karp. 1972. Theoretical Improvements in
+//!   Algorithmic Efficiency for Network Flow Problems. J. ACM 19, 2 (April
+//!   1972), 248–264. <https://doi.org/10.1145/321694.321699>
+
+use {
+    super::{
+        MinCostFlow,
+        Network,
+        potentials,
+        total_cost,
+    },
+    crate::{
+        ArcsWeighted,
+        NegativeCircuit,
+        Order,
+    },
+    core::cmp::Reverse,
+    std::collections::BinaryHeap,
+};
+
+/// Successive shortest paths.
+///
+/// # Examples
+///
+/// ```
+/// use graaf::{
+///     AddArcWeighted,
+///     AdjacencyListWeighted,
+///     ArcsWeighted,
+///     Empty,
+///     SuccessiveShortestPaths,
+/// };
+///
+/// let mut digraph = AdjacencyListWeighted::<(usize, isize)>::empty(4);
+///
+/// digraph.add_arc_weighted(0, 1, (2, 1));
+/// digraph.add_arc_weighted(0, 2, (2, 4));
+/// digraph.add_arc_weighted(1, 2, (1, 1));
+/// digraph.add_arc_weighted(1, 3, (1, 5));
+/// digraph.add_arc_weighted(2, 3, (3, 1));
+///
+/// let min_cost_flow = SuccessiveShortestPaths::new(&digraph)
+///     .min_cost_max_flow(0, 3)
+///     .unwrap();
+///
+/// assert_eq!(min_cost_flow.value, 4);
+/// assert_eq!(min_cost_flow.cost, 19);
+///
+/// assert!(min_cost_flow.flows.arcs_weighted().eq([
+///     (0, 1, &2),
+///     (0, 2, &2),
+///     (1, 2, &1),
+///     (1, 3, &1),
+///     (2, 3, &3),
+/// ]));
+/// ```
+#[derive(Clone, Debug)]
+pub struct SuccessiveShortestPaths<'a, D> {
+    digraph: &'a D,
+}
+
+impl<'a, D> SuccessiveShortestPaths<'a, D>
+where
+    D: ArcsWeighted<Weight = (usize, isize)> + Order,
+{
+    /// Initialize the successive shortest paths algorithm.
+    ///
+    /// # Arguments
+    ///
+    /// * `digraph`: The digraph. Each arc weight is a `(capacity, cost)` pair.
+    #[must_use]
+    pub const fn new(digraph: &'a D) -> Self {
+        Self { digraph }
+    }
+
+    /// Find a minimum-cost maximum flow.
+    ///
+    /// # Arguments
+    ///
+    /// * `s`: The source vertex.
+    /// * `t`: The sink vertex.
+    ///
+    /// # Errors
+    ///
+    /// Returns the [`NegativeCircuit`] if the arcs with positive capacity
+    /// contain a circuit with negative cost.
+    ///
+    /// # Panics
+    ///
+    /// * Panics if `s` isn't in the digraph.
+    /// * Panics if `t` isn't in the digraph.
+    /// * Panics if `s` equals `t`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use graaf::{
+    ///     AddArcWeighted,
+    ///     AdjacencyListWeighted,
+    ///     Empty,
+    ///     SuccessiveShortestPaths,
+    /// };
+    ///
+    /// let mut digraph = AdjacencyListWeighted::<(usize, isize)>::empty(4);
+    ///
+    /// digraph.add_arc_weighted(0, 1, (1, 2));
+    /// digraph.add_arc_weighted(0, 2, (1, -1));
+    /// digraph.add_arc_weighted(1, 3, (1, 1));
+    /// digraph.add_arc_weighted(2, 3, (1, 3));
+    ///
+    /// let min_cost_flow = SuccessiveShortestPaths::new(&digraph)
+    ///     .min_cost_max_flow(0, 3)
+    ///     .unwrap();
+    ///
+    /// assert_eq!(min_cost_flow.value, 2);
+    /// assert_eq!(min_cost_flow.cost, 5);
+    ///
+    /// digraph.add_arc_weighted(3, 1, (1, -2));
+    ///
+    /// let circuit = SuccessiveShortestPaths::new(&digraph)
+    ///     .min_cost_max_flow(0, 3)
+    ///     .unwrap_err();
+    ///
+    /// assert!(circuit.circuit.eq(&[1, 3]));
+    /// assert_eq!(circuit.weight, -1);
+    /// ```
+    pub fn min_cost_max_flow(
+        &self,
+        s: usize,
+        t: usize,
+    ) -> Result<MinCostFlow, NegativeCircuit> {
+        let order = self.digraph.order();
+
+        let mut network = Network::from_arcs(
+            order,
+            s,
+            t,
+            self.digraph
+                .arcs_weighted()
+                .map(|(u, v, &(c, _))| (u, v, c)),
+        );
+
+        let mut costs = Vec::with_capacity(network.heads.len());
+
+        for (u, v, &(_, w)) in self.digraph.arcs_weighted() {
+            if u != v {
+                costs.push(w);
+                costs.push(-w);
+            }
+        }
+
+        let mut potentials = potentials(self.digraph)?;
+
+        let value =
+            augment(&mut network, &costs, &mut potentials, s, t, usize::MAX);
+
+        let flows = network.into_max_flow(s, value).flows;
+        let cost = total_cost(self.digraph, &flows);
+
+        Ok(MinCostFlow { value, cost, flows })
+    }
+}
+
+/// Augment flow along cheapest residual paths until `limit` units flow from
+/// `s` to `t` or `t` is unreachable, and return the flow value.
+pub(crate) fn augment(
+    network: &mut Network<usize>,
+    costs: &[isize],
+    potentials: &mut [isize],
+    s: usize,
+    t: usize,
+    limit: usize,
+) -> usize {
+    let mut value = 0;
+
+    while value < limit {
+        let Some(pred) = cheapest_path(network, costs, potentials, s, t)
+        else {
+            break;
+        };
+
+        let mut delta = limit - value;
+        let mut v = t;
+
+        while v != s {
+            delta = delta.min(network.capacities[pred[v]]);
+            v = network.heads[pred[v] ^ 1];
+        }
+
+        let mut v = t;
+
+        while v != s {
+            network.push(pred[v], delta);
+            v = network.heads[pred[v] ^ 1];
+        }
+
+        value += delta;
+    }
+
+    value
+}
+
+/// Find a cheapest residual path from `s` to `t` with Dijkstra's algorithm on
+/// the reduced costs and update the potentials.
+fn cheapest_path(
+    network: &Network<usize>,
+    costs: &[isize],
+    potentials: &mut [isize],
+    s: usize,
+    t: usize,
+) -> Option<Vec<usize>> {
+    let order = network.out.len();
+    let mut dist = vec![isize::MAX; order];
+    let mut pred = vec![usize::MAX; order];
+    let mut heap = BinaryHeap::from([(Reverse(0), s)]);
+
+    dist[s] = 0;
+
+    while let Some((Reverse(distance), u)) = heap.pop() {
+        if distance > dist[u] {
+            continue;
+        }
+
+        for &e in &network.out[u] {
+            if network.capacities[e] == 0 {
+                continue;
+            }
+
+            let v = network.heads[e];
+            let distance = distance + costs[e] + potentials[u] - potentials[v];
+
+            if distance < dist[v] {
+                dist[v] = distance;
+                pred[v] = e;
+                heap.push((Reverse(distance), v));
+            }
+        }
+    }
+
+    if dist[t] == isize::MAX {
+        return None;
+    }
+
+    for (p, d) in potentials.iter_mut().zip(dist) {
+        if d != isize::MAX {
+            *p += d;
+        }
+    }
+
+    Some(pred)
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::*,
+        crate::{
+            AddArcWeighted,
+            AdjacencyListWeighted,
+            Dinic,
+            Empty,
+            NetworkSimplex,
+            proptest_strategy::digraph_weighted,
+        },
+        proptest::prelude::*,
+    };
+
+    fn capacities(
+        digraph: &AdjacencyListWeighted<(usize, isize)>,
+    ) -> AdjacencyListWeighted<usize> {
+        let mut capacities = AdjacencyListWeighted::empty(digraph.order());
+
+        for (u, v, &(c, _)) in digraph.arcs_weighted() {
+            capacities.add_arc_weighted(u, v, c);
+        }
+
+        capacities
+    }
+
+    #[test]
+    fn min_cost_max_flow_cheaper_longer_path() {
+        let digraph = digraph_weighted(
+            4,
+            &[
+                (0, 1, (1, 1)),
+                (0, 3, (1, 10)),
+                (1, 2, (1, 1)),
+                (2, 3, (1, 1)),
+            ],
+        );
+
+        let min_cost_flow = SuccessiveShortestPaths::new(&digraph)
+            .min_cost_max_flow(0, 3)
+            .unwrap();
+
+        assert_eq!(min_cost_flow.value, 2);
+        assert_eq!(min_cost_flow.cost, 13);
+    }
+
+    #[test]
+    fn min_cost_max_flow_negative_circuit_unreachable() {
+        let digraph = digraph_weighted(
+            4,
+            &[(0, 1, (1, 1)), (2, 3, (1, -2)), (3, 2, (1, 1))],
+        );
+
+        assert_eq!(
+            SuccessiveShortestPaths::new(&digraph).min_cost_max_flow(0, 1),
+            Err(NegativeCircuit {
+                circuit: vec![2, 3],
+                weight: -1,
+            })
+        );
+    }
+
+    #[test]
+    fn min_cost_max_flow_negative_cost() {
+        let digraph = digraph_weighted(
+            4,
+            &[
+                (0, 1, (2, -3)),
+                (0, 2, (1, 0)),
+                (1, 3, (1, 0)),
+                (2, 3, (2, 0)),
+            ],
+        );
+
+        let min_cost_flow = SuccessiveShortestPaths::new(&digraph)
+            .min_cost_max_flow(0, 3)
+            .unwrap();
+
+        assert_eq!(min_cost_flow.value, 2);
+        assert_eq!(min_cost_flow.cost, -3);
+    }
+
+    #[test]
+    fn min_cost_max_flow_reroute() {
+        let digraph = digraph_weighted(
+            4,
+            &[
+                (0, 1, (1, 1)),
+                (0, 2, (1, 3)),
+                (1, 2, (1, 1)),
+                (1, 3, (1, 3)),
+                (2, 3, (1, 1)),
+            ],
+        );
+
+        let min_cost_flow = SuccessiveShortestPaths::new(&digraph)
+            .min_cost_max_flow(0, 3)
+            .unwrap();
+
+        assert_eq!(min_cost_flow.value, 2);
+        assert_eq!(min_cost_flow.cost, 8);
+
+        assert!(min_cost_flow.flows.arcs_weighted().eq([
+            (0, 1, &1),
+            (0, 2, &1),
+            (1, 2, &0),
+            (1, 3, &1),
+            (2, 3, &1),
+        ]));
+    }
+
+    #[test]
+    fn min_cost_max_flow_unreachable() {
+        let digraph = digraph_weighted(3, &[(0, 1, (4, 1)), (2, 1, (4, 1))]);
+
+        let min_cost_flow = SuccessiveShortestPaths::new(&digraph)
+            .min_cost_max_flow(0, 2)
+            .unwrap();
+
+        assert_eq!(min_cost_flow.value, 0);
+        assert_eq!(min_cost_flow.cost, 0);
+    }
+
+    #[test]
+    fn min_cost_max_flow_zero_capacity() {
+        let digraph = digraph_weighted(
+            3,
+            &[(0, 1, (0, -5)), (1, 0, (1, 1)), (0, 2, (1, 1))],
+        );
+
+        let min_cost_flow = SuccessiveShortestPaths::new(&digraph)
+            .min_cost_max_flow(0, 2)
+            .unwrap();
+
+        assert_eq!(min_cost_flow.value, 1);
+        assert_eq!(min_cost_flow.cost, 1);
+    }
+
+    #[test]
+    #[should_panic(expected = "s and t are the same vertex")]
+    fn min_cost_max_flow_s_eq_t() {
+        let digraph = digraph_weighted(2, &[(0, 1, (1, 1))]);
+
+        drop(SuccessiveShortestPaths::new(&digraph).min_cost_max_flow(0, 0));
+    }
+
+    proptest! {
+        #[test]
+        fn min_cost_max_flow_eq_network_simplex(
+            order in 2..7_usize,
+            arcs in prop::collection::vec(
+                (0..7_usize, 0..7_usize, (0..5_usize, -5..10_isize)),
+                0..20,
+            ),
+        ) {
+            let digraph = digraph_weighted(order, &arcs);
+            let t = order - 1;
+
+            let ssp = SuccessiveShortestPaths::new(&digraph)
+                .min_cost_max_flow(0, t)
+                .map(|min_cost_flow| (min_cost_flow.value, min_cost_flow.cost));
+
+            let simplex = NetworkSimplex::new(&digraph)
+                .min_cost_max_flow(0, t)
+                .map(|min_cost_flow| (min_cost_flow.value, min_cost_flow.cost));
+
+            assert_eq!(ssp, simplex);
+        }
+
+        #[test]
+        fn min_cost_max_flow_value_eq_dinic(
+            order in 2..8_usize,
+            arcs in prop::collection::vec(
+                (0..8_usize, 0..8_usize, (0..10_usize, 0..10_isize)),
+                0..24,
+            ),
+        ) {
+            let digraph = digraph_weighted(order, &arcs);
+            let t = order - 1;
+
+            let min_cost_flow = SuccessiveShortestPaths::new(&digraph)
+                .min_cost_max_flow(0, t)
+                .unwrap();
+
+            assert_eq!(
+                min_cost_flow.value,
+                Dinic::new(&capacities(&digraph)).max_flow(0, t).value
+            );
+        }
+    }
+}
@@ -47,7 +47,7 @@
 //! digraph.add_arc_weighted(6, 2, 5);
 //!
 //! let mut floyd_warshall = FloydWarshall::new(&digraph);
-//! let dist = floyd_warshall.distances();
+//! let dist = floyd_warshall.distances().unwrap();
 //!
 //! assert!(dist[0..7].eq(&[0, 5, 3, 2, 3, 3, 4]));
 //! assert!(dist[7..14].eq(&[3, 0, 5, 1, 2, 2, 3]));
@@ -61,11 +61,19 @@
 //! [^1]: Robert W. Floyd. 1962. Algorithm 97: Shortest path. Commun.
 //!   ACM 5, 6 (June 1962), 345. <https://doi.org/10.1145/367766.368168>
 
-use crate::{
-    ArcsWeighted,
-    DistanceMatrix,
-    Order,
-    Vertices,
+use {
+    crate::{
+        ArcsWeighted,
+        DistanceMatrix,
+        NegativeCircuit,
+        Order,
+        PathMatrix,
+        Vertices,
+    },
+    std::iter::{
+        once,
+        successors,
+    },
 };
 
 /// The Floyd-Warshall algorithm.
@@ -115,7 +123,7 @@ use crate::{
 /// digraph.add_arc_weighted(6, 2, 5);
 ///
 /// let mut floyd_warshall = FloydWarshall::new(&digraph);
-/// let dist = floyd_warshall.distances();
+/// let dist = floyd_warshall.distances().unwrap();
 ///
 /// assert!(dist[0..7].eq(&[0, 5, 3, 2, 3, 3, 4]));
 /// assert!(dist[7..14].eq(&[3, 0, 5, 1, 2, 2, 3]));
@@ -132,6 +140,7 @@ use crate::{
 pub struct FloydWarshall<'a, D> {
     digraph: &'a D,
     dist: DistanceMatrix<isize>,
+    next: PathMatrix,
 }
 
 impl<'a, D> FloydWarshall<'a, D> {
@@ -145,15 +154,162 @@ impl<'a, D> FloydWarshall<'a, D> {
     where
         D: Order,
     {
+        let order = digraph.order();
+
         Self {
             digraph,
-            dist: DistanceMatrix::<isize>::new(digraph.order(), isize::MAX),
+            dist: DistanceMatrix::<isize>::new(order, isize::MAX),
+            next: PathMatrix::new(order),
+        }
+    }
+
+    /// Relax the vertex pairs through each intermediate vertex until the
+    /// distances converge or a diagonal entry turns negative.
+    fn search(&mut self) -> Result<(), NegativeCircuit>
+    where
+        D: ArcsWeighted<Weight = isize> + Order + Vertices,
+    {
+        let order = self.digraph.order();
+
+        self.dist.dist.fill(isize::MAX);
+        self.next.next.fill(None);
+
+        let dist_ptr = self.dist.dist.as_mut_ptr();
+        let next_ptr = self.next.next.as_mut_ptr();
+
+        for (u, v, &w) in self.digraph.arcs_weighted() {
+            unsafe {
+                *dist_ptr.add(u * order + v) = w;
+                *next_ptr.add(u * order + v) = Some(v);
+            }
+        }
+
+        for i in 0..order {
+            unsafe {
+                *dist_ptr.add(i * order + i) = 0;
+                *next_ptr.add(i * order + i) = Some(i);
+            }
+        }
+
+        for i in self.digraph.vertices() {
+            for j in self.digraph.vertices() {
+                let a = unsafe { *dist_ptr.add(j * order + i) };
+
+                if a == isize::MAX {
+                    continue;
+                }
+
+                let next_j_i = unsafe { *next_ptr.add(j * order + i) };
+
+                for k in self.digraph.vertices() {
+                    let b = unsafe { *dist_ptr.add(i * order + k) };
+
+                    if b == isize::MAX {
+                        continue;
+                    }
+
+                    let s = a + b;
+
+                    if s < unsafe { *dist_ptr.add(j * order + k) } {
+                        // A negative diagonal entry closes a negative
+                        // circuit through `j` and `i`.
+                        if j == k {
+                            return Err(self.circuit(j, i));
+                        }
+
+                        unsafe {
+                            *dist_ptr.add(j * order + k) = s;
+                            *next_ptr.add(j * order + k) = next_j_i;
+                        }
+                    }
+                }
+            }
         }
+
+        Ok(())
+    }
+
+    /// Find a negative circuit in the closed walk from `j` to `i` and back.
+    ///
+    /// The walk follows `next` from `j` to `i` and from `i` back to `j`.
+    /// It is split into circuits as it is traversed, so the remaining simple
+    /// path closes the last circuit and the search always yields one.
+    fn circuit(&self, j: usize, i: usize) -> NegativeCircuit
+    where
+        D: ArcsWeighted<Weight = isize> + Order,
+    {
+        let order = self.digraph.order();
+        let mut weights = vec![isize::MAX; order * order];
+
+        for (u, v, &w) in self.digraph.arcs_weighted() {
+            let x = &mut weights[u * order + v];
+
+            *x = (*x).min(w);
+        }
+
+        let leg = |u: usize, t: usize| {
+            successors(self.next[(u, t)], move |&x| self.next[(x, t)])
+                .take_while(move |&x| x != t)
+                .take(order)
+        };
+
+        let mut stack = vec![j];
+        let mut prefix = vec![0];
+        let mut circuits = Vec::new();
+        let mut u = j;
+        let mut weight = 0_isize;
+
+        for v in leg(j, i).chain(once(i)).chain(leg(i, j)) {
+            weight = weight.saturating_add(weights[u * order + v]);
+
+            if let Some(p) = stack.iter().position(|&x| x == v) {
+                circuits.push(NegativeCircuit {
+                    circuit: stack.split_off(p),
+                    weight: weight - prefix[p],
+                });
+
+                weight = prefix[p];
+                prefix.truncate(p);
+            }
+
+            stack.push(v);
+            prefix.push(weight);
+            u = v;
+        }
+
+        let last = NegativeCircuit {
+            circuit: stack,
+            weight: weight.saturating_add(weights[u * order + j]),
+        };
+
+        // The walk's weight is negative, so at least one circuit is
+        // negative.
+        let NegativeCircuit {
+            mut circuit,
+            weight,
+        } = circuits
+            .into_iter()
+            .fold(last, |a, b| if b.weight < a.weight { b } else { a });
+
+        if let Some(p) = (0..circuit.len()).min_by_key(|&p| circuit[p]) {
+            circuit.rotate_left(p);
+        }
+
+        NegativeCircuit { circuit, weight }
     }
 
     /// Find the shortest paths between all vertex pairs in an arc-weighted
     /// digraph.
     ///
+    /// # Returns
+    ///
+    /// The [`DistanceMatrix`] of the shortest distances.
+    ///
+    /// # Errors
+    ///
+    /// Returns the [`NegativeCircuit`] if the digraph contains a negative
+    /// circuit.
+    ///
     /// # Examples
     ///
     /// ## A digraph
@@ -196,7 +352,7 @@ impl<'a, D> FloydWarshall<'a, D> {
     /// digraph.add_arc_weighted(6, 2, 5);
     ///
     /// let mut floyd_warshall = FloydWarshall::new(&digraph);
-    /// let dist = floyd_warshall.distances();
+    /// let dist = floyd_warshall.distances().unwrap();
     ///
     /// assert!(dist[0..7].eq(&[0, 5, 3, 2, 3, 3, 4]));
     /// assert!(dist[7..14].eq(&[3, 0, 5, 1, 2, 2, 3]));
@@ -206,54 +362,93 @@ impl<'a, D> FloydWarshall<'a, D> {
     /// assert!(dist[35..42].eq(&[10, 9, 6, 10, 11, 0, 1]));
     /// assert!(dist[42..49].eq(&[9, 8, 5, 9, 10, 10, 0]));
     /// ```
+    ///
+    /// ## Negative cycle
+    ///
+    /// ```
+    /// use graaf::{
+    ///     AddArcWeighted,
+    ///     AdjacencyListWeighted,
+    ///     Empty,
+    ///     FloydWarshall,
+    /// };
+    ///
+    /// let mut digraph = AdjacencyListWeighted::<isize>::empty(3);
+    ///
+    /// digraph.add_arc_weighted(0, 1, -2);
+    /// digraph.add_arc_weighted(1, 2, -1);
+    /// digraph.add_arc_weighted(2, 0, -1);
+    ///
+    /// let circuit = FloydWarshall::new(&digraph).distances().unwrap_err();
+    ///
+    /// assert!(circuit.circuit.eq(&[0, 1, 2]));
+    /// assert_eq!(circuit.weight, -4);
+    /// ```
     #[doc(alias = "apsp")]
-    #[must_use]
-    pub fn distances(&mut self) -> &DistanceMatrix<isize>
+    pub fn distances(
+        &mut self,
+    ) -> Result<&DistanceMatrix<isize>, NegativeCircuit>
     where
         D: ArcsWeighted<Weight = isize> + Order + Vertices,
     {
-        let dist_ptr = self.dist.dist.as_mut_ptr();
-        let order = self.digraph.order();
-
-        for (u, v, &w) in self.digraph.arcs_weighted() {
-            unsafe {
-                *dist_ptr.add(u * order + v) = w;
-            }
-        }
-
-        for i in 0..self.digraph.order() {
-            unsafe {
-                *dist_ptr.add(i * order + i) = 0;
-            }
-        }
+        self.search()?;
 
-        for i in self.digraph.vertices() {
-            for j in self.digraph.vertices() {
-                let a = unsafe { *dist_ptr.add(j * order + i) };
-
-                if a == isize::MAX {
-                    continue;
-                }
-
-                for k in self.digraph.vertices() {
-                    let b = unsafe { *dist_ptr.add(i * order + k) };
-
-                    if b == isize::MAX {
-                        continue;
-                    }
-
-                    let s = a + b;
+        Ok(&self.dist)
+    }
 
-                    if s < unsafe { *dist_ptr.add(j * order + k) } {
-                        unsafe {
-                            *dist_ptr.add(j * order + k) = s;
-                        }
-                    }
-                }
-            }
-        }
+    /// Find the next vertex on a shortest path between all vertex pairs in an
+    /// arc-weighted digraph.
+    ///
+    /// # Returns
+    ///
+    /// The [`PathMatrix`] of the shortest paths.
+    ///
+    /// # Errors
+    ///
+    /// Returns the [`NegativeCircuit`] if the digraph contains a negative
+    /// circuit.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use graaf::{
+    ///     AddArcWeighted,
+    ///     AdjacencyListWeighted,
+    ///     Empty,
+    ///     FloydWarshall,
+    /// };
+    ///
+    /// let mut digraph = AdjacencyListWeighted::<isize>::empty(7);
+    ///
+    /// digraph.add_arc_weighted(0, 1, 5);
+    /// digraph.add_arc_weighted(0, 2, 3);
+    /// digraph.add_arc_weighted(0, 3, 2);
+    /// digraph.add_arc_weighted(0, 4, 4);
+    /// digraph.add_arc_weighted(1, 0, 3);
+    /// digraph.add_arc_weighted(1, 3, 1);
+    /// digraph.add_arc_weighted(1, 4, 2);
+    /// digraph.add_arc_weighted(2, 6, 4);
+    /// digraph.add_arc_weighted(3, 4, 1);
+    /// digraph.add_arc_weighted(3, 5, 1);
+    /// digraph.add_arc_weighted(4, 2, 3);
+    /// digraph.add_arc_weighted(5, 6, 1);
+    /// digraph.add_arc_weighted(6, 0, 9);
+    /// digraph.add_arc_weighted(6, 1, 8);
+    /// digraph.add_arc_weighted(6, 2, 5);
+    ///
+    /// let mut floyd_warshall = FloydWarshall::new(&digraph);
+    /// let paths = floyd_warshall.paths().unwrap();
+    ///
+    /// assert_eq!(paths.path(0, 6), Some(vec![0, 3, 5, 6]));
+    /// assert_eq!(paths.path(4, 0), Some(vec![4, 2, 6, 0]));
+    /// ```
+    pub fn paths(&mut self) -> Result<&PathMatrix, NegativeCircuit>
+    where
+        D: ArcsWeighted<Weight = isize> + Order + Vertices,
+    {
+        self.search()?;
 
-        &self.dist
+        Ok(&self.next)
     }
 }
 
@@ -265,6 +460,8 @@ mod tests {
             AddArcWeighted,
             AdjacencyListWeighted,
             Empty,
+            OutNeighborsWeighted,
+            proptest_strategy::digraph_weighted,
             repr::adjacency_list_weighted::fixture::{
                 bang_jensen_94_isize,
                 bang_jensen_96_isize,
@@ -274,10 +471,73 @@ mod tests {
                 kattis_bryr_3_isize,
                 kattis_crosscountry_isize,
                 kattis_shortestpath1_isize,
+                kattis_shortestpath3,
             },
         },
+        proptest::prelude::*,
     };
 
+    proptest! {
+        #[test]
+        fn paths_follow_arcs(
+            order in 1..8_usize,
+            arcs in prop::collection::vec(
+                (0..8_usize, 0..8_usize, -3..10_isize),
+                0..24,
+            ),
+        ) {
+            let digraph = digraph_weighted(order, &arcs);
+
+            let weight = |u: usize, v: usize| {
+                digraph
+                    .out_neighbors_weighted(u)
+                    .find_map(|(x, &w)| (x == v).then_some(w))
+                    .unwrap()
+            };
+
+            let mut floyd_warshall = FloydWarshall::new(&digraph);
+
+            match floyd_warshall.distances() {
+                Ok(dist) => {
+                    let dist = dist.clone();
+                    let paths = floyd_warshall.paths().unwrap();
+
+                    for u in 0..order {
+                        for v in 0..order {
+                            let path = paths.path(u, v);
+
+                            if dist[(u, v)] == isize::MAX {
+                                assert_eq!(path, None);
+                            } else {
+                                let path = path.unwrap();
+
+                                assert_eq!(
+                                    path.windows(2)
+                                        .map(|a| weight(a[0], a[1]))
+                                        .sum::<isize>(),
+                                    dist[(u, v)]
+                                );
+                            }
+                        }
+                    }
+                }
+                Err(NegativeCircuit { circuit, weight: total }) => {
+                    let len = circuit.len();
+
+                    assert_eq!(
+                        (0..len)
+                            .map(|i| weight(circuit[i], circuit[(i + 1) % len]))
+                            .sum::<isize>(),
+                        total
+                    );
+
+                    assert!(total < 0);
+                    assert_eq!(circuit.iter().min(), circuit.first());
+                }
+            }
+        }
+    }
+
     #[test]
     fn distances_doctest() {
         let mut digraph = AdjacencyListWeighted::empty(4);
@@ -289,7 +549,7 @@ mod tests {
         digraph.add_arc_weighted(3, 1, -1);
 
         let mut floyd_warshall = FloydWarshall::new(&digraph);
-        let dist = floyd_warshall.distances();
+        let dist = floyd_warshall.distances().unwrap();
 
         assert_eq!(dist[(0, 0)], 0);
         assert_eq!(dist[(0, 1)], -1);
@@ -313,7 +573,7 @@ mod tests {
     fn distances_trivial() {
         let digraph = AdjacencyListWeighted::<isize>::trivial();
         let mut floyd_warshall = FloydWarshall::new(&digraph);
-        let dist = floyd_warshall.distances();
+        let dist = floyd_warshall.distances().unwrap();
 
         assert_eq!(dist[(0, 0)], 0);
     }
@@ -322,7 +582,7 @@ mod tests {
     fn distances_bang_jensen_94_weighted() {
         let digraph = bang_jensen_94_isize();
         let mut floyd_warshall = FloydWarshall::new(&digraph);
-        let dist = floyd_warshall.distances();
+        let dist = floyd_warshall.distances().unwrap();
 
         assert_eq!(dist[(0, 0)], 0);
         assert_eq!(dist[(0, 1)], 1);
@@ -337,7 +597,7 @@ mod tests {
     fn distances_bang_jensen_96() {
         let digraph = bang_jensen_96_isize();
         let mut floyd_warshall = FloydWarshall::new(&digraph);
-        let dist = floyd_warshall.distances();
+        let dist = floyd_warshall.distances().unwrap();
 
         assert_eq!(dist[(0, 0)], 0);
         assert_eq!(dist[(0, 1)], 5);
@@ -351,7 +611,7 @@ mod tests {
     fn distances_bang_jensen_99() {
         let digraph = bang_jensen_99();
         let mut floyd_warshall = FloydWarshall::new(&digraph);
-        let dist = floyd_warshall.distances();
+        let dist = floyd_warshall.distances().unwrap();
 
         assert_eq!(dist[(0, 0)], 0);
         assert_eq!(dist[(0, 1)], 8);
@@ -365,7 +625,7 @@ mod tests {
     fn distances_kattis_bryr_1() {
         let digraph = kattis_bryr_1_isize();
         let mut floyd_warshall = FloydWarshall::new(&digraph);
-        let dist = floyd_warshall.distances();
+        let dist = floyd_warshall.distances().unwrap();
 
         assert_eq!(dist[(0, 0)], 0);
         assert_eq!(dist[(0, 1)], 1);
@@ -376,7 +636,7 @@ mod tests {
     fn distances_kattis_bryr_2() {
         let digraph = kattis_bryr_2_isize();
         let mut floyd_warshall = FloydWarshall::new(&digraph);
-        let dist = floyd_warshall.distances();
+        let dist = floyd_warshall.distances().unwrap();
 
         assert_eq!(dist[(0, 0)], 0);
         assert_eq!(dist[(0, 1)], 1);
@@ -390,7 +650,7 @@ mod tests {
     fn distances_kattis_bryr_3() {
         let digraph = kattis_bryr_3_isize();
         let mut floyd_warshall = FloydWarshall::new(&digraph);
-        let dist = floyd_warshall.distances();
+        let dist = floyd_warshall.distances().unwrap();
 
         assert_eq!(dist[(0, 0)], 0);
         assert_eq!(dist[(0, 1)], 0);
@@ -408,7 +668,7 @@ mod tests {
     fn distances_kattis_crosscountry() {
         let digraph = kattis_crosscountry_isize();
         let mut floyd_warshall = FloydWarshall::new(&digraph);
-        let dist = floyd_warshall.distances();
+        let dist = floyd_warshall.distances().unwrap();
 
         assert_eq!(dist[(0, 0)], 0);
         assert_eq!(dist[(0, 1)], 1);
@@ -420,11 +680,86 @@ mod tests {
     fn distances_kattis_shortestpath1() {
         let digraph = kattis_shortestpath1_isize();
         let mut floyd_warshall = FloydWarshall::new(&digraph);
-        let dist = floyd_warshall.distances();
+        let dist = floyd_warshall.distances().unwrap();
 
         assert_eq!(dist[(0, 0)], 0);
         assert_eq!(dist[(0, 1)], 2);
         assert_eq!(dist[(0, 2)], 4);
         assert_eq!(dist[(0, 3)], dist.infinity);
     }
+
+    #[test]
+    fn distances_kattis_shortestpath3() {
+        assert_eq!(
+            FloydWarshall::new(&kattis_shortestpath3()).distances(),
+            Err(NegativeCircuit {
+                circuit: vec![1, 2],
+                weight: -1
+            })
+        );
+    }
+
+    #[test]
+    fn distances_negative_circuit() {
+        let mut digraph = AdjacencyListWeighted::<isize>::empty(3);
+
+        digraph.add_arc_weighted(0, 1, -2);
+        digraph.add_arc_weighted(1, 2, -1);
+        digraph.add_arc_weighted(2, 0, -1);
+
+        assert_eq!(
+            FloydWarshall::new(&digraph).distances(),
+            Err(NegativeCircuit {
+                circuit: vec![0, 1, 2],
+                weight: -4
+            })
+        );
+    }
+
+    #[test]
+    fn distances_unreachable_negative_circuit() {
+        let mut digraph = AdjacencyListWeighted::<isize>::empty(4);
+
+        digraph.add_arc_weighted(0, 1, 2);
+        digraph.add_arc_weighted(2, 3, -2);
+        digraph.add_arc_weighted(3, 2, -1);
+
+        assert_eq!(
+            FloydWarshall::new(&digraph).distances(),
+            Err(NegativeCircuit {
+                circuit: vec![2, 3],
+                weight: -3
+            })
+        );
+    }
+
+    #[test]
+    fn paths_bang_jensen_99() {
+        let digraph = bang_jensen_99();
+        let mut floyd_warshall = FloydWarshall::new(&digraph);
+        let paths = floyd_warshall.paths().unwrap();
+
+        assert_eq!(paths.path(0, 4), Some(vec![0, 2, 3, 5, 4]));
+        assert_eq!(paths.path(1, 5), Some(vec![1, 2, 3, 5]));
+        assert_eq!(paths.path(4, 3), Some(vec![4, 3]));
+        assert_eq!(paths.path(3, 0), None);
+    }
+
+    #[test]
+    fn paths_kattis_shortestpath1() {
+        let digraph = kattis_shortestpath1_isize();
+        let mut floyd_warshall = FloydWarshall::new(&digraph);
+        let paths = floyd_warshall.paths().unwrap();
+
+        assert_eq!(paths.path(0, 2), Some(vec![0, 1, 2]));
+        assert_eq!(paths.path(0, 3), None);
+    }
+
+    #[test]
+    fn paths_trivial() {
+        let digraph = AdjacencyListWeighted::<isize>::trivial();
+        let mut floyd_warshall = FloydWarshall::new(&digraph);
+
+        assert_eq!(floyd_warshall.paths().unwrap().path(0, 0), Some(vec![0]));
+    }
 }
@@ -0,0 +1,429 @@
+//! Hierholzer's algorithm.
+//!
+//! Hierholzer's algorithm finds an Eulerian circuit or trail, a closed or open
+//! trail that traverses each arc of a digraph exactly once.[^1] It follows
+//! unused arcs until it gets stuck and splices the closed trails it finds
+//! along the way into the result.
+//!
+//! An Eulerian trail through a de Bruijn-style digraph, whose vertices are the
+//! `(k - 1)`-mers and whose arcs are the `k`-mers of a sequence, spells out a
+//! sequence that contains each `k`-mer exactly once.
+//!
+//! The time complexity is `O(v + a)`, where `v` is the digraph's order and `a`
+//! is the digraph's size.
+//!
+//! # Examples
+//!
+//! ## Circuit
+//!
+//! ```
+//! use graaf::{
+//!     AddArc,
+//!     AdjacencyList,
+//!     Empty,
+//!     Hierholzer,
+//! };
+//!
+//! let mut digraph = AdjacencyList::empty(5);
+//!
+//! digraph.add_arc(0, 1);
+//! digraph.add_arc(1, 2);
+//! digraph.add_arc(2, 0);
+//! digraph.add_arc(2, 3);
+//! digraph.add_arc(3, 4);
+//! digraph.add_arc(4, 2);
+//!
+//! assert_eq!(
+//!     Hierholzer::new(&digraph).circuit(),
+//!     Some(vec![0, 1, 2, 3, 4, 2, 0])
+//! );
+//! ```
+//!
+//! ## Sequence assembly
+//!
+//! ```
+//! use graaf::{
+//!     AddArc,
+//!     AdjacencyList,
+//!     Empty,
+//!     Hierholzer,
+//! };
+//!
+//! let kmers = ["CTA", "TAC", "ACG", "CGT", "GTA", "TAG"];
+//! let mut labels = Vec::new();
+//!
+//! for kmer in kmers {
+//!     for label in [&kmer[..2], &kmer[1..]] {
+//!         if !labels.contains(&label) {
+//!             labels.push(label);
+//!         }
+//!     }
+//! }
+//!
+//! let mut digraph = AdjacencyList::empty(labels.len());
+//!
+//! for kmer in kmers {
+//!     let u = labels.iter().position(|&l| l == &kmer[..2]).unwrap();
+//!     let v = labels.iter().position(|&l| l == &kmer[1..]).unwrap();
+//!
+//!     digraph.add_arc(u, v);
+//! }
+//!
+//! let trail = Hierholzer::new(&digraph).trail().unwrap();
+//! let mut sequence = labels[trail[0]].to_owned();
+//!
+//! for &u in &trail[1..] {
+//!     sequence.push_str(&labels[u][1..]);
+//! }
+//!
+//! assert_eq!(sequence, "CTACGTAG");
+//! ```
+//!
+//! [^1]: Carl Hierholzer and Chr Wiener. 1873. Ueber die Möglichkeit, einen
+//!   Linienzug ohne Wiederholung und ohne Unterbrechung zu umfahren.
+//!   Mathematische Annalen 6, 1 (1873), 30–32.
+//!   <https://doi.org/10.1007/BF01442866>
+
+use crate::{
+    HasEulerianTrail,
+    Indegree,
+    IsEulerian,
+    Order,
+    OutNeighbors,
+    Outdegree,
+    Vertices,
+};
+
+/// Hierholzer's algorithm.
+///
+/// # Examples
+///
+/// ```
+/// use graaf::{
+///     AddArc,
+///     AdjacencyList,
+///     Empty,
+///     Hierholzer,
+/// };
+///
+/// let mut digraph = AdjacencyList::empty(5);
+///
+/// digraph.add_arc(0, 1);
+/// digraph.add_arc(1, 2);
+/// digraph.add_arc(2, 0);
+/// digraph.add_arc(2, 3);
+/// digraph.add_arc(3, 4);
+/// digraph.add_arc(4, 2);
+///
+/// assert_eq!(
+///     Hierholzer::new(&digraph).circuit(),
+///     Some(vec![0, 1, 2, 3, 4, 2, 0])
+/// );
+/// ```
+#[derive(Clone, Debug)]
+pub struct Hierholzer<'a, D> {
+    digraph: &'a D,
+}
+
+impl<'a, D> Hierholzer<'a, D> {
+    /// Initialize Hierholzer's algorithm.
+    ///
+    /// # Arguments
+    ///
+    /// * `digraph`: The digraph.
+    #[must_use]
+    pub const fn new(digraph: &'a D) -> Self {
+        Self { digraph }
+    }
+
+    /// Find an Eulerian circuit.
+    ///
+    /// # Returns
+    ///
+    /// Returns the vertices of an Eulerian circuit that starts and ends at
+    /// the first vertex with an outgoing arc, or `None` if the digraph isn't
+    /// Eulerian. The circuit of a digraph without arcs is `[0]`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use graaf::{
+    ///     AddArc,
+    ///     AdjacencyList,
+    ///     Empty,
+    ///     Hierholzer,
+    /// };
+    ///
+    /// let mut digraph = AdjacencyList::empty(3);
+    ///
+    /// digraph.add_arc(0, 1);
+    /// digraph.add_arc(1, 0);
+    /// digraph.add_arc(1, 2);
+    ///
+    /// assert_eq!(Hierholzer::new(&digraph).circuit(), None);
+    ///
+    /// digraph.add_arc(2, 1);
+    ///
+    /// assert_eq!(
+    ///     Hierholzer::new(&digraph).circuit(),
+    ///     Some(vec![0, 1, 2, 1, 0])
+    /// );
+    /// ```
+    #[must_use]
+    pub fn circuit(&self) -> Option<Vec<usize>>
+    where
+        D: IsEulerian + Order + OutNeighbors,
+    {
+        self.digraph
+            .is_eulerian()
+            .then(|| self.walk(self.first_tail()))
+    }
+
+    /// Find an Eulerian trail.
+    ///
+    /// If the digraph is Eulerian, the trail is the circuit returned by
+    /// [`Hierholzer::circuit`].
+    ///
+    /// # Returns
+    ///
+    /// Returns the vertices of an Eulerian trail, or `None` if the digraph
+    /// doesn't contain an Eulerian trail.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use graaf::{
+    ///     AddArc,
+    ///     AdjacencyList,
+    ///     Empty,
+    ///     Hierholzer,
+    /// };
+    ///
+    /// let mut digraph = AdjacencyList::empty(4);
+    ///
+    /// digraph.add_arc(0, 1);
+    /// digraph.add_arc(1, 2);
+    /// digraph.add_arc(3, 2);
+    ///
+    /// assert_eq!(Hierholzer::new(&digraph).trail(), None);
+    ///
+    /// digraph.add_arc(2, 3);
+    ///
+    /// assert_eq!(Hierholzer::new(&digraph).trail(), Some(vec![0, 1, 2, 3, 2]));
+    /// ```
+    #[must_use]
+    pub fn trail(&self) -> Option<Vec<usize>>
+    where
+        D: HasEulerianTrail
+            + Indegree
+            + Order
+            + OutNeighbors
+            + Outdegree
+            + Vertices,
+    {
+        self.digraph.has_eulerian_trail().then(|| {
+            let s = self
+                .digraph
+                .vertices()
+                .find(|&u| {
+                    self.digraph.outdegree(u) > self.digraph.indegree(u)
+                })
+                .unwrap_or_else(|| self.first_tail());
+
+            self.walk(s)
+        })
+    }
+
+    /// Return the first vertex with an outgoing arc, or `0` if the digraph
+    /// has no arcs.
+    fn first_tail(&self) -> usize
+    where
+        D: Order + OutNeighbors,
+    {
+        (0..self.digraph.order())
+            .find(|&u| self.digraph.out_neighbors(u).next().is_some())
+            .unwrap_or(0)
+    }
+
+    fn walk(&self, s: usize) -> Vec<usize>
+    where
+        D: Order + OutNeighbors,
+    {
+        let mut unused = (0..self.digraph.order())
+            .map(|u| {
+                let mut out_neighbors =
+                    self.digraph.out_neighbors(u).collect::<Vec<_>>();
+
+                out_neighbors.reverse();
+
+                out_neighbors
+            })
+            .collect::<Vec<_>>();
+
+        let mut stack = vec![s];
+        let mut trail = Vec::new();
+
+        while let Some(&u) = stack.last() {
+            if let Some(v) = unused[u].pop() {
+                stack.push(v);
+            } else {
+                trail.push(u);
+                let _ = stack.pop();
+            }
+        }
+
+        trail.reverse();
+
+        trail
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::*,
+        crate::{
+            AddArc,
+            AdjacencyList,
+            Circuit,
+            Complete,
+            Empty,
+            HasArc,
+            Path,
+            Size,
+            proptest_strategy::digraph,
+            repr::adjacency_list::fixture::{
+                bang_jensen_34,
+                kattis_escapewallmaria_3,
+            },
+        },
+        proptest::prelude::*,
+        std::collections::BTreeSet,
+    };
+
+    fn is_eulerian_trail(digraph: &AdjacencyList, trail: &[usize]) -> bool {
+        let arcs = trail
+            .windows(2)
+            .map(|w| (w[0], w[1]))
+            .collect::<BTreeSet<_>>();
+
+        arcs.len() == trail.len() - 1
+            && arcs.len() == digraph.size()
+            && arcs.iter().all(|&(u, v)| digraph.has_arc(u, v))
+    }
+
+    #[test]
+    fn circuit_bang_jensen_34() {
+        assert_eq!(Hierholzer::new(&bang_jensen_34()).circuit(), None);
+    }
+
+    #[test]
+    fn circuit_empty() {
+        let digraph = AdjacencyList::empty(3);
+
+        assert_eq!(Hierholzer::new(&digraph).circuit(), Some(vec![0]));
+    }
+
+    #[test]
+    fn circuit_isolated_vertex() {
+        let mut digraph = AdjacencyList::empty(4);
+
+        digraph.add_arc(1, 2);
+        digraph.add_arc(2, 3);
+        digraph.add_arc(3, 1);
+
+        assert_eq!(
+            Hierholzer::new(&digraph).circuit(),
+            Some(vec![1, 2, 3, 1])
+        );
+    }
+
+    #[test]
+    fn circuit_kattis_escapewallmaria_3() {
+        assert_eq!(
+            Hierholzer::new(&kattis_escapewallmaria_3()).circuit(),
+            Some(vec![1, 2, 1, 5, 6, 2, 6, 5, 9, 13, 12, 13, 9, 5, 1])
+        );
+    }
+
+    #[test]
+    fn circuit_trivial() {
+        let digraph = AdjacencyList::trivial();
+
+        assert_eq!(Hierholzer::new(&digraph).circuit(), Some(vec![0]));
+    }
+
+    #[test]
+    fn trail_bang_jensen_34() {
+        assert_eq!(Hierholzer::new(&bang_jensen_34()).trail(), None);
+    }
+
+    #[test]
+    fn trail_isolated_vertex() {
+        let mut digraph = AdjacencyList::empty(4);
+
+        digraph.add_arc(1, 2);
+        digraph.add_arc(2, 3);
+        digraph.add_arc(3, 1);
+
+        assert_eq!(Hierholzer::new(&digraph).trail(), Some(vec![1, 2, 3, 1]));
+    }
+
+    #[test]
+    fn trail_path() {
+        let digraph = AdjacencyList::path(4);
+
+        assert_eq!(Hierholzer::new(&digraph).trail(), Some(vec![0, 1, 2, 3]));
+    }
+
+    proptest! {
+        #[test]
+        fn circuit_circuit(order in 1..25_usize) {
+            let digraph = AdjacencyList::circuit(order);
+            let circuit = Hierholzer::new(&digraph).circuit().unwrap();
+
+            assert_eq!(circuit.len(), order + usize::from(order > 1));
+            assert!(is_eulerian_trail(&digraph, &circuit));
+        }
+
+        #[test]
+        fn circuit_complete(order in 1..10_usize) {
+            let digraph = AdjacencyList::complete(order);
+            let circuit = Hierholzer::new(&digraph).circuit().unwrap();
+
+            assert_eq!(circuit.first(), circuit.last());
+            assert!(is_eulerian_trail(&digraph, &circuit));
+        }
+
+        #[test]
+        fn circuit_is_closed_trail(
+            order in 1..8_usize,
+            arcs in prop::collection::vec((0..8_usize, 0..8_usize), 0..32),
+        ) {
+            let digraph = digraph(order, &arcs);
+
+            if let Some(circuit) = Hierholzer::new(&digraph).circuit() {
+                assert!(digraph.is_eulerian());
+                assert_eq!(circuit.first(), circuit.last());
+                assert!(is_eulerian_trail(&digraph, &circuit));
+            } else {
+                assert!(!digraph.is_eulerian());
+            }
+        }
+
+        #[test]
+        fn trail_is_trail(
+            order in 1..8_usize,
+            arcs in prop::collection::vec((0..8_usize, 0..8_usize), 0..32),
+        ) {
+            let digraph = digraph(order, &arcs);
+
+            if let Some(trail) = Hierholzer::new(&digraph).trail() {
+                assert!(digraph.has_eulerian_trail());
+                assert!(is_eulerian_trail(&digraph, &trail));
+            } else {
+                assert!(!digraph.has_eulerian_trail());
+            }
+        }
+    }
+}
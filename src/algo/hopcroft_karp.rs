@@ -0,0 +1,450 @@
+//! Hopcroft-Karp maximum bipartite matching.
+//!
+//! The Hopcroft-Karp algorithm finds a maximum-cardinality matching in a
+//! bipartite digraph whose arcs go from a left vertex set to a right vertex
+//! set.[^1] Each phase finds a maximal set of vertex-disjoint shortest
+//! augmenting paths with a breadth-first search followed by depth-first
+//! searches. By König's theorem, the size of a maximum matching equals the
+//! size of a minimum vertex cover, so the algorithm also returns a minimum
+//! vertex cover as a certificate.[^2]
+//!
+//! The time complexity is `O(a √v)`, where `v` is the digraph's order and `a`
+//! is the digraph's size.
+//!
+//! # Examples
+//!
+//! ```
+//! use {
+//!     graaf::{
+//!         AddArc,
+//!         AdjacencyList,
+//!         Empty,
+//!         HopcroftKarp,
+//!     },
+//!     std::collections::BTreeSet,
+//! };
+//!
+//! let mut digraph = AdjacencyList::empty(6);
+//!
+//! digraph.add_arc(0, 3);
+//! digraph.add_arc(0, 4);
+//! digraph.add_arc(1, 3);
+//! digraph.add_arc(2, 3);
+//!
+//! let matching = HopcroftKarp::new(&digraph, 0..3).max_matching();
+//!
+//! assert_eq!(matching.pairs, vec![(0, 4), (1, 3)]);
+//! assert_eq!(matching.vertex_cover, BTreeSet::from([0, 3]));
+//! ```
+//!
+//! [^1]: John E. Hopcroft and Richard M. Karp. 1973. An n^5/2 Algorithm for
+//!   Maximum Matchings in Bipartite Graphs. SIAM J. Comput. 2, 4 (1973),
+//!   225–231. <https://doi.org/10.1137/0202019>
+//!
+//! [^2]: Dénes Kőnig. 1931. Gráfok és mátrixok. Matematikai és Fizikai Lapok
+//!   38 (1931), 116–119.
+
+use {
+    crate::{
+        Order,
+        OutNeighbors,
+    },
+    std::collections::{
+        BTreeSet,
+        VecDeque,
+    },
+};
+
+/// A maximum matching.
+///
+/// # Examples
+///
+/// ```
+/// use {
+///     graaf::{
+///         AdjacencyList,
+///         Biclique,
+///         HopcroftKarp,
+///     },
+///     std::collections::BTreeSet,
+/// };
+///
+/// let digraph = AdjacencyList::biclique(2, 3);
+/// let matching = HopcroftKarp::new(&digraph, 0..2).max_matching();
+///
+/// assert_eq!(matching.pairs, vec![(0, 2), (1, 3)]);
+/// assert_eq!(matching.vertex_cover, BTreeSet::from([0, 1]));
+/// ```
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[non_exhaustive]
+pub struct Matching {
+    /// The matched `(left, right)` pairs, ordered by left vertex.
+    pub pairs: Vec<(usize, usize)>,
+    /// A minimum vertex cover of the arcs from the left to the right set.
+    pub vertex_cover: BTreeSet<usize>,
+}
+
+/// Hopcroft-Karp maximum bipartite matching.
+///
+/// # Examples
+///
+/// ```
+/// use {
+///     graaf::{
+///         AddArc,
+///         AdjacencyList,
+///         Empty,
+///         HopcroftKarp,
+///     },
+///     std::collections::BTreeSet,
+/// };
+///
+/// let mut digraph = AdjacencyList::empty(6);
+///
+/// digraph.add_arc(0, 3);
+/// digraph.add_arc(0, 4);
+/// digraph.add_arc(1, 3);
+/// digraph.add_arc(2, 3);
+///
+/// let matching = HopcroftKarp::new(&digraph, 0..3).max_matching();
+///
+/// assert_eq!(matching.pairs, vec![(0, 4), (1, 3)]);
+/// assert_eq!(matching.vertex_cover, BTreeSet::from([0, 3]));
+/// ```
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct HopcroftKarp<'a, D> {
+    digraph: &'a D,
+    left: Vec<bool>,
+}
+
+impl<'a, D> HopcroftKarp<'a, D>
+where
+    D: Order + OutNeighbors,
+{
+    /// Initialize the Hopcroft-Karp algorithm.
+    ///
+    /// The vertices not in `left` form the right set. Arcs that don't go
+    /// from a left vertex to a right vertex are ignored.
+    ///
+    /// # Arguments
+    ///
+    /// * `digraph`: The digraph.
+    /// * `left`: The left vertex set.
+    ///
+    /// # Panics
+    ///
+    /// Panics if a vertex in `left` isn't in the digraph.
+    #[must_use]
+    pub fn new<T>(digraph: &'a D, left: T) -> Self
+    where
+        T: IntoIterator<Item = usize>,
+    {
+        let order = digraph.order();
+        let mut is_left = vec![false; order];
+
+        for u in left {
+            assert!(u < order, "u = {u} isn't in the digraph");
+
+            is_left[u] = true;
+        }
+
+        Self {
+            digraph,
+            left: is_left,
+        }
+    }
+
+    /// Find a maximum matching and a minimum vertex cover.
+    ///
+    /// # Panics
+    ///
+    /// Panics if an out-neighbor of a left vertex isn't in the digraph.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use {
+    ///     graaf::{
+    ///         AddArc,
+    ///         AdjacencyList,
+    ///         Empty,
+    ///         HopcroftKarp,
+    ///     },
+    ///     std::collections::BTreeSet,
+    /// };
+    ///
+    /// let mut digraph = AdjacencyList::empty(6);
+    ///
+    /// digraph.add_arc(0, 3);
+    /// digraph.add_arc(1, 3);
+    /// digraph.add_arc(1, 4);
+    /// digraph.add_arc(2, 4);
+    /// digraph.add_arc(2, 5);
+    ///
+    /// let matching = HopcroftKarp::new(&digraph, 0..3).max_matching();
+    ///
+    /// assert_eq!(matching.pairs, vec![(0, 3), (1, 4), (2, 5)]);
+    /// assert_eq!(matching.vertex_cover, BTreeSet::from([0, 1, 2]));
+    /// ```
+    #[must_use]
+    pub fn max_matching(&self) -> Matching {
+        let order = self.digraph.order();
+        let mut adjacent = vec![Vec::new(); order];
+
+        for u in (0..order).filter(|&u| self.left[u]) {
+            adjacent[u] = self
+                .digraph
+                .out_neighbors(u)
+                .filter(|&v| !self.left[v])
+                .collect();
+        }
+
+        let mut mate = vec![usize::MAX; order];
+        let mut dist = vec![usize::MAX; order];
+        let mut current = vec![0; order];
+
+        while self.layers(&adjacent, &mate, &mut dist) {
+            current.fill(0);
+
+            for u in 0..order {
+                if self.left[u] && mate[u] == usize::MAX {
+                    let _ = augment(
+                        &adjacent,
+                        &mut mate,
+                        &mut dist,
+                        &mut current,
+                        u,
+                    );
+                }
+            }
+        }
+
+        // By König's theorem, the left vertices unreachable by alternating
+        // paths from a free left vertex and the right vertices reachable by
+        // them form a minimum vertex cover.
+        let mut visited = vec![false; order];
+        let mut queue = VecDeque::new();
+
+        for u in 0..order {
+            if self.left[u] && mate[u] == usize::MAX {
+                visited[u] = true;
+                queue.push_back(u);
+            }
+        }
+
+        while let Some(u) = queue.pop_front() {
+            for &v in &adjacent[u] {
+                if mate[u] != v && !visited[v] {
+                    visited[v] = true;
+
+                    let w = mate[v];
+
+                    if w != usize::MAX && !visited[w] {
+                        visited[w] = true;
+                        queue.push_back(w);
+                    }
+                }
+            }
+        }
+
+        Matching {
+            pairs: (0..order)
+                .filter(|&u| self.left[u] && mate[u] != usize::MAX)
+                .map(|u| (u, mate[u]))
+                .collect(),
+            vertex_cover: (0..order)
+                .filter(|&u| self.left[u] != visited[u])
+                .collect(),
+        }
+    }
+
+    /// Layer the left vertices by their distance from a free left vertex
+    /// along alternating paths. Return whether an augmenting path exists.
+    fn layers(
+        &self,
+        adjacent: &[Vec<usize>],
+        mate: &[usize],
+        dist: &mut [usize],
+    ) -> bool {
+        let mut queue = VecDeque::new();
+        let mut found = false;
+
+        for (u, d) in dist.iter_mut().enumerate() {
+            if self.left[u] && mate[u] == usize::MAX {
+                *d = 0;
+                queue.push_back(u);
+            } else {
+                *d = usize::MAX;
+            }
+        }
+
+        while let Some(u) = queue.pop_front() {
+            for &v in &adjacent[u] {
+                let w = mate[v];
+
+                if w == usize::MAX {
+                    found = true;
+                } else if dist[w] == usize::MAX {
+                    dist[w] = dist[u] + 1;
+                    queue.push_back(w);
+                }
+            }
+        }
+
+        found
+    }
+}
+
+/// Find an augmenting path from the free left vertex `u` along the layers and
+/// flip the matching along it.
+fn augment(
+    adjacent: &[Vec<usize>],
+    mate: &mut [usize],
+    dist: &mut [usize],
+    current: &mut [usize],
+    u: usize,
+) -> bool {
+    let mut stack = vec![u];
+    let mut right = Vec::new();
+
+    while let Some(&x) = stack.last() {
+        let Some(&v) = adjacent[x].get(current[x]) else {
+            // No augmenting path passes through `x` in this phase.
+            dist[x] = usize::MAX;
+            let _ = stack.pop();
+            let _ = right.pop();
+
+            continue;
+        };
+
+        current[x] += 1;
+
+        let w = mate[v];
+
+        if w == usize::MAX {
+            right.push(v);
+
+            for (&x, &v) in stack.iter().zip(&right) {
+                mate[x] = v;
+                mate[v] = x;
+            }
+
+            return true;
+        }
+
+        if dist[w] == dist[x] + 1 {
+            stack.push(w);
+            right.push(v);
+        }
+    }
+
+    false
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::*,
+        crate::{
+            AdjacencyList,
+            Arcs,
+            Biclique,
+            HasArc,
+            proptest_strategy::digraph,
+            repr::adjacency_list::fixture::bang_jensen_94,
+        },
+        proptest::prelude::*,
+        std::iter::{
+            empty,
+            once,
+        },
+    };
+
+    #[test]
+    fn max_matching_augmenting_path() {
+        let digraph = digraph(6, &[(0, 3), (0, 4), (1, 3), (2, 4), (2, 5)]);
+        let matching = HopcroftKarp::new(&digraph, 0..3).max_matching();
+
+        assert_eq!(matching.pairs, vec![(0, 4), (1, 3), (2, 5)]);
+        assert_eq!(matching.vertex_cover.len(), 3);
+    }
+
+    #[test]
+    fn max_matching_bang_jensen_94() {
+        let digraph = bang_jensen_94();
+        let matching = HopcroftKarp::new(&digraph, [0, 3, 4]).max_matching();
+
+        assert_eq!(matching.pairs, vec![(0, 1), (3, 5), (4, 6)]);
+        assert_eq!(matching.vertex_cover, BTreeSet::from([0, 3, 4]));
+    }
+
+    #[test]
+    fn max_matching_biclique() {
+        let digraph = AdjacencyList::biclique(4, 2);
+        let matching = HopcroftKarp::new(&digraph, 0..4).max_matching();
+
+        assert_eq!(matching.pairs, vec![(0, 4), (1, 5)]);
+        assert_eq!(matching.vertex_cover, BTreeSet::from([4, 5]));
+    }
+
+    #[test]
+    fn max_matching_empty_left() {
+        let digraph = AdjacencyList::biclique(2, 2);
+        let matching = HopcroftKarp::new(&digraph, empty()).max_matching();
+
+        assert!(matching.pairs.is_empty());
+        assert!(matching.vertex_cover.is_empty());
+    }
+
+    #[test]
+    fn max_matching_ignores_arcs_within_a_set() {
+        let digraph = digraph(4, &[(0, 1), (2, 3), (3, 0)]);
+        let matching = HopcroftKarp::new(&digraph, 0..2).max_matching();
+
+        assert!(matching.pairs.is_empty());
+        assert!(matching.vertex_cover.is_empty());
+    }
+
+    #[test]
+    #[should_panic(expected = "u = 2 isn't in the digraph")]
+    fn new_left_out_of_bounds() {
+        let digraph = digraph(2, &[(0, 1)]);
+
+        drop(HopcroftKarp::new(&digraph, once(2)));
+    }
+
+    proptest! {
+        #[test]
+        fn max_matching_vertex_cover_certifies(
+            order in 2..10_usize,
+            left in prop::collection::vec(any::<bool>(), 10),
+            arcs in prop::collection::vec((0..10_usize, 0..10_usize), 0..30),
+        ) {
+            let digraph = digraph(order, &arcs);
+            let left = (0..order).filter(|&u| left[u]).collect::<Vec<_>>();
+            let matching =
+                HopcroftKarp::new(&digraph, left.iter().copied()).max_matching();
+
+            let mut matched = BTreeSet::new();
+
+            for &(u, v) in &matching.pairs {
+                assert!(left.contains(&u));
+                assert!(!left.contains(&v));
+                assert!(digraph.has_arc(u, v));
+                assert!(matched.insert(u));
+                assert!(matched.insert(v));
+            }
+
+            assert_eq!(matching.vertex_cover.len(), matching.pairs.len());
+
+            for (u, v) in digraph.arcs() {
+                if left.contains(&u) && !left.contains(&v) {
+                    assert!(
+                        matching.vertex_cover.contains(&u)
+                            || matching.vertex_cover.contains(&v)
+                    );
+                }
+            }
+        }
+    }
+}
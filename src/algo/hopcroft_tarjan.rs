@@ -0,0 +1,954 @@
+//! The Hopcroft-Tarjan algorithm.
+//!
+//! The Hopcroft-Tarjan algorithm[^1] finds the articulation points, bridges,
+//! biconnected components, and 2-edge-connected components of a digraph's
+//! underlying undirected graph. An articulation point is a vertex whose
+//! removal increases the number of connected components. A bridge is an edge
+//! whose removal increases the number of connected components.
+//!
+//! The underlying graph has an edge `{u, v}` if the digraph has the arc
+//! `(u, v)`, the arc `(v, u)`, or both, so symmetric digraphs like those
+//! generated by [`Cycle`](crate::Cycle) and [`Wheel`](crate::Wheel) are
+//! treated as undirected graphs.
+//!
+//! The time complexity is `O((v + a) log v)`, where `v` is the digraph's
+//! order and `a` is the digraph's size. The search is linear; the `log v`
+//! factor comes from collecting the results in ordered sets.
+//!
+//! # Examples
+//!
+//! Vertex `2` joins the triangle `{0, 1, 2}` to the path `2 → 3 → 4`.
+//!
+//! ```
+//! use {
+//!     graaf::{
+//!         AddArc,
+//!         AdjacencyList,
+//!         Empty,
+//!         HopcroftTarjan,
+//!     },
+//!     std::collections::BTreeSet,
+//! };
+//!
+//! let mut digraph = AdjacencyList::empty(5);
+//!
+//! digraph.add_arc(0, 1);
+//! digraph.add_arc(1, 2);
+//! digraph.add_arc(2, 0);
+//! digraph.add_arc(2, 3);
+//! digraph.add_arc(3, 4);
+//!
+//! let hopcroft_tarjan = HopcroftTarjan::new(&digraph);
+//!
+//! assert!(
+//!     hopcroft_tarjan
+//!         .articulation_points()
+//!         .eq(&BTreeSet::from([2, 3]))
+//! );
+//! assert!(
+//!     hopcroft_tarjan
+//!         .bridges()
+//!         .eq(&BTreeSet::from([(2, 3), (3, 4)]))
+//! );
+//! ```
+//!
+//! [^1]: John Hopcroft and Robert Tarjan. 1973. Algorithm 447: efficient
+//!   algorithms for graph manipulation. Commun. ACM 16, 6 (June 1973),
+//!   372–378. <https://doi.org/10.1145/362248.362272>
+
+use {
+    crate::{
+        Arcs,
+        Order,
+    },
+    std::collections::{
+        BTreeSet,
+        VecDeque,
+    },
+};
+
+/// Find the sorted neighbors of each vertex in the digraph's underlying
+/// graph.
+fn neighbors<D>(digraph: &D) -> Vec<Vec<usize>>
+where
+    D: Arcs + Order,
+{
+    let order = digraph.order();
+    let mut edges = vec![Vec::new(); order];
+    let mut neighbors = vec![Vec::<usize>::new(); order];
+
+    for (u, v) in digraph.arcs() {
+        if u != v {
+            edges[u].push(v);
+            edges[v].push(u);
+        }
+    }
+
+    // Visiting `v` in increasing order sorts each neighbor list, and an arc
+    // and its reverse push `v` twice in a row.
+    for (v, edges) in edges.iter().enumerate() {
+        for &u in edges {
+            if neighbors[u].last() != Some(&v) {
+                neighbors[u].push(v);
+            }
+        }
+    }
+
+    neighbors
+}
+
+/// The Hopcroft-Tarjan algorithm.
+///
+/// The Hopcroft-Tarjan algorithm[^1] finds the articulation points, bridges,
+/// biconnected components, and 2-edge-connected components of a digraph's
+/// underlying undirected graph.
+///
+/// # Examples
+///
+/// Vertex `2` joins the triangle `{0, 1, 2}` to the path `2 → 3 → 4`.
+///
+/// ```
+/// use {
+///     graaf::{
+///         AddArc,
+///         AdjacencyList,
+///         Empty,
+///         HopcroftTarjan,
+///     },
+///     std::collections::BTreeSet,
+/// };
+///
+/// let mut digraph = AdjacencyList::empty(5);
+///
+/// digraph.add_arc(0, 1);
+/// digraph.add_arc(1, 2);
+/// digraph.add_arc(2, 0);
+/// digraph.add_arc(2, 3);
+/// digraph.add_arc(3, 4);
+///
+/// let hopcroft_tarjan = HopcroftTarjan::new(&digraph);
+///
+/// assert!(
+///     hopcroft_tarjan
+///         .articulation_points()
+///         .eq(&BTreeSet::from([2, 3]))
+/// );
+/// assert!(
+///     hopcroft_tarjan
+///         .bridges()
+///         .eq(&BTreeSet::from([(2, 3), (3, 4)]))
+/// );
+/// ```
+///
+/// [^1]: John Hopcroft and Robert Tarjan. 1973. Algorithm 447: efficient
+///   algorithms for graph manipulation. Commun. ACM 16, 6 (June 1973),
+///   372–378. <https://doi.org/10.1145/362248.362272>
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct HopcroftTarjan {
+    articulation_points: BTreeSet<usize>,
+    biconnected_components: Vec<BTreeSet<usize>>,
+    bridges: BTreeSet<(usize, usize)>,
+    two_edge_connected_components: Vec<BTreeSet<usize>>,
+}
+
+impl HopcroftTarjan {
+    /// Construct a new instance of the Hopcroft-Tarjan algorithm.
+    ///
+    /// # Arguments
+    ///
+    /// * `digraph`: The digraph.
+    #[must_use]
+    pub fn new<D>(digraph: &D) -> Self
+    where
+        D: Arcs + Order,
+    {
+        let neighbors = neighbors(digraph);
+
+        let mut hopcroft_tarjan = Self {
+            articulation_points: BTreeSet::new(),
+            biconnected_components: Vec::new(),
+            bridges: BTreeSet::new(),
+            two_edge_connected_components: Vec::new(),
+        };
+
+        hopcroft_tarjan.search(&neighbors);
+        hopcroft_tarjan.split_bridges(&neighbors);
+
+        hopcroft_tarjan
+    }
+
+    /// Find the articulation points, biconnected components, and bridges.
+    fn search(&mut self, neighbors: &[Vec<usize>]) {
+        let order = neighbors.len();
+        let mut disc = vec![usize::MAX; order];
+        let mut low = vec![0; order];
+        let mut time = 0;
+        let mut stack = Vec::new();
+        let mut edges = Vec::new();
+
+        for r in 0..order {
+            if disc[r] != usize::MAX {
+                continue;
+            }
+
+            disc[r] = time;
+            low[r] = time;
+            time += 1;
+
+            let mut children = 0;
+
+            stack.push((r, r, 0));
+
+            while let Some((u, p, i)) = stack.last_mut() {
+                let (u, p) = (*u, *p);
+
+                if let Some(&v) = neighbors[u].get(*i) {
+                    *i += 1;
+
+                    if v == p {
+                        continue;
+                    }
+
+                    if disc[v] == usize::MAX {
+                        disc[v] = time;
+                        low[v] = time;
+                        time += 1;
+
+                        if u == r {
+                            children += 1;
+                        }
+
+                        edges.push((u, v));
+                        stack.push((v, u, 0));
+                    } else if disc[v] < disc[u] {
+                        low[u] = low[u].min(disc[v]);
+
+                        edges.push((u, v));
+                    }
+
+                    continue;
+                }
+
+                let _ = stack.pop();
+
+                if u == r {
+                    continue;
+                }
+
+                low[p] = low[p].min(low[u]);
+
+                if low[u] > disc[p] {
+                    let _ = self.bridges.insert((p.min(u), p.max(u)));
+                }
+
+                if low[u] >= disc[p] {
+                    if p != r {
+                        let _ = self.articulation_points.insert(p);
+                    }
+
+                    let mut component = BTreeSet::new();
+
+                    while let Some((x, y)) = edges.pop() {
+                        let _ = component.insert(x);
+                        let _ = component.insert(y);
+
+                        if (x, y) == (p, u) {
+                            break;
+                        }
+                    }
+
+                    self.biconnected_components.push(component);
+                }
+            }
+
+            if children > 1 {
+                let _ = self.articulation_points.insert(r);
+            }
+        }
+    }
+
+    /// Find the 2-edge-connected components by removing the bridges.
+    fn split_bridges(&mut self, neighbors: &[Vec<usize>]) {
+        let mut visited = vec![false; neighbors.len()];
+        let mut queue = VecDeque::new();
+
+        for s in 0..neighbors.len() {
+            if visited[s] {
+                continue;
+            }
+
+            let mut component = BTreeSet::new();
+
+            visited[s] = true;
+            queue.push_back(s);
+
+            while let Some(u) = queue.pop_front() {
+                let _ = component.insert(u);
+
+                for &v in &neighbors[u] {
+                    if !visited[v]
+                        && !self.bridges.contains(&(u.min(v), u.max(v)))
+                    {
+                        visited[v] = true;
+                        queue.push_back(v);
+                    }
+                }
+            }
+
+            self.two_edge_connected_components.push(component);
+        }
+    }
+
+    /// Find the articulation points of the digraph's underlying graph.
+    ///
+    /// An articulation point, or cut vertex, is a vertex whose removal
+    /// increases the number of connected components.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use {
+    ///     graaf::{
+    ///         AdjacencyList,
+    ///         HopcroftTarjan,
+    ///         Path,
+    ///     },
+    ///     std::collections::BTreeSet,
+    /// };
+    ///
+    /// let digraph = AdjacencyList::path(4);
+    ///
+    /// assert!(
+    ///     HopcroftTarjan::new(&digraph)
+    ///         .articulation_points()
+    ///         .eq(&BTreeSet::from([1, 2]))
+    /// );
+    /// ```
+    #[doc(alias = "cut_vertices")]
+    #[must_use]
+    pub const fn articulation_points(&self) -> &BTreeSet<usize> {
+        &self.articulation_points
+    }
+
+    /// Find the biconnected components of the digraph's underlying graph.
+    ///
+    /// A biconnected component is a maximal set of vertices that remains
+    /// connected after removing any one of its vertices. Isolated vertices
+    /// don't belong to a biconnected component.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use {
+    ///     graaf::{
+    ///         AddArc,
+    ///         AdjacencyList,
+    ///         Empty,
+    ///         HopcroftTarjan,
+    ///     },
+    ///     std::collections::BTreeSet,
+    /// };
+    ///
+    /// let mut digraph = AdjacencyList::empty(5);
+    ///
+    /// digraph.add_arc(0, 1);
+    /// digraph.add_arc(1, 2);
+    /// digraph.add_arc(2, 0);
+    /// digraph.add_arc(2, 3);
+    /// digraph.add_arc(3, 4);
+    ///
+    /// assert!(
+    ///     HopcroftTarjan::new(&digraph)
+    ///         .biconnected_components()
+    ///         .iter()
+    ///         .eq(&[
+    ///             BTreeSet::from([3, 4]),
+    ///             BTreeSet::from([2, 3]),
+    ///             BTreeSet::from([0, 1, 2]),
+    ///         ])
+    /// );
+    /// ```
+    #[doc(alias = "blocks")]
+    #[must_use]
+    pub fn biconnected_components(&self) -> &[BTreeSet<usize>] {
+        &self.biconnected_components
+    }
+
+    /// Find the bridges of the digraph's underlying graph.
+    ///
+    /// A bridge, or cut edge, is an edge whose removal increases the number
+    /// of connected components. Each bridge `{u, v}` is returned as `(u, v)`
+    /// with `u < v`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use {
+    ///     graaf::{
+    ///         AdjacencyList,
+    ///         HopcroftTarjan,
+    ///         Path,
+    ///     },
+    ///     std::collections::BTreeSet,
+    /// };
+    ///
+    /// let digraph = AdjacencyList::path(4);
+    ///
+    /// assert!(HopcroftTarjan::new(&digraph).bridges().eq(&BTreeSet::from([
+    ///     (0, 1),
+    ///     (1, 2),
+    ///     (2, 3)
+    /// ])));
+    /// ```
+    #[doc(alias = "cut_edges")]
+    #[must_use]
+    pub const fn bridges(&self) -> &BTreeSet<(usize, usize)> {
+        &self.bridges
+    }
+
+    /// Find the 2-edge-connected components of the digraph's underlying
+    /// graph.
+    ///
+    /// A 2-edge-connected component is a maximal set of vertices that remains
+    /// connected after removing any one edge. Removing the bridges splits the
+    /// underlying graph into its 2-edge-connected components. The components
+    /// are ordered by their smallest vertex.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use {
+    ///     graaf::{
+    ///         AddArc,
+    ///         AdjacencyList,
+    ///         Empty,
+    ///         HopcroftTarjan,
+    ///     },
+    ///     std::collections::BTreeSet,
+    /// };
+    ///
+    /// let mut digraph = AdjacencyList::empty(5);
+    ///
+    /// digraph.add_arc(0, 1);
+    /// digraph.add_arc(1, 2);
+    /// digraph.add_arc(2, 0);
+    /// digraph.add_arc(2, 3);
+    /// digraph.add_arc(3, 4);
+    ///
+    /// assert!(
+    ///     HopcroftTarjan::new(&digraph)
+    ///         .two_edge_connected_components()
+    ///         .iter()
+    ///         .eq(&[
+    ///             BTreeSet::from([0, 1, 2]),
+    ///             BTreeSet::from([3]),
+    ///             BTreeSet::from([4]),
+    ///         ])
+    /// );
+    /// ```
+    #[must_use]
+    pub fn two_edge_connected_components(&self) -> &[BTreeSet<usize>] {
+        &self.two_edge_connected_components
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::*,
+        crate::{
+            AdjacencyList,
+            Cycle,
+            Empty,
+            Star,
+            Wheel,
+            repr::adjacency_list::fixture::{
+                bang_jensen_34,
+                bang_jensen_94,
+                bang_jensen_196,
+                kattis_builddeps,
+                kattis_cantinaofbabel_1,
+                kattis_cantinaofbabel_2,
+                kattis_escapewallmaria_1,
+                kattis_escapewallmaria_2,
+                kattis_escapewallmaria_3,
+            },
+        },
+    };
+
+    #[test]
+    fn articulation_points_bang_jensen_196() {
+        assert!(
+            HopcroftTarjan::new(&bang_jensen_196())
+                .articulation_points()
+                .eq(&BTreeSet::from([7]))
+        );
+    }
+
+    #[test]
+    fn articulation_points_bang_jensen_34() {
+        assert!(
+            HopcroftTarjan::new(&bang_jensen_34())
+                .articulation_points()
+                .eq(&BTreeSet::from([2]))
+        );
+    }
+
+    #[test]
+    fn articulation_points_bang_jensen_94() {
+        assert!(
+            HopcroftTarjan::new(&bang_jensen_94())
+                .articulation_points()
+                .eq(&BTreeSet::from([2, 4]))
+        );
+    }
+
+    #[test]
+    fn articulation_points_cycle() {
+        assert!(
+            HopcroftTarjan::new(&AdjacencyList::cycle(5))
+                .articulation_points()
+                .is_empty()
+        );
+    }
+
+    #[test]
+    fn articulation_points_kattis_builddeps() {
+        assert!(
+            HopcroftTarjan::new(&kattis_builddeps())
+                .articulation_points()
+                .is_empty()
+        );
+    }
+
+    #[test]
+    fn articulation_points_kattis_cantinaofbabel_1() {
+        assert!(
+            HopcroftTarjan::new(&kattis_cantinaofbabel_1())
+                .articulation_points()
+                .eq(&BTreeSet::from([1, 3]))
+        );
+    }
+
+    #[test]
+    fn articulation_points_kattis_cantinaofbabel_2() {
+        assert!(
+            HopcroftTarjan::new(&kattis_cantinaofbabel_2())
+                .articulation_points()
+                .eq(&BTreeSet::from([2, 3, 5, 7, 8]))
+        );
+    }
+
+    #[test]
+    fn articulation_points_kattis_escapewallmaria_1() {
+        assert!(
+            HopcroftTarjan::new(&kattis_escapewallmaria_1())
+                .articulation_points()
+                .eq(&BTreeSet::from([5, 9, 13]))
+        );
+    }
+
+    #[test]
+    fn articulation_points_kattis_escapewallmaria_2() {
+        assert!(
+            HopcroftTarjan::new(&kattis_escapewallmaria_2())
+                .articulation_points()
+                .eq(&BTreeSet::from([5, 9, 13]))
+        );
+    }
+
+    #[test]
+    fn articulation_points_kattis_escapewallmaria_3() {
+        assert!(
+            HopcroftTarjan::new(&kattis_escapewallmaria_3())
+                .articulation_points()
+                .eq(&BTreeSet::from([5, 9, 13]))
+        );
+    }
+
+    #[test]
+    fn articulation_points_star() {
+        assert!(
+            HopcroftTarjan::new(&AdjacencyList::star(5))
+                .articulation_points()
+                .eq(&BTreeSet::from([0]))
+        );
+    }
+
+    #[test]
+    fn articulation_points_trivial() {
+        assert!(
+            HopcroftTarjan::new(&AdjacencyList::trivial())
+                .articulation_points()
+                .is_empty()
+        );
+    }
+
+    #[test]
+    fn articulation_points_wheel() {
+        assert!(
+            HopcroftTarjan::new(&AdjacencyList::wheel(5))
+                .articulation_points()
+                .is_empty()
+        );
+    }
+
+    #[test]
+    fn biconnected_components_bang_jensen_196() {
+        assert!(
+            HopcroftTarjan::new(&bang_jensen_196())
+                .biconnected_components()
+                .iter()
+                .eq(&[
+                    BTreeSet::from([5, 6, 7]),
+                    BTreeSet::from([0, 1, 2, 3, 4, 7]),
+                ])
+        );
+    }
+
+    #[test]
+    fn biconnected_components_bang_jensen_34() {
+        assert!(
+            HopcroftTarjan::new(&bang_jensen_34())
+                .biconnected_components()
+                .iter()
+                .eq(&[
+                    BTreeSet::from([2, 3]),
+                    BTreeSet::from([0, 1, 2, 4, 5])
+                ])
+        );
+    }
+
+    #[test]
+    fn biconnected_components_bang_jensen_94() {
+        assert!(
+            HopcroftTarjan::new(&bang_jensen_94())
+                .biconnected_components()
+                .iter()
+                .eq(&[
+                    BTreeSet::from([4, 6]),
+                    BTreeSet::from([2, 4]),
+                    BTreeSet::from([0, 1, 2, 3, 5]),
+                ])
+        );
+    }
+
+    #[test]
+    fn biconnected_components_cycle() {
+        assert!(
+            HopcroftTarjan::new(&AdjacencyList::cycle(5))
+                .biconnected_components()
+                .iter()
+                .eq(&[BTreeSet::from([0, 1, 2, 3, 4])])
+        );
+    }
+
+    #[test]
+    fn biconnected_components_empty() {
+        assert!(
+            HopcroftTarjan::new(&AdjacencyList::empty(3))
+                .biconnected_components()
+                .is_empty()
+        );
+    }
+
+    #[test]
+    fn biconnected_components_kattis_builddeps() {
+        assert!(
+            HopcroftTarjan::new(&kattis_builddeps())
+                .biconnected_components()
+                .iter()
+                .eq(&[BTreeSet::from([0, 1, 2, 3, 4, 5])])
+        );
+    }
+
+    #[test]
+    fn biconnected_components_kattis_cantinaofbabel_1() {
+        assert!(
+            HopcroftTarjan::new(&kattis_cantinaofbabel_1())
+                .biconnected_components()
+                .iter()
+                .eq(&[
+                    BTreeSet::from([3, 5, 6, 7, 8, 9, 10, 11]),
+                    BTreeSet::from([1, 2, 3, 4]),
+                    BTreeSet::from([0, 1]),
+                ])
+        );
+    }
+
+    #[test]
+    fn biconnected_components_kattis_cantinaofbabel_2() {
+        assert!(
+            HopcroftTarjan::new(&kattis_cantinaofbabel_2())
+                .biconnected_components()
+                .iter()
+                .eq(&[
+                    BTreeSet::from([3, 4]),
+                    BTreeSet::from([3, 5]),
+                    BTreeSet::from([5, 6]),
+                    BTreeSet::from([2, 5]),
+                    BTreeSet::from([8, 9, 10, 11]),
+                    BTreeSet::from([7, 8]),
+                    BTreeSet::from([0, 1, 2, 7]),
+                ])
+        );
+    }
+
+    #[test]
+    fn biconnected_components_kattis_escapewallmaria_1() {
+        assert!(
+            HopcroftTarjan::new(&kattis_escapewallmaria_1())
+                .biconnected_components()
+                .iter()
+                .eq(&[
+                    BTreeSet::from([5, 6]),
+                    BTreeSet::from([12, 13]),
+                    BTreeSet::from([9, 13]),
+                    BTreeSet::from([5, 9]),
+                ])
+        );
+    }
+
+    #[test]
+    fn biconnected_components_kattis_escapewallmaria_2() {
+        assert!(
+            HopcroftTarjan::new(&kattis_escapewallmaria_2())
+                .biconnected_components()
+                .iter()
+                .eq(&[
+                    BTreeSet::from([5, 6]),
+                    BTreeSet::from([12, 13]),
+                    BTreeSet::from([9, 13]),
+                    BTreeSet::from([5, 9]),
+                ])
+        );
+    }
+
+    #[test]
+    fn biconnected_components_kattis_escapewallmaria_3() {
+        assert!(
+            HopcroftTarjan::new(&kattis_escapewallmaria_3())
+                .biconnected_components()
+                .iter()
+                .eq(&[
+                    BTreeSet::from([12, 13]),
+                    BTreeSet::from([9, 13]),
+                    BTreeSet::from([5, 9]),
+                    BTreeSet::from([1, 2, 5, 6]),
+                ])
+        );
+    }
+
+    #[test]
+    fn bridges_bang_jensen_196() {
+        assert!(HopcroftTarjan::new(&bang_jensen_196()).bridges().is_empty());
+    }
+
+    #[test]
+    fn bridges_bang_jensen_34() {
+        assert!(
+            HopcroftTarjan::new(&bang_jensen_34())
+                .bridges()
+                .eq(&BTreeSet::from([(2, 3)]))
+        );
+    }
+
+    #[test]
+    fn bridges_bang_jensen_94() {
+        assert!(
+            HopcroftTarjan::new(&bang_jensen_94())
+                .bridges()
+                .eq(&BTreeSet::from([(2, 4), (4, 6)]))
+        );
+    }
+
+    #[test]
+    fn bridges_cycle() {
+        assert!(
+            HopcroftTarjan::new(&AdjacencyList::cycle(5))
+                .bridges()
+                .is_empty()
+        );
+    }
+
+    #[test]
+    fn bridges_kattis_builddeps() {
+        assert!(
+            HopcroftTarjan::new(&kattis_builddeps())
+                .bridges()
+                .is_empty()
+        );
+    }
+
+    #[test]
+    fn bridges_kattis_cantinaofbabel_1() {
+        assert!(
+            HopcroftTarjan::new(&kattis_cantinaofbabel_1())
+                .bridges()
+                .eq(&BTreeSet::from([(0, 1)]))
+        );
+    }
+
+    #[test]
+    fn bridges_kattis_cantinaofbabel_2() {
+        assert!(
+            HopcroftTarjan::new(&kattis_cantinaofbabel_2())
+                .bridges()
+                .eq(&BTreeSet::from([(2, 5), (3, 4), (3, 5), (5, 6), (7, 8)]))
+        );
+    }
+
+    #[test]
+    fn bridges_kattis_escapewallmaria_1() {
+        assert!(
+            HopcroftTarjan::new(&kattis_escapewallmaria_1())
+                .bridges()
+                .eq(&BTreeSet::from([(5, 6), (5, 9), (9, 13), (12, 13)]))
+        );
+    }
+
+    #[test]
+    fn bridges_kattis_escapewallmaria_2() {
+        assert!(
+            HopcroftTarjan::new(&kattis_escapewallmaria_2())
+                .bridges()
+                .eq(&BTreeSet::from([(5, 6), (5, 9), (9, 13), (12, 13)]))
+        );
+    }
+
+    #[test]
+    fn bridges_kattis_escapewallmaria_3() {
+        assert!(
+            HopcroftTarjan::new(&kattis_escapewallmaria_3())
+                .bridges()
+                .eq(&BTreeSet::from([(5, 9), (9, 13), (12, 13)]))
+        );
+    }
+
+    #[test]
+    fn bridges_star() {
+        assert!(
+            HopcroftTarjan::new(&AdjacencyList::star(4))
+                .bridges()
+                .eq(&BTreeSet::from([(0, 1), (0, 2), (0, 3)]))
+        );
+    }
+
+    #[test]
+    fn two_edge_connected_components_bang_jensen_196() {
+        assert!(
+            HopcroftTarjan::new(&bang_jensen_196())
+                .two_edge_connected_components()
+                .iter()
+                .eq(&[BTreeSet::from([0, 1, 2, 3, 4, 5, 6, 7])])
+        );
+    }
+
+    #[test]
+    fn two_edge_connected_components_bang_jensen_34() {
+        assert!(
+            HopcroftTarjan::new(&bang_jensen_34())
+                .two_edge_connected_components()
+                .iter()
+                .eq(&[BTreeSet::from([0, 1, 2, 4, 5]), BTreeSet::from([3])])
+        );
+    }
+
+    #[test]
+    fn two_edge_connected_components_bang_jensen_94() {
+        assert!(
+            HopcroftTarjan::new(&bang_jensen_94())
+                .two_edge_connected_components()
+                .iter()
+                .eq(&[
+                    BTreeSet::from([0, 1, 2, 3, 5]),
+                    BTreeSet::from([4]),
+                    BTreeSet::from([6]),
+                ])
+        );
+    }
+
+    #[test]
+    fn two_edge_connected_components_kattis_builddeps() {
+        assert!(
+            HopcroftTarjan::new(&kattis_builddeps())
+                .two_edge_connected_components()
+                .iter()
+                .eq(&[BTreeSet::from([0, 1, 2, 3, 4, 5])])
+        );
+    }
+
+    #[test]
+    fn two_edge_connected_components_kattis_cantinaofbabel_1() {
+        assert!(
+            HopcroftTarjan::new(&kattis_cantinaofbabel_1())
+                .two_edge_connected_components()
+                .iter()
+                .eq(&[
+                    BTreeSet::from([0]),
+                    BTreeSet::from([1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11]),
+                ])
+        );
+    }
+
+    #[test]
+    fn two_edge_connected_components_kattis_cantinaofbabel_2() {
+        assert!(
+            HopcroftTarjan::new(&kattis_cantinaofbabel_2())
+                .two_edge_connected_components()
+                .iter()
+                .eq(&[
+                    BTreeSet::from([0, 1, 2, 7]),
+                    BTreeSet::from([3]),
+                    BTreeSet::from([4]),
+                    BTreeSet::from([5]),
+                    BTreeSet::from([6]),
+                    BTreeSet::from([8, 9, 10, 11]),
+                ])
+        );
+    }
+
+    #[test]
+    fn two_edge_connected_components_kattis_escapewallmaria_1() {
+        assert!(
+            HopcroftTarjan::new(&kattis_escapewallmaria_1())
+                .two_edge_connected_components()
+                .iter()
+                .eq(&(0..16).map(|u| BTreeSet::from([u])).collect::<Vec<_>>())
+        );
+    }
+
+    #[test]
+    fn two_edge_connected_components_kattis_escapewallmaria_3() {
+        assert!(
+            HopcroftTarjan::new(&kattis_escapewallmaria_3())
+                .two_edge_connected_components()
+                .iter()
+                .eq(&[
+                    BTreeSet::from([0]),
+                    BTreeSet::from([1, 2, 5, 6]),
+                    BTreeSet::from([3]),
+                    BTreeSet::from([4]),
+                    BTreeSet::from([7]),
+                    BTreeSet::from([8]),
+                    BTreeSet::from([9]),
+                    BTreeSet::from([10]),
+                    BTreeSet::from([11]),
+                    BTreeSet::from([12]),
+                    BTreeSet::from([13]),
+                    BTreeSet::from([14]),
+                    BTreeSet::from([15]),
+                ])
+        );
+    }
+
+    #[test]
+    fn two_edge_connected_components_wheel() {
+        assert!(
+            HopcroftTarjan::new(&AdjacencyList::wheel(5))
+                .two_edge_connected_components()
+                .iter()
+                .eq(&[BTreeSet::from([0, 1, 2, 3, 4])])
+        );
+    }
+}
@@ -0,0 +1,684 @@
+//! The Hungarian method.
+//!
+//! The Hungarian method, also known as the Kuhn-Munkres algorithm, finds a
+//! minimum-cost assignment in a bipartite digraph whose arcs go from a left
+//! vertex set to a right vertex set.[^1][^2] Each vertex in the smaller set
+//! is matched to a distinct vertex in the larger set. The method grows the
+//! assignment one row at a time along shortest augmenting paths, keeping the
+//! reduced costs non-negative with vertex potentials.
+//!
+//! [`HungarianMatrix`] assigns the rows of a square cost matrix to its
+//! columns directly.
+//!
+//! The time complexity is `O(m² n)`, where `m` is the size of the smaller set
+//! and `n` is the size of the larger set.
+//!
+//! # Examples
+//!
+//! Assign three workers, `0`, `1`, and `2`, to three tasks, `3`, `4`, and
+//! `5`. The arc weights are the costs.
+//!
+//! ```
+//! use graaf::{
+//!     AddArcWeighted,
+//!     AdjacencyListWeighted,
+//!     Empty,
+//!     Hungarian,
+//! };
+//!
+//! let costs = [[4, 1, 3], [2, 0, 5], [3, 2, 2]];
+//! let mut digraph = AdjacencyListWeighted::<isize>::empty(6);
+//!
+//! for (u, row) in costs.iter().enumerate() {
+//!     for (v, &w) in row.iter().enumerate() {
+//!         digraph.add_arc_weighted(u, 3 + v, w);
+//!     }
+//! }
+//!
+//! let assignment = Hungarian::new(&digraph, 0..3).assignment().unwrap();
+//!
+//! assert_eq!(assignment.pairs, vec![(0, 4), (1, 3), (2, 5)]);
+//! assert_eq!(assignment.cost, 5);
+//! ```
+//!
+//! [^1]: H. W. Kuhn. 1955. The Hungarian method for the assignment problem.
+//!   Naval Research Logistics Quarterly 2, 1–2 (1955), 83–97.
+//!   <https://doi.org/10.1002/nav.3800020109>
+//!
+//! [^2]: James Munkres. 1957. Algorithms for the Assignment and
+//!   Transportation Problems. J. Soc. Indust. Appl. Math. 5, 1 (1957),
+//!   32–38. <https://doi.org/10.1137/0105003>
+
+use crate::{
+    ArcsWeighted,
+    DistanceMatrix,
+    Order,
+};
+
+/// A minimum-cost assignment.
+///
+/// # Examples
+///
+/// ```
+/// use graaf::{
+///     AddArcWeighted,
+///     AdjacencyListWeighted,
+///     Empty,
+///     Hungarian,
+/// };
+///
+/// let mut digraph = AdjacencyListWeighted::<isize>::empty(4);
+///
+/// digraph.add_arc_weighted(0, 2, 3);
+/// digraph.add_arc_weighted(0, 3, 1);
+/// digraph.add_arc_weighted(1, 2, 2);
+/// digraph.add_arc_weighted(1, 3, 4);
+///
+/// let assignment = Hungarian::new(&digraph, 0..2).assignment().unwrap();
+///
+/// assert_eq!(assignment.pairs, vec![(0, 3), (1, 2)]);
+/// assert_eq!(assignment.cost, 3);
+/// ```
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[non_exhaustive]
+pub struct Assignment {
+    /// The assigned `(left, right)` pairs, ordered by left vertex.
+    pub pairs: Vec<(usize, usize)>,
+    /// The total cost of the assignment.
+    pub cost: isize,
+}
+
+/// The Hungarian method.
+///
+/// # Examples
+///
+/// Assign three workers, `0`, `1`, and `2`, to three tasks, `3`, `4`, and
+/// `5`. The arc weights are the costs.
+///
+/// ```
+/// use graaf::{
+///     AddArcWeighted,
+///     AdjacencyListWeighted,
+///     Empty,
+///     Hungarian,
+/// };
+///
+/// let costs = [[4, 1, 3], [2, 0, 5], [3, 2, 2]];
+/// let mut digraph = AdjacencyListWeighted::<isize>::empty(6);
+///
+/// for (u, row) in costs.iter().enumerate() {
+///     for (v, &w) in row.iter().enumerate() {
+///         digraph.add_arc_weighted(u, 3 + v, w);
+///     }
+/// }
+///
+/// let assignment = Hungarian::new(&digraph, 0..3).assignment().unwrap();
+///
+/// assert_eq!(assignment.pairs, vec![(0, 4), (1, 3), (2, 5)]);
+/// assert_eq!(assignment.cost, 5);
+/// ```
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Hungarian<'a, D> {
+    digraph: &'a D,
+    left: Vec<bool>,
+}
+
+impl<'a, D> Hungarian<'a, D>
+where
+    D: ArcsWeighted<Weight = isize> + Order,
+{
+    /// Initialize the Hungarian method.
+    ///
+    /// The vertices not in `left` form the right set. Arcs that don't go
+    /// from a left vertex to a right vertex are ignored.
+    ///
+    /// # Arguments
+    ///
+    /// * `digraph`: The digraph. The arc weights are the costs.
+    /// * `left`: The left vertex set.
+    ///
+    /// # Panics
+    ///
+    /// Panics if a vertex in `left` isn't in the digraph.
+    #[must_use]
+    pub fn new<T>(digraph: &'a D, left: T) -> Self
+    where
+        T: IntoIterator<Item = usize>,
+    {
+        let order = digraph.order();
+        let mut is_left = vec![false; order];
+
+        for u in left {
+            assert!(u < order, "u = {u} isn't in the digraph");
+
+            is_left[u] = true;
+        }
+
+        Self {
+            digraph,
+            left: is_left,
+        }
+    }
+
+    /// Find a minimum-cost assignment.
+    ///
+    /// Every vertex in the smaller of the two sets is assigned. The sets may
+    /// differ in size.
+    ///
+    /// # Returns
+    ///
+    /// Returns `None` if no assignment covers the smaller set, for example
+    /// when a vertex in it has no arcs.
+    ///
+    /// # Examples
+    ///
+    /// Assign two workers, `0` and `1`, to two of three tasks, `2`, `3`, and
+    /// `4`.
+    ///
+    /// ```
+    /// use graaf::{
+    ///     AddArcWeighted,
+    ///     AdjacencyListWeighted,
+    ///     Empty,
+    ///     Hungarian,
+    /// };
+    ///
+    /// let mut digraph = AdjacencyListWeighted::<isize>::empty(5);
+    ///
+    /// digraph.add_arc_weighted(0, 2, 4);
+    /// digraph.add_arc_weighted(0, 3, 1);
+    /// digraph.add_arc_weighted(0, 4, 6);
+    /// digraph.add_arc_weighted(1, 2, 2);
+    /// digraph.add_arc_weighted(1, 3, 0);
+    /// digraph.add_arc_weighted(1, 4, 5);
+    ///
+    /// let assignment = Hungarian::new(&digraph, 0..2).assignment().unwrap();
+    ///
+    /// assert_eq!(assignment.pairs, vec![(0, 3), (1, 2)]);
+    /// assert_eq!(assignment.cost, 3);
+    ///
+    /// let mut digraph = AdjacencyListWeighted::<isize>::empty(4);
+    ///
+    /// digraph.add_arc_weighted(0, 2, 1);
+    /// digraph.add_arc_weighted(1, 2, 1);
+    ///
+    /// assert!(Hungarian::new(&digraph, 0..2).assignment().is_none());
+    /// ```
+    #[must_use]
+    pub fn assignment(&self) -> Option<Assignment> {
+        let order = self.digraph.order();
+        let mut index = vec![0; order];
+        let mut left = Vec::new();
+        let mut right = Vec::new();
+
+        for (u, i) in index.iter_mut().enumerate() {
+            if self.left[u] {
+                *i = left.len();
+                left.push(u);
+            } else {
+                *i = right.len();
+                right.push(u);
+            }
+        }
+
+        let mut costs = vec![None; left.len() * right.len()];
+
+        for (u, v, &w) in self.digraph.arcs_weighted() {
+            if self.left[u] && !self.left[v] {
+                costs[index[u] * right.len() + index[v]] = Some(w);
+            }
+        }
+
+        let mut pairs = Vec::with_capacity(left.len().min(right.len()));
+
+        if left.len() <= right.len() {
+            let columns = assign(left.len(), right.len(), |i, j| {
+                costs[i * right.len() + j]
+            })?;
+
+            for (i, j) in columns.into_iter().enumerate() {
+                pairs.push((left[i], right[j]));
+            }
+        } else {
+            let columns = assign(right.len(), left.len(), |j, i| {
+                costs[i * right.len() + j]
+            })?;
+
+            for (j, i) in columns.into_iter().enumerate() {
+                pairs.push((left[i], right[j]));
+            }
+
+            pairs.sort_unstable();
+        }
+
+        let cost = pairs
+            .iter()
+            .filter_map(|&(u, v)| costs[index[u] * right.len() + index[v]])
+            .sum();
+
+        Some(Assignment { pairs, cost })
+    }
+}
+
+/// The Hungarian method on a square cost matrix.
+///
+/// # Examples
+///
+/// ```
+/// use graaf::{
+///     DistanceMatrix,
+///     HungarianMatrix,
+/// };
+///
+/// let mut costs = DistanceMatrix::new(3, isize::MAX);
+///
+/// for (i, row) in [[4, isize::MAX, 3], [2, 0, 5], [4, 2, 2]]
+///     .iter()
+///     .enumerate()
+/// {
+///     for (j, &w) in row.iter().enumerate() {
+///         costs[(i, j)] = w;
+///     }
+/// }
+///
+/// let assignment = HungarianMatrix::new(&costs).assignment().unwrap();
+///
+/// assert_eq!(assignment.pairs, vec![(0, 0), (1, 1), (2, 2)]);
+/// assert_eq!(assignment.cost, 6);
+/// ```
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct HungarianMatrix<'a> {
+    costs: &'a DistanceMatrix<isize>,
+}
+
+impl<'a> HungarianMatrix<'a> {
+    /// Initialize the Hungarian method on a square cost matrix.
+    ///
+    /// Entry `(i, j)` is the cost of assigning row `i` to column `j`. Entries
+    /// equal to `infinity` are forbidden.
+    ///
+    /// # Arguments
+    ///
+    /// * `costs`: The cost matrix.
+    #[must_use]
+    pub const fn new(costs: &'a DistanceMatrix<isize>) -> Self {
+        Self { costs }
+    }
+
+    /// Find a minimum-cost assignment of rows to columns.
+    ///
+    /// # Returns
+    ///
+    /// Returns the `(row, column)` pairs and the total cost, or `None` if no
+    /// assignment avoids the forbidden entries.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use graaf::{
+    ///     DistanceMatrix,
+    ///     HungarianMatrix,
+    /// };
+    ///
+    /// let mut costs = DistanceMatrix::new(2, isize::MAX);
+    ///
+    /// costs[(0, 0)] = 1;
+    /// costs[(1, 0)] = 1;
+    ///
+    /// assert!(HungarianMatrix::new(&costs).assignment().is_none());
+    /// ```
+    #[must_use]
+    pub fn assignment(&self) -> Option<Assignment> {
+        let costs = self.costs;
+        let order = costs.order;
+
+        let columns = assign(order, order, |i, j| {
+            let w = costs[(i, j)];
+
+            (w != costs.infinity).then_some(w)
+        })?;
+
+        let pairs = columns.into_iter().enumerate().collect::<Vec<_>>();
+        let cost = pairs.iter().map(|&(i, j)| costs[(i, j)]).sum();
+
+        Some(Assignment { pairs, cost })
+    }
+}
+
+/// Assign each of `rows` rows to a distinct one of `columns` columns with
+/// minimum total cost, where `rows <= columns`. `None` costs are forbidden.
+/// Return the column of each row.
+fn assign<F>(rows: usize, columns: usize, cost: F) -> Option<Vec<usize>>
+where
+    F: Fn(usize, usize) -> Option<isize>,
+{
+    // Row `0` and column `0` are sentinels; row `i` and column `j` are
+    // `i - 1` and `j - 1` of the cost table.
+    let mut u = vec![0; rows + 1];
+    let mut v = vec![0; columns + 1];
+    let mut row = vec![0; columns + 1];
+    let mut way = vec![0; columns + 1];
+
+    for i in 1..=rows {
+        let mut min = vec![isize::MAX; columns + 1];
+        let mut used = vec![false; columns + 1];
+        let mut j0 = 0;
+
+        row[0] = i;
+
+        // Grow a shortest augmenting path to a free column.
+        while row[j0] != 0 {
+            let i0 = row[j0];
+            let mut delta = isize::MAX;
+            let mut j1 = 0;
+
+            used[j0] = true;
+
+            for j in 1..=columns {
+                if used[j] {
+                    continue;
+                }
+
+                if let Some(c) = cost(i0 - 1, j - 1) {
+                    let reduced = c - u[i0] - v[j];
+
+                    if reduced < min[j] {
+                        min[j] = reduced;
+                        way[j] = j0;
+                    }
+                }
+
+                if min[j] < delta {
+                    delta = min[j];
+                    j1 = j;
+                }
+            }
+
+            if delta == isize::MAX {
+                return None;
+            }
+
+            for j in 0..=columns {
+                if used[j] {
+                    u[row[j]] += delta;
+                    v[j] -= delta;
+                } else if min[j] != isize::MAX {
+                    min[j] -= delta;
+                }
+            }
+
+            j0 = j1;
+        }
+
+        // Flip the assignment along the path.
+        while j0 != 0 {
+            let j1 = way[j0];
+
+            row[j0] = row[j1];
+            j0 = j1;
+        }
+    }
+
+    let mut assignment = vec![0; rows];
+
+    for j in 1..=columns {
+        if row[j] != 0 {
+            assignment[row[j] - 1] = j - 1;
+        }
+    }
+
+    Some(assignment)
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::*,
+        crate::{
+            AddArcWeighted,
+            AdjacencyListWeighted,
+            Empty,
+            FloydWarshall,
+            proptest_strategy::digraph_weighted,
+            repr::adjacency_list_weighted::fixture::kattis_crosscountry_isize,
+        },
+        proptest::prelude::*,
+        std::iter::{
+            empty,
+            once,
+        },
+    };
+
+    /// Build the bipartite digraph of a cost matrix.
+    fn bipartite(
+        costs: &[Vec<isize>],
+        columns: usize,
+    ) -> AdjacencyListWeighted<isize> {
+        let rows = costs.len();
+
+        let arcs = costs
+            .iter()
+            .enumerate()
+            .flat_map(|(u, row)| {
+                row.iter()
+                    .take(columns)
+                    .enumerate()
+                    .map(move |(v, &w)| (u, rows + v, w))
+            })
+            .collect::<Vec<_>>();
+
+        digraph_weighted(rows + columns, &arcs)
+    }
+
+    /// Find the minimum cost by trying every assignment of the rows.
+    fn brute_force(
+        costs: &[Vec<isize>],
+        columns: usize,
+        i: usize,
+        used: &mut Vec<bool>,
+    ) -> isize {
+        if i == costs.len() {
+            return 0;
+        }
+
+        let mut min = isize::MAX;
+
+        for j in 0..columns {
+            if !used[j] {
+                used[j] = true;
+                min = min.min(
+                    costs[i][j] + brute_force(costs, columns, i + 1, used),
+                );
+                used[j] = false;
+            }
+        }
+
+        min
+    }
+
+    #[test]
+    fn assignment_forbidden_pair() {
+        let mut digraph = AdjacencyListWeighted::<isize>::empty(4);
+
+        digraph.add_arc_weighted(0, 2, 1);
+        digraph.add_arc_weighted(0, 3, 5);
+        digraph.add_arc_weighted(1, 2, 2);
+
+        let assignment = Hungarian::new(&digraph, 0..2).assignment().unwrap();
+
+        assert_eq!(assignment.pairs, vec![(0, 3), (1, 2)]);
+        assert_eq!(assignment.cost, 7);
+    }
+
+    #[test]
+    fn assignment_ignores_arcs_within_a_set() {
+        let mut digraph = AdjacencyListWeighted::<isize>::empty(4);
+
+        digraph.add_arc_weighted(0, 1, -10);
+        digraph.add_arc_weighted(0, 2, 1);
+        digraph.add_arc_weighted(1, 3, 1);
+        digraph.add_arc_weighted(3, 0, -10);
+
+        let assignment = Hungarian::new(&digraph, 0..2).assignment().unwrap();
+
+        assert_eq!(assignment.pairs, vec![(0, 2), (1, 3)]);
+        assert_eq!(assignment.cost, 2);
+    }
+
+    #[test]
+    fn assignment_infeasible() {
+        let mut digraph = AdjacencyListWeighted::<isize>::empty(4);
+
+        digraph.add_arc_weighted(0, 2, 1);
+        digraph.add_arc_weighted(0, 3, 2);
+
+        assert!(Hungarian::new(&digraph, 0..2).assignment().is_none());
+    }
+
+    #[test]
+    fn assignment_more_left() {
+        let digraph = bipartite(&[vec![4, 2], vec![1, 6], vec![3, 1]], 2);
+        let assignment = Hungarian::new(&digraph, 0..3).assignment().unwrap();
+
+        assert_eq!(assignment.pairs, vec![(1, 3), (2, 4)]);
+        assert_eq!(assignment.cost, 2);
+    }
+
+    #[test]
+    fn assignment_negative_costs() {
+        let digraph = bipartite(&[vec![-4, -1], vec![-2, -5]], 2);
+        let assignment = Hungarian::new(&digraph, 0..2).assignment().unwrap();
+
+        assert_eq!(assignment.pairs, vec![(0, 2), (1, 3)]);
+        assert_eq!(assignment.cost, -9);
+    }
+
+    #[test]
+    fn assignment_no_left() {
+        let digraph = bipartite(&[vec![1]], 1);
+        let assignment =
+            Hungarian::new(&digraph, empty()).assignment().unwrap();
+
+        assert!(assignment.pairs.is_empty());
+        assert_eq!(assignment.cost, 0);
+    }
+
+    #[test]
+    fn assignment_square() {
+        let digraph =
+            bipartite(&[vec![4, 1, 3], vec![2, 0, 5], vec![3, 2, 2]], 3);
+
+        let assignment = Hungarian::new(&digraph, 0..3).assignment().unwrap();
+
+        assert_eq!(assignment.pairs, vec![(0, 4), (1, 3), (2, 5)]);
+        assert_eq!(assignment.cost, 5);
+    }
+
+    #[test]
+    fn hungarian_matrix_eq_bipartite() {
+        let mut costs = DistanceMatrix::new(3, isize::MAX);
+
+        for (i, row) in [[4, 1, 3], [2, 0, 5], [3, 2, 2]].iter().enumerate() {
+            for (j, &w) in row.iter().enumerate() {
+                costs[(i, j)] = w;
+            }
+        }
+
+        let assignment = HungarianMatrix::new(&costs).assignment().unwrap();
+
+        assert_eq!(assignment.pairs, vec![(0, 1), (1, 0), (2, 2)]);
+        assert_eq!(assignment.cost, 5);
+    }
+
+    #[test]
+    fn hungarian_matrix_infeasible() {
+        let mut costs = DistanceMatrix::new(2, isize::MAX);
+
+        costs[(0, 0)] = 1;
+        costs[(1, 0)] = 1;
+
+        assert!(HungarianMatrix::new(&costs).assignment().is_none());
+    }
+
+    #[test]
+    fn hungarian_matrix_kattis_crosscountry() {
+        let digraph = kattis_crosscountry_isize();
+        let mut floyd_warshall = FloydWarshall::new(&digraph);
+        let dist = floyd_warshall.distances().unwrap();
+        let assignment = HungarianMatrix::new(dist).assignment().unwrap();
+
+        assert_eq!(assignment.pairs, vec![(0, 0), (1, 1), (2, 2), (3, 3)]);
+        assert_eq!(assignment.cost, 0);
+    }
+
+    #[test]
+    #[should_panic(expected = "u = 2 isn't in the digraph")]
+    fn new_left_out_of_bounds() {
+        let digraph = bipartite(&[vec![1]], 1);
+
+        drop(Hungarian::new(&digraph, once(2)));
+    }
+
+    proptest! {
+        #[test]
+        fn assignment_eq_brute_force(
+            rows in 1..5_usize,
+            columns in 1..5_usize,
+            costs in prop::collection::vec(
+                prop::collection::vec(-20..20_isize, 4),
+                4,
+            ),
+        ) {
+            let (rows, columns, costs) = if rows <= columns {
+                (rows, columns, costs[..rows].to_vec())
+            } else {
+                let transposed = (0..columns)
+                    .map(|j| (0..rows).map(|i| costs[i][j]).collect())
+                    .collect::<Vec<_>>();
+
+                (columns, rows, transposed)
+            };
+
+            let digraph = bipartite(&costs, columns);
+            let assignment =
+                Hungarian::new(&digraph, 0..rows).assignment().unwrap();
+
+            assert_eq!(assignment.pairs.len(), rows);
+
+            assert_eq!(
+                assignment.cost,
+                brute_force(&costs, columns, 0, &mut vec![false; columns])
+            );
+        }
+
+        #[test]
+        fn assignment_transpose_eq(
+            rows in 1..5_usize,
+            columns in 1..5_usize,
+            costs in prop::collection::vec(
+                prop::collection::vec(-20..20_isize, 4),
+                4,
+            ),
+        ) {
+            let costs = costs[..rows].to_vec();
+            let digraph = bipartite(&costs, columns);
+            let assignment =
+                Hungarian::new(&digraph, 0..rows).assignment().unwrap();
+
+            assert_eq!(assignment.pairs.len(), rows.min(columns));
+
+            let mut transposed =
+                AdjacencyListWeighted::empty(rows + columns);
+
+            for (u, v, &w) in digraph.arcs_weighted() {
+                transposed.add_arc_weighted(v, u, w);
+            }
+
+            let transposed = Hungarian::new(&transposed, rows..rows + columns)
+                .assignment()
+                .unwrap();
+
+            assert_eq!(transposed.cost, assignment.cost);
+        }
+    }
+}
@@ -0,0 +1,598 @@
+//! Italiano's algorithm.
+//!
+//! Italiano's algorithm[^1] finds a digraph's strong bridges and strong
+//! articulation points. A strong bridge is an arc whose removal increases the
+//! number of strongly connected components. A strong articulation point is a
+//! vertex whose removal increases the number of strongly connected
+//! components.
+//!
+//! [`Tarjan`] splits the digraph into its strongly connected components. In
+//! each component, the strong bridges are the bridges of the flowgraphs
+//! rooted at an arbitrary vertex `r` of the digraph and its converse, and the
+//! strong articulation points are the non-trivial [`Dominators`] of these
+//! flowgraphs, together with `r` if removing it breaks the component.
+//!
+//! The time complexity is `O(a log v)`, where `v` is the digraph's order and
+//! `a` is the digraph's size.
+//!
+//! # Examples
+//!
+//! ```
+//! use {
+//!     graaf::{
+//!         AddArc,
+//!         AdjacencyList,
+//!         Empty,
+//!         Italiano,
+//!     },
+//!     std::collections::BTreeSet,
+//! };
+//!
+//! let mut digraph = AdjacencyList::empty(4);
+//!
+//! digraph.add_arc(0, 1);
+//! digraph.add_arc(1, 2);
+//! digraph.add_arc(2, 0);
+//! digraph.add_arc(2, 3);
+//! digraph.add_arc(3, 2);
+//!
+//! let italiano = Italiano::new(&digraph);
+//!
+//! assert!(italiano.strong_bridges().eq(&BTreeSet::from([
+//!     (0, 1),
+//!     (1, 2),
+//!     (2, 0),
+//!     (2, 3),
+//!     (3, 2),
+//! ])));
+//!
+//! assert!(
+//!     italiano
+//!         .strong_articulation_points()
+//!         .eq(&BTreeSet::from([0, 1, 2]))
+//! );
+//! ```
+//!
+//! [^1]: Giuseppe F. Italiano, Luigi Laura, and Federico Santaroni. 2012.
+//!   Finding strong bridges and strong articulation points in linear time.
+//!   Theoretical Computer Science 447, 74–84.
+//!   <https://doi.org/10.1016/j.tcs.2011.11.011>
+
+use {
+    crate::{
+        Dominators,
+        InNeighbors,
+        Order,
+        OutNeighbors,
+        Tarjan,
+        Vertices,
+    },
+    std::collections::{
+        BTreeSet,
+        VecDeque,
+    },
+};
+
+/// A strongly connected component with its vertices relabeled from `0`.
+struct Component<'a>(&'a [Vec<usize>]);
+
+impl Order for Component<'_> {
+    fn order(&self) -> usize {
+        self.0.len()
+    }
+}
+
+impl OutNeighbors for Component<'_> {
+    fn out_neighbors(&self, u: usize) -> impl Iterator<Item = usize> {
+        self.0[u].iter().copied()
+    }
+}
+
+/// Count the vertices reachable from `s` without passing through `skip`.
+fn reach(succ: &[Vec<usize>], s: usize, skip: usize) -> usize {
+    let mut visited = vec![false; succ.len()];
+    let mut queue = VecDeque::from([s]);
+    let mut count = 0;
+
+    visited[s] = true;
+    visited[skip] = true;
+
+    while let Some(u) = queue.pop_front() {
+        count += 1;
+
+        for &v in &succ[u] {
+            if !visited[v] {
+                visited[v] = true;
+                queue.push_back(v);
+            }
+        }
+    }
+
+    count
+}
+
+/// Italiano's algorithm.
+///
+/// Italiano's algorithm[^1] finds a digraph's strong bridges and strong
+/// articulation points.
+///
+/// # Examples
+///
+/// ```
+/// use {
+///     graaf::{
+///         AddArc,
+///         AdjacencyList,
+///         Empty,
+///         Italiano,
+///     },
+///     std::collections::BTreeSet,
+/// };
+///
+/// let mut digraph = AdjacencyList::empty(4);
+///
+/// digraph.add_arc(0, 1);
+/// digraph.add_arc(1, 2);
+/// digraph.add_arc(2, 0);
+/// digraph.add_arc(2, 3);
+/// digraph.add_arc(3, 2);
+///
+/// let italiano = Italiano::new(&digraph);
+///
+/// assert!(italiano.strong_bridges().eq(&BTreeSet::from([
+///     (0, 1),
+///     (1, 2),
+///     (2, 0),
+///     (2, 3),
+///     (3, 2),
+/// ])));
+///
+/// assert!(
+///     italiano
+///         .strong_articulation_points()
+///         .eq(&BTreeSet::from([0, 1, 2]))
+/// );
+/// ```
+///
+/// [^1]: Giuseppe F. Italiano, Luigi Laura, and Federico Santaroni. 2012.
+///   Finding strong bridges and strong articulation points in linear time.
+///   Theoretical Computer Science 447, 74–84.
+///   <https://doi.org/10.1016/j.tcs.2011.11.011>
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Italiano {
+    strong_articulation_points: BTreeSet<usize>,
+    strong_bridges: BTreeSet<(usize, usize)>,
+}
+
+impl Italiano {
+    /// Construct a new instance of Italiano's algorithm.
+    ///
+    /// # Arguments
+    ///
+    /// * `digraph`: The digraph.
+    #[must_use]
+    pub fn new<D>(digraph: &D) -> Self
+    where
+        D: InNeighbors + OutNeighbors + Vertices,
+    {
+        let mut italiano = Self {
+            strong_articulation_points: BTreeSet::new(),
+            strong_bridges: BTreeSet::new(),
+        };
+
+        for component in Tarjan::new(digraph).components() {
+            let order = component.len();
+
+            if order < 2 {
+                continue;
+            }
+
+            let vertices = component.iter().copied().collect::<Vec<_>>();
+            let local = |u: usize| vertices.binary_search(&u).ok();
+
+            let out = vertices
+                .iter()
+                .map(|&u| digraph.out_neighbors(u).filter_map(local).collect())
+                .collect::<Vec<Vec<_>>>();
+
+            let inn = vertices
+                .iter()
+                .map(|&u| digraph.in_neighbors(u).filter_map(local).collect())
+                .collect::<Vec<Vec<_>>>();
+
+            for (succ, pred, converse) in
+                [(&out, &inn, false), (&inn, &out, true)]
+            {
+                let tree = Dominators::new(&Component(succ), 0).tree();
+
+                for v in 1..order {
+                    if let Some(u) = tree.idom(v).filter(|&u| u != 0) {
+                        let _ = italiano
+                            .strong_articulation_points
+                            .insert(vertices[u]);
+                    }
+
+                    let mut entries =
+                        pred[v].iter().filter(|&&w| !tree.dominates(v, w));
+
+                    if let (Some(&u), None) = (entries.next(), entries.next())
+                    {
+                        let arc = if converse {
+                            (vertices[v], vertices[u])
+                        } else {
+                            (vertices[u], vertices[v])
+                        };
+
+                        let _ = italiano.strong_bridges.insert(arc);
+                    }
+                }
+            }
+
+            if reach(&out, 1, 0) != order - 1 || reach(&inn, 1, 0) != order - 1
+            {
+                let _ =
+                    italiano.strong_articulation_points.insert(vertices[0]);
+            }
+        }
+
+        italiano
+    }
+
+    /// Find the digraph's strong articulation points.
+    ///
+    /// A strong articulation point is a vertex whose removal increases the
+    /// number of strongly connected components.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use {
+    ///     graaf::{
+    ///         AdjacencyList,
+    ///         Circuit,
+    ///         Italiano,
+    ///     },
+    ///     std::collections::BTreeSet,
+    /// };
+    ///
+    /// let digraph = AdjacencyList::circuit(4);
+    ///
+    /// assert!(
+    ///     Italiano::new(&digraph)
+    ///         .strong_articulation_points()
+    ///         .eq(&BTreeSet::from([0, 1, 2, 3]))
+    /// );
+    /// ```
+    #[must_use]
+    pub const fn strong_articulation_points(&self) -> &BTreeSet<usize> {
+        &self.strong_articulation_points
+    }
+
+    /// Find the digraph's strong bridges.
+    ///
+    /// A strong bridge is an arc whose removal increases the number of
+    /// strongly connected components.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use {
+    ///     graaf::{
+    ///         AdjacencyList,
+    ///         Circuit,
+    ///         Italiano,
+    ///     },
+    ///     std::collections::BTreeSet,
+    /// };
+    ///
+    /// let digraph = AdjacencyList::circuit(3);
+    ///
+    /// assert!(
+    ///     Italiano::new(&digraph)
+    ///         .strong_bridges()
+    ///         .eq(&BTreeSet::from([(0, 1), (1, 2), (2, 0)]))
+    /// );
+    /// ```
+    #[must_use]
+    pub const fn strong_bridges(&self) -> &BTreeSet<(usize, usize)> {
+        &self.strong_bridges
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::*,
+        crate::{
+            AdjacencyList,
+            Circuit,
+            Complete,
+            Cycle,
+            Empty,
+            repr::adjacency_list::fixture::{
+                bang_jensen_34,
+                bang_jensen_94,
+                bang_jensen_196,
+                kattis_builddeps,
+                kattis_cantinaofbabel_1,
+                kattis_cantinaofbabel_2,
+                kattis_escapewallmaria_1,
+                kattis_escapewallmaria_2,
+                kattis_escapewallmaria_3,
+            },
+        },
+    };
+
+    #[test]
+    fn strong_articulation_points_bang_jensen_196() {
+        assert!(
+            Italiano::new(&bang_jensen_196())
+                .strong_articulation_points()
+                .eq(&BTreeSet::from([2, 3, 5, 6, 7]))
+        );
+    }
+
+    #[test]
+    fn strong_articulation_points_bang_jensen_34() {
+        assert!(
+            Italiano::new(&bang_jensen_34())
+                .strong_articulation_points()
+                .is_empty()
+        );
+    }
+
+    #[test]
+    fn strong_articulation_points_bang_jensen_94() {
+        assert!(
+            Italiano::new(&bang_jensen_94())
+                .strong_articulation_points()
+                .is_empty()
+        );
+    }
+
+    #[test]
+    fn strong_articulation_points_complete() {
+        assert!(
+            Italiano::new(&AdjacencyList::complete(4))
+                .strong_articulation_points()
+                .is_empty()
+        );
+    }
+
+    #[test]
+    fn strong_articulation_points_cycle() {
+        assert!(
+            Italiano::new(&AdjacencyList::cycle(4))
+                .strong_articulation_points()
+                .is_empty()
+        );
+    }
+
+    #[test]
+    fn strong_articulation_points_kattis_builddeps() {
+        assert!(
+            Italiano::new(&kattis_builddeps())
+                .strong_articulation_points()
+                .is_empty()
+        );
+    }
+
+    #[test]
+    fn strong_articulation_points_kattis_cantinaofbabel_1() {
+        assert!(
+            Italiano::new(&kattis_cantinaofbabel_1())
+                .strong_articulation_points()
+                .eq(&BTreeSet::from([1, 2, 3, 4, 6, 7, 9, 11]))
+        );
+    }
+
+    #[test]
+    fn strong_articulation_points_kattis_cantinaofbabel_2() {
+        assert!(
+            Italiano::new(&kattis_cantinaofbabel_2())
+                .strong_articulation_points()
+                .eq(&BTreeSet::from([0, 1, 2, 7, 8, 9, 10, 11]))
+        );
+    }
+
+    #[test]
+    fn strong_articulation_points_kattis_escapewallmaria_1() {
+        assert!(
+            Italiano::new(&kattis_escapewallmaria_1())
+                .strong_articulation_points()
+                .eq(&BTreeSet::from([5, 9]))
+        );
+    }
+
+    #[test]
+    fn strong_articulation_points_kattis_escapewallmaria_2() {
+        assert!(
+            Italiano::new(&kattis_escapewallmaria_2())
+                .strong_articulation_points()
+                .eq(&BTreeSet::from([5]))
+        );
+    }
+
+    #[test]
+    fn strong_articulation_points_kattis_escapewallmaria_3() {
+        assert!(
+            Italiano::new(&kattis_escapewallmaria_3())
+                .strong_articulation_points()
+                .eq(&BTreeSet::from([5, 9, 13]))
+        );
+    }
+
+    #[test]
+    fn strong_articulation_points_trivial() {
+        assert!(
+            Italiano::new(&AdjacencyList::trivial())
+                .strong_articulation_points()
+                .is_empty()
+        );
+    }
+
+    #[test]
+    fn strong_bridges_bang_jensen_196() {
+        assert!(Italiano::new(&bang_jensen_196()).strong_bridges().eq(
+            &BTreeSet::from([
+                (0, 1),
+                (1, 0),
+                (2, 3),
+                (3, 4),
+                (4, 2),
+                (5, 6),
+                (6, 7),
+                (7, 5)
+            ])
+        ));
+    }
+
+    #[test]
+    fn strong_bridges_bang_jensen_34() {
+        assert!(Italiano::new(&bang_jensen_34()).strong_bridges().is_empty());
+    }
+
+    #[test]
+    fn strong_bridges_bang_jensen_94() {
+        assert!(Italiano::new(&bang_jensen_94()).strong_bridges().is_empty());
+    }
+
+    #[test]
+    fn strong_bridges_circuit() {
+        assert!(
+            Italiano::new(&AdjacencyList::circuit(4))
+                .strong_bridges()
+                .eq(&BTreeSet::from([(0, 1), (1, 2), (2, 3), (3, 0)]))
+        );
+    }
+
+    #[test]
+    fn strong_bridges_complete() {
+        assert!(
+            Italiano::new(&AdjacencyList::complete(4))
+                .strong_bridges()
+                .is_empty()
+        );
+    }
+
+    #[test]
+    fn strong_bridges_cycle() {
+        assert!(
+            Italiano::new(&AdjacencyList::cycle(4))
+                .strong_bridges()
+                .is_empty()
+        );
+    }
+
+    #[test]
+    fn strong_bridges_kattis_builddeps() {
+        assert!(
+            Italiano::new(&kattis_builddeps())
+                .strong_bridges()
+                .is_empty()
+        );
+    }
+
+    #[test]
+    fn strong_bridges_kattis_cantinaofbabel_1() {
+        assert!(
+            Italiano::new(&kattis_cantinaofbabel_1())
+                .strong_bridges()
+                .eq(&BTreeSet::from([
+                    (0, 1),
+                    (1, 0),
+                    (1, 4),
+                    (2, 1),
+                    (3, 2),
+                    (3, 11),
+                    (4, 3),
+                    (5, 6),
+                    (6, 5),
+                    (6, 10),
+                    (7, 3),
+                    (9, 7),
+                    (10, 6),
+                    (11, 9)
+                ]))
+        );
+    }
+
+    #[test]
+    fn strong_bridges_kattis_cantinaofbabel_2() {
+        assert!(
+            Italiano::new(&kattis_cantinaofbabel_2())
+                .strong_bridges()
+                .eq(&BTreeSet::from([
+                    (0, 1),
+                    (1, 7),
+                    (2, 0),
+                    (3, 4),
+                    (4, 3),
+                    (5, 6),
+                    (6, 5),
+                    (7, 2),
+                    (8, 11),
+                    (9, 8),
+                    (10, 9),
+                    (11, 10)
+                ]))
+        );
+    }
+
+    #[test]
+    fn strong_bridges_kattis_escapewallmaria_1() {
+        assert!(
+            Italiano::new(&kattis_escapewallmaria_1())
+                .strong_bridges()
+                .eq(&BTreeSet::from([
+                    (5, 6),
+                    (5, 9),
+                    (6, 5),
+                    (9, 5),
+                    (9, 13),
+                    (13, 9)
+                ]))
+        );
+    }
+
+    #[test]
+    fn strong_bridges_kattis_escapewallmaria_2() {
+        assert!(
+            Italiano::new(&kattis_escapewallmaria_2())
+                .strong_bridges()
+                .eq(&BTreeSet::from([
+                    (5, 6),
+                    (5, 9),
+                    (6, 5),
+                    (9, 5),
+                    (12, 13),
+                    (13, 12)
+                ]))
+        );
+    }
+
+    #[test]
+    fn strong_bridges_kattis_escapewallmaria_3() {
+        assert!(
+            Italiano::new(&kattis_escapewallmaria_3())
+                .strong_bridges()
+                .eq(&BTreeSet::from([
+                    (5, 9),
+                    (9, 5),
+                    (9, 13),
+                    (12, 13),
+                    (13, 9),
+                    (13, 12)
+                ]))
+        );
+    }
+
+    #[test]
+    fn strong_bridges_trivial() {
+        assert!(
+            Italiano::new(&AdjacencyList::trivial())
+                .strong_bridges()
+                .is_empty()
+        );
+    }
+}
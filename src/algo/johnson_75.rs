@@ -1,5 +1,20 @@
 //! Johnson's circuit-finding algorithm.
 //!
+//! Johnson's algorithm[^1] iterates over a digraph's circuits, one at a time.
+//! Each circuit starts at its smallest vertex.
+//!
+//! [`Johnson75::max_length`] bounds the number of vertices in a circuit. The
+//! bounded search uses the length-aware blocking of Gupta and Suzumura[^2]
+//! instead of Johnson's plain blocking. [`Johnson75::max_count`] bounds the
+//! number of circuits.
+//!
+//! Before searching from each start vertex, the algorithm finds the strongly
+//! connected components of the subdigraph induced by the remaining vertices.
+//! This preprocessing takes `O(v (v + a) log v)` time over all start vertices,
+//! where `v` is the digraph's order and `a` is the digraph's size. The search
+//! itself takes `O((v + a)(c + 1))` time, where `c` is the number of circuits,
+//! or `O((v + a) k (c + 1))` time with a maximum length `k`.
+//!
 //! # Examples
 //!
 //! ```
@@ -10,9 +25,8 @@
 //! };
 //!
 //! let digraph = AdjacencyMap::cycle(5);
-//! let mut johnson = Johnson75::new(&digraph);
 //!
-//! assert!(johnson.circuits().eq(&[
+//! assert!(Johnson75::new(&digraph).eq([
 //!     vec![0, 1],
 //!     vec![0, 1, 2, 3, 4],
 //!     vec![0, 4],
@@ -22,10 +36,34 @@
 //!     vec![3, 4]
 //! ]));
 //! ```
+//!
+//! ## Bounded
+//!
+//! ```
+//! use graaf::{
+//!     AdjacencyMap,
+//!     Cycle,
+//!     Johnson75,
+//! };
+//!
+//! let digraph = AdjacencyMap::cycle(5);
+//!
+//! assert!(Johnson75::new(&digraph).max_length(2).max_count(3).eq([
+//!     vec![0, 1],
+//!     vec![0, 4],
+//!     vec![1, 2]
+//! ]));
+//! ```
+//!
+//! [^1]: Donald B. Johnson. 1975. Finding all the elementary circuits of a
+//!   directed graph. SIAM Journal on Computing 4, 1 (1975), 77–84.
+//!   <https://doi.org/10.1137/0204007>
+//!
+//! [^2]: Anshul Gupta and Toyotaro Suzumura. 2021. Finding all bounded-length
+//!   simple cycles in a directed graph. <https://arxiv.org/abs/2105.10094>
 
 use {
     crate::{
-        FilterVertices,
         Order,
         OutNeighbors,
         Tarjan,
@@ -34,13 +72,89 @@ use {
     std::collections::BTreeSet,
 };
 
+/// The subdigraph induced by the vertices not less than `s`.
+struct Suffix<'a, D> {
+    digraph: &'a D,
+    s: usize,
+}
+
+impl<D> OutNeighbors for Suffix<'_, D>
+where
+    D: OutNeighbors,
+{
+    fn out_neighbors(&self, u: usize) -> impl Iterator<Item = usize> {
+        self.digraph.out_neighbors(u).filter(|&v| v >= self.s)
+    }
+}
+
+impl<D> Vertices for Suffix<'_, D>
+where
+    D: Vertices,
+{
+    fn vertices(&self) -> impl Iterator<Item = usize> {
+        self.digraph.vertices().filter(|&u| u >= self.s)
+    }
+}
+
 /// Johnson's circuit-finding algorithm.
+///
+/// # Examples
+///
+/// ```
+/// use graaf::{
+///     AdjacencyMap,
+///     Cycle,
+///     Johnson75,
+/// };
+///
+/// let digraph = AdjacencyMap::cycle(5);
+///
+/// assert!(Johnson75::new(&digraph).eq([
+///     vec![0, 1],
+///     vec![0, 1, 2, 3, 4],
+///     vec![0, 4],
+///     vec![0, 4, 3, 2, 1],
+///     vec![1, 2],
+///     vec![2, 3],
+///     vec![3, 4]
+/// ]));
+/// ```
+///
+/// ## Bounded
+///
+/// ```
+/// use graaf::{
+///     AdjacencyMap,
+///     Cycle,
+///     Johnson75,
+/// };
+///
+/// let digraph = AdjacencyMap::cycle(5);
+///
+/// assert!(Johnson75::new(&digraph).max_length(2).max_count(3).eq([
+///     vec![0, 1],
+///     vec![0, 4],
+///     vec![1, 2]
+/// ]));
+/// ```
+///
+/// [^1]: Donald B. Johnson. 1975. Finding all the elementary circuits of a
+///   directed graph. SIAM Journal on Computing 4, 1 (1975), 77–84.
+///   <https://doi.org/10.1137/0204007>
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct Johnson75<'a, D> {
     a: &'a D,
     b: Vec<BTreeSet<usize>>,
-    blocked: BTreeSet<usize>,
+    lock: Vec<usize>,
+    on_stack: Vec<bool>,
     stack: Vec<usize>,
+    next: Vec<usize>,
+    blen: Vec<usize>,
+    neighbors: Vec<Vec<usize>>,
+    starts: Vec<usize>,
+    max_length: Option<usize>,
+    max_count: Option<usize>,
+    count: usize,
 }
 
 impl<'a, D> Johnson75<'a, D> {
@@ -52,78 +166,65 @@ impl<'a, D> Johnson75<'a, D> {
     #[must_use]
     pub fn new(a: &'a D) -> Self
     where
-        D: Order,
+        D: Order + Vertices,
     {
+        let order = a.order();
+        let mut starts = a.vertices().collect::<Vec<_>>();
+
+        starts.reverse();
+
         Self {
             a,
-            b: vec![BTreeSet::new(); a.order()],
-            blocked: BTreeSet::new(),
+            b: vec![BTreeSet::new(); order],
+            lock: vec![usize::MAX; order],
+            on_stack: vec![false; order],
             stack: Vec::new(),
+            next: Vec::new(),
+            blen: Vec::new(),
+            neighbors: vec![Vec::new(); order],
+            starts,
+            max_length: None,
+            max_count: None,
+            count: 0,
         }
     }
 
+    /// Only find circuits with at most `max_length` vertices.
+    ///
+    /// # Arguments
+    ///
+    /// * `max_length`: The maximum number of vertices in a circuit.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use graaf::{
+    ///     AdjacencyMap,
+    ///     Cycle,
+    ///     Johnson75,
+    /// };
+    ///
+    /// let digraph = AdjacencyMap::cycle(4);
+    ///
+    /// assert!(Johnson75::new(&digraph).max_length(2).eq([
+    ///     vec![0, 1],
+    ///     vec![0, 3],
+    ///     vec![1, 2],
+    ///     vec![2, 3]
+    /// ]));
+    /// ```
     #[must_use]
-    fn is_blocked(&self, u: usize) -> bool {
-        self.blocked.contains(&u)
-    }
-
-    fn unblock(&mut self, u: usize) {
-        if self.is_blocked(u) {
-            let _ = self.blocked.remove(&u);
-            let b_ptr = self.b.as_mut_ptr();
-
-            while let Some(v) = unsafe { (*b_ptr.add(u)).pop_first() } {
-                self.unblock(v);
-            }
-        }
-    }
-
-    #[must_use]
-    fn circuit(
-        &mut self,
-        v: usize,
-        s: usize,
-        scc: &D,
-        result: &mut Vec<Vec<usize>>,
-    ) -> bool
-    where
-        D: OutNeighbors,
-    {
-        self.stack.push(v);
-
-        let mut f = false;
-        let _ = self.blocked.insert(v);
-
-        for w in scc.out_neighbors(v) {
-            if w == s {
-                result.push(self.stack.clone());
-
-                f = true;
-            } else if !self.is_blocked(w) && self.circuit(w, s, scc, result) {
-                f = true;
-            }
-        }
+    pub const fn max_length(mut self, max_length: usize) -> Self {
+        self.max_length = Some(max_length);
 
-        if f {
-            self.unblock(v);
-        } else {
-            let b_ptr = self.b.as_mut_ptr();
-
-            for w in scc.out_neighbors(v) {
-                let _ = unsafe { (*b_ptr.add(w)).insert(v) };
-            }
-        }
-
-        let _ = self.stack.pop();
-
-        f
+        self
     }
 
-    /// Find circuits.
+    /// Stop after finding `max_count` circuits.
     ///
-    /// # Panics
+    /// # Arguments
     ///
-    /// Panics if there is a bug in [`Tarjan::components`].
+    /// * `max_count`: The maximum number of circuits.
     ///
     /// # Examples
     ///
@@ -134,57 +235,156 @@ impl<'a, D> Johnson75<'a, D> {
     ///     Johnson75,
     /// };
     ///
-    /// let digraph = AdjacencyMap::cycle(5);
+    /// let digraph = AdjacencyMap::cycle(4);
     ///
-    /// assert!(Johnson75::new(&digraph).circuits().eq(&[
-    ///     vec![0, 1],
-    ///     vec![0, 1, 2, 3, 4],
-    ///     vec![0, 4],
-    ///     vec![0, 4, 3, 2, 1],
-    ///     vec![1, 2],
-    ///     vec![2, 3],
-    ///     vec![3, 4]
-    /// ]));
+    /// assert!(
+    ///     Johnson75::new(&digraph)
+    ///         .max_count(2)
+    ///         .eq([vec![0, 1], vec![0, 1, 2, 3]])
+    /// );
     /// ```
     #[must_use]
-    pub fn circuits(&mut self) -> Vec<Vec<usize>>
+    pub const fn max_count(mut self, max_count: usize) -> Self {
+        self.max_count = Some(max_count);
+
+        self
+    }
+
+    /// Prepare the search for circuits starting at `s`.
+    ///
+    /// Only vertices in the strongly connected component of `s` in the
+    /// subdigraph induced by the vertices not less than `s` can be on such a
+    /// circuit.
+    fn start(&mut self, s: usize, k: usize)
     where
-        D: FilterVertices + Order + OutNeighbors + Vertices,
+        D: OutNeighbors + Vertices,
     {
-        let mut result = Vec::new();
-
-        for s in self.a.vertices() {
-            let subgraph = self.a.filter_vertices(|u| u >= s);
-            let mut tarjan = Tarjan::new(&subgraph);
-            let components = tarjan.components();
-
-            if let Some(min_scc) =
-                components.iter().min_by_key(|scc| scc.iter().min())
-            {
-                let component =
-                    self.a.filter_vertices(|u| min_scc.contains(&u));
-
-                if component.order() > 0 {
-                    let &start = min_scc.iter().min().unwrap();
-                    let b_ptr = self.b.as_mut_ptr();
-
-                    for vertex in component.vertices() {
-                        let _ = self.blocked.remove(&vertex);
-
-                        unsafe {
-                            if let Some(b_set) = b_ptr.add(vertex).as_mut() {
-                                b_set.clear();
-                            }
-                        };
-                    }
-
-                    let _ =
-                        self.circuit(start, start, &component, &mut result);
+        let subgraph = Suffix { digraph: self.a, s };
+        let mut tarjan = Tarjan::new(&subgraph);
+
+        let Some(component) =
+            tarjan.components().iter().find(|scc| scc.contains(&s))
+        else {
+            return;
+        };
+
+        for &u in component {
+            self.b[u].clear();
+            self.lock[u] = k;
+            self.neighbors[u] = self
+                .a
+                .out_neighbors(u)
+                .filter(|v| component.contains(v))
+                .collect();
+        }
+
+        self.lock[s] = 0;
+        self.on_stack[s] = true;
+        self.stack.push(s);
+        self.next.push(0);
+        self.blen.push(k);
+    }
+
+    /// Release the vertices blocked by `v` after it closed a circuit.
+    fn unblock(&mut self, v: usize, bl: usize, k: usize) {
+        let mut stack = vec![(bl, v)];
+
+        while let Some((bl, u)) = stack.pop() {
+            if self.max_length.is_none() {
+                if self.lock[u] != usize::MAX {
+                    self.lock[u] = usize::MAX;
+
+                    stack.extend(self.b[u].iter().map(|&w| (bl, w)));
+                    self.b[u].clear();
                 }
+            } else if self.lock[u] < k - bl + 1 {
+                self.lock[u] = k - bl + 1;
+
+                stack.extend(
+                    self.b[u]
+                        .iter()
+                        .filter(|&&w| !self.on_stack[w])
+                        .map(|&w| (bl + 1, w)),
+                );
             }
         }
+    }
+}
+
+impl<D> Iterator for Johnson75<'_, D>
+where
+    D: OutNeighbors + Vertices,
+{
+    type Item = Vec<usize>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self
+            .max_count
+            .is_some_and(|max_count| self.count >= max_count)
+            || self.max_length == Some(0)
+        {
+            return None;
+        }
+
+        let k = self.max_length.unwrap_or(usize::MAX);
+
+        loop {
+            let Some(&v) = self.stack.last() else {
+                let s = self.starts.pop()?;
+
+                self.start(s, k);
+
+                continue;
+            };
 
-        result
+            let s = self.stack[0];
+            let i = self.next.len() - 1;
+
+            if let Some(&w) = self.neighbors[v].get(self.next[i]) {
+                self.next[i] += 1;
+
+                if w == s {
+                    self.blen[i] = 1;
+                    self.count += 1;
+
+                    return Some(self.stack.clone());
+                }
+
+                if self.stack.len() < self.lock[w] {
+                    self.lock[w] = if self.max_length.is_some() {
+                        self.stack.len() + 1
+                    } else {
+                        0
+                    };
+
+                    self.on_stack[w] = true;
+                    self.stack.push(w);
+                    self.next.push(0);
+                    self.blen.push(k);
+                }
+
+                continue;
+            }
+
+            let _ = self.stack.pop();
+            let _ = self.next.pop();
+            let bl = self.blen.pop().unwrap_or(k);
+
+            self.on_stack[v] = false;
+
+            if let Some(last) = self.blen.last_mut() {
+                *last = (*last).min(bl);
+            }
+
+            if bl < k {
+                self.unblock(v, bl, k);
+            } else {
+                for j in 0..self.neighbors[v].len() {
+                    let w = self.neighbors[v][j];
+                    let _ = self.b[w].insert(v);
+                }
+            }
+        }
     }
 }
 
@@ -193,10 +393,16 @@ mod tests {
     use {
         super::*,
         crate::{
+            AdjacencyList,
             AdjacencyMap,
             Biclique,
             Circuit,
+            Complete,
             Cycle,
+            repr::adjacency_list::fixture::{
+                bang_jensen_196,
+                kattis_cantinaofbabel_1,
+            },
         },
     };
 
@@ -204,7 +410,7 @@ mod tests {
     fn biclique_2_2() {
         let digraph = AdjacencyMap::biclique(2, 2);
 
-        assert!(Johnson75::new(&digraph).circuits().eq(&[
+        assert!(Johnson75::new(&digraph).eq([
             vec![0, 2],
             vec![0, 2, 1, 3],
             vec![0, 3],
@@ -218,7 +424,7 @@ mod tests {
     fn biclique_2_3() {
         let digraph = AdjacencyMap::biclique(2, 3);
 
-        assert!(Johnson75::new(&digraph).circuits().eq(&[
+        assert!(Johnson75::new(&digraph).eq([
             vec![0, 2],
             vec![0, 2, 1, 3],
             vec![0, 2, 1, 4],
@@ -238,32 +444,28 @@ mod tests {
     fn circuit_3() {
         let digraph = AdjacencyMap::circuit(3);
 
-        assert!(Johnson75::new(&digraph).circuits().eq(&[vec![0, 1, 2]]));
+        assert!(Johnson75::new(&digraph).eq([vec![0, 1, 2]]));
     }
 
     #[test]
     fn circuit_4() {
         let digraph = AdjacencyMap::circuit(4);
 
-        assert!(Johnson75::new(&digraph).circuits().eq(&[vec![0, 1, 2, 3]]));
+        assert!(Johnson75::new(&digraph).eq([vec![0, 1, 2, 3]]));
     }
 
     #[test]
     fn circuit_5() {
         let digraph = AdjacencyMap::circuit(5);
 
-        assert!(
-            Johnson75::new(&digraph)
-                .circuits()
-                .eq(&[vec![0, 1, 2, 3, 4]])
-        );
+        assert!(Johnson75::new(&digraph).eq([vec![0, 1, 2, 3, 4]]));
     }
 
     #[test]
     fn cycle_3() {
         let digraph = AdjacencyMap::cycle(3);
 
-        assert!(Johnson75::new(&digraph).circuits().eq(&[
+        assert!(Johnson75::new(&digraph).eq([
             vec![0, 1],
             vec![0, 1, 2],
             vec![0, 2],
@@ -276,7 +478,7 @@ mod tests {
     fn cycle_4() {
         let digraph = AdjacencyMap::cycle(4);
 
-        assert!(Johnson75::new(&digraph).circuits().eq(&[
+        assert!(Johnson75::new(&digraph).eq([
             vec![0, 1],
             vec![0, 1, 2, 3],
             vec![0, 3],
@@ -290,7 +492,7 @@ mod tests {
     fn cycle_5() {
         let digraph = AdjacencyMap::cycle(5);
 
-        assert!(Johnson75::new(&digraph).circuits().eq(&[
+        assert!(Johnson75::new(&digraph).eq([
             vec![0, 1],
             vec![0, 1, 2, 3, 4],
             vec![0, 4],
@@ -300,4 +502,73 @@ mod tests {
             vec![3, 4]
         ]));
     }
+
+    #[test]
+    fn max_count_0() {
+        let digraph = AdjacencyMap::cycle(5);
+
+        assert_eq!(Johnson75::new(&digraph).max_count(0).next(), None);
+    }
+
+    #[test]
+    fn max_count_complete() {
+        let digraph = AdjacencyList::complete(8);
+
+        assert_eq!(Johnson75::new(&digraph).max_count(100).count(), 100);
+    }
+
+    #[test]
+    fn max_length_0() {
+        let digraph = AdjacencyMap::cycle(5);
+
+        assert_eq!(Johnson75::new(&digraph).max_length(0).next(), None);
+    }
+
+    #[test]
+    fn max_length_bang_jensen_196() {
+        assert!(
+            Johnson75::new(&bang_jensen_196())
+                .max_length(2)
+                .eq([vec![0, 1], vec![2, 3]])
+        );
+    }
+
+    #[test]
+    fn max_length_complete() {
+        let digraph = AdjacencyList::complete(5);
+
+        assert_eq!(Johnson75::new(&digraph).count(), 84);
+        assert_eq!(Johnson75::new(&digraph).max_length(2).count(), 10);
+        assert_eq!(Johnson75::new(&digraph).max_length(3).count(), 30);
+        assert_eq!(Johnson75::new(&digraph).max_length(4).count(), 60);
+        assert_eq!(Johnson75::new(&digraph).max_length(5).count(), 84);
+    }
+
+    #[test]
+    fn max_length_cycle() {
+        let digraph = AdjacencyMap::cycle(5);
+
+        assert!(Johnson75::new(&digraph).max_length(4).eq([
+            vec![0, 1],
+            vec![0, 4],
+            vec![1, 2],
+            vec![2, 3],
+            vec![3, 4]
+        ]));
+    }
+
+    #[test]
+    fn max_length_kattis_cantinaofbabel_1() {
+        assert!(Johnson75::new(&kattis_cantinaofbabel_1()).max_length(3).eq(
+            [
+                vec![0, 1],
+                vec![1, 2],
+                vec![3, 4],
+                vec![3, 7],
+                vec![5, 6],
+                vec![6, 10],
+                vec![9, 11]
+            ]
+        ));
+    }
 }
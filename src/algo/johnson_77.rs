@@ -0,0 +1,450 @@
+//! Johnson's all-pairs shortest-path algorithm.
+//!
+//! Johnson's algorithm[^1] finds the shortest paths between all vertex pairs
+//! in an arc-weighted digraph with negative weights. It finds vertex
+//! potentials with [`BellmanFordMoore`] from a virtual source, reweights the
+//! arcs to be non-negative, and runs [`DijkstraDist`] from every vertex.
+//!
+//! The time complexity is `O(v a log v)`, where `v` is the digraph's order and
+//! `a` is the digraph's size. On sparse digraphs, this is faster than the
+//! `O(v³)` of [`FloydWarshall`](crate::FloydWarshall).
+//!
+//! # Examples
+//!
+//! ## Shortest distances
+//!
+//! ```
+//! use graaf::{
+//!     AddArcWeighted,
+//!     AdjacencyListWeighted,
+//!     Empty,
+//!     Johnson77,
+//! };
+//!
+//! let mut digraph = AdjacencyListWeighted::empty(4);
+//!
+//! digraph.add_arc_weighted(0, 2, -2);
+//! digraph.add_arc_weighted(1, 0, 4);
+//! digraph.add_arc_weighted(1, 2, 3);
+//! digraph.add_arc_weighted(2, 3, 2);
+//! digraph.add_arc_weighted(3, 1, -1);
+//!
+//! let mut johnson = Johnson77::new(&digraph);
+//! let dist = johnson.distances().unwrap();
+//!
+//! assert!(dist[0..4].eq(&[0, -1, -2, 0]));
+//! assert!(dist[4..8].eq(&[4, 0, 2, 4]));
+//! assert!(dist[8..12].eq(&[5, 1, 0, 2]));
+//! assert!(dist[12..16].eq(&[3, -1, 1, 0]));
+//! ```
+//!
+//! ## Negative cycle
+//!
+//! There are no shortest paths through the negative cycle.
+//!
+//! ```
+//! use graaf::{
+//!     AddArcWeighted,
+//!     AdjacencyListWeighted,
+//!     Empty,
+//!     Johnson77,
+//! };
+//!
+//! let mut digraph = AdjacencyListWeighted::empty(4);
+//!
+//! digraph.add_arc_weighted(0, 1, 2);
+//! digraph.add_arc_weighted(1, 2, -1);
+//! digraph.add_arc_weighted(2, 3, -1);
+//! digraph.add_arc_weighted(3, 1, -1);
+//!
+//! let circuit = Johnson77::new(&digraph).distances().unwrap_err();
+//!
+//! assert!(circuit.circuit.eq(&[1, 2, 3]));
+//! assert_eq!(circuit.weight, -3);
+//! ```
+//!
+//! [^1]: Donald B. Johnson. 1977. Efficient algorithms for shortest paths in
+//!   sparse networks. J. ACM 24, 1 (January 1977), 1–13.
+//!   <https://doi.org/10.1145/321992.321993>
+#![doc(alias = "apsp")]
+
+use {
+    crate::{
+        ArcsWeighted,
+        BellmanFordMoore,
+        ContiguousOrder,
+        DijkstraDist,
+        DistanceMatrix,
+        NegativeCircuit,
+        Order,
+        OutNeighborsWeighted,
+    },
+    std::iter::once,
+};
+
+/// Johnson's all-pairs shortest-path algorithm.
+///
+/// Johnson's algorithm[^1] finds the shortest paths between all vertex pairs
+/// in an arc-weighted digraph with negative weights.
+///
+/// # Examples
+///
+/// ## Shortest distances
+///
+/// ```
+/// use graaf::{
+///     AddArcWeighted,
+///     AdjacencyListWeighted,
+///     Empty,
+///     Johnson77,
+/// };
+///
+/// let mut digraph = AdjacencyListWeighted::empty(4);
+///
+/// digraph.add_arc_weighted(0, 2, -2);
+/// digraph.add_arc_weighted(1, 0, 4);
+/// digraph.add_arc_weighted(1, 2, 3);
+/// digraph.add_arc_weighted(2, 3, 2);
+/// digraph.add_arc_weighted(3, 1, -1);
+///
+/// let mut johnson = Johnson77::new(&digraph);
+/// let dist = johnson.distances().unwrap();
+///
+/// assert!(dist[0..4].eq(&[0, -1, -2, 0]));
+/// assert!(dist[4..8].eq(&[4, 0, 2, 4]));
+/// assert!(dist[8..12].eq(&[5, 1, 0, 2]));
+/// assert!(dist[12..16].eq(&[3, -1, 1, 0]));
+/// ```
+///
+/// ## Negative cycle
+///
+/// There are no shortest paths through the negative cycle.
+///
+/// ```
+/// use graaf::{
+///     AddArcWeighted,
+///     AdjacencyListWeighted,
+///     Empty,
+///     Johnson77,
+/// };
+///
+/// let mut digraph = AdjacencyListWeighted::empty(4);
+///
+/// digraph.add_arc_weighted(0, 1, 2);
+/// digraph.add_arc_weighted(1, 2, -1);
+/// digraph.add_arc_weighted(2, 3, -1);
+/// digraph.add_arc_weighted(3, 1, -1);
+///
+/// let circuit = Johnson77::new(&digraph).distances().unwrap_err();
+///
+/// assert!(circuit.circuit.eq(&[1, 2, 3]));
+/// assert_eq!(circuit.weight, -3);
+/// ```
+///
+/// [^1]: Donald B. Johnson. 1977. Efficient algorithms for shortest paths in
+///   sparse networks. J. ACM 24, 1 (January 1977), 1–13.
+///   <https://doi.org/10.1145/321992.321993>
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Johnson77<'a, D> {
+    digraph: &'a D,
+    dist: DistanceMatrix<isize>,
+}
+
+/// The digraph with a virtual source that has an arc of weight `0` to every
+/// vertex.
+struct Augmented<'a, D>(&'a D);
+
+impl<D> Order for Augmented<'_, D>
+where
+    D: Order,
+{
+    fn order(&self) -> usize {
+        self.0.order() + 1
+    }
+}
+
+impl<D> ContiguousOrder for Augmented<'_, D> where D: ContiguousOrder {}
+
+impl<D> ArcsWeighted for Augmented<'_, D>
+where
+    D: ArcsWeighted<Weight = isize> + Order,
+{
+    type Weight = isize;
+
+    fn arcs_weighted(&self) -> impl Iterator<Item = (usize, usize, &isize)> {
+        let order = self.0.order();
+
+        self.0
+            .arcs_weighted()
+            .chain((0..order).map(move |v| (order, v, &0)))
+    }
+}
+
+/// The digraph with its arcs reweighted by the vertex potentials.
+struct Reweighted(Vec<Vec<(usize, usize)>>);
+
+impl Reweighted {
+    /// Reweight the arcs of `digraph` by the vertex potentials `h`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if a reduced weight is negative, i.e., if `h` isn't a feasible
+    /// potential.
+    fn new<D>(digraph: &D, h: &[isize]) -> Self
+    where
+        D: ArcsWeighted<Weight = isize> + Order,
+    {
+        let mut arcs = vec![Vec::new(); digraph.order()];
+
+        for (u, v, &w) in digraph.arcs_weighted() {
+            let w = usize::try_from(w + h[u] - h[v])
+                .expect("reduced weights are non-negative");
+
+            arcs[u].push((v, w));
+        }
+
+        Self(arcs)
+    }
+}
+
+impl Order for Reweighted {
+    fn order(&self) -> usize {
+        self.0.len()
+    }
+}
+
+impl OutNeighborsWeighted for Reweighted {
+    type Weight = usize;
+
+    fn out_neighbors_weighted(
+        &self,
+        u: usize,
+    ) -> impl Iterator<Item = (usize, &usize)> {
+        self.0[u].iter().map(|(v, w)| (*v, w))
+    }
+}
+
+impl<'a, D> Johnson77<'a, D> {
+    /// Construct a new instance of Johnson's all-pairs shortest-path
+    /// algorithm.
+    ///
+    /// # Arguments
+    ///
+    /// * `digraph`: The digraph.
+    #[must_use]
+    pub fn new(digraph: &'a D) -> Self
+    where
+        D: Order,
+    {
+        Self {
+            digraph,
+            dist: DistanceMatrix::<isize>::new(digraph.order(), isize::MAX),
+        }
+    }
+
+    /// Find the shortest paths between all vertex pairs in an arc-weighted
+    /// digraph with negative weights.
+    ///
+    /// # Returns
+    ///
+    /// The [`DistanceMatrix`] of the shortest distances.
+    ///
+    /// # Errors
+    ///
+    /// Returns the [`NegativeCircuit`] if the digraph contains a negative
+    /// circuit.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use graaf::{
+    ///     AddArcWeighted,
+    ///     AdjacencyListWeighted,
+    ///     Empty,
+    ///     Johnson77,
+    /// };
+    ///
+    /// let mut digraph = AdjacencyListWeighted::empty(4);
+    ///
+    /// digraph.add_arc_weighted(0, 2, -2);
+    /// digraph.add_arc_weighted(1, 0, 4);
+    /// digraph.add_arc_weighted(1, 2, 3);
+    /// digraph.add_arc_weighted(2, 3, 2);
+    /// digraph.add_arc_weighted(3, 1, -1);
+    ///
+    /// let mut johnson = Johnson77::new(&digraph);
+    /// let dist = johnson.distances().unwrap();
+    ///
+    /// assert!(dist[0..4].eq(&[0, -1, -2, 0]));
+    /// assert!(dist[4..8].eq(&[4, 0, 2, 4]));
+    /// assert!(dist[8..12].eq(&[5, 1, 0, 2]));
+    /// assert!(dist[12..16].eq(&[3, -1, 1, 0]));
+    /// ```
+    #[doc(alias = "apsp")]
+    pub fn distances(
+        &mut self,
+    ) -> Result<&DistanceMatrix<isize>, NegativeCircuit>
+    where
+        D: ArcsWeighted<Weight = isize> + ContiguousOrder,
+    {
+        let order = self.digraph.order();
+        let augmented = Augmented(self.digraph);
+        let mut bellman_ford_moore = BellmanFordMoore::new(&augmented, order);
+        let h = bellman_ford_moore.distances()?;
+        let reweighted = Reweighted::new(self.digraph, h);
+
+        for u in 0..order {
+            let dist = DijkstraDist::new(&reweighted, once(u)).distances();
+
+            for (v, d) in dist.into_iter().enumerate() {
+                self.dist[(u, v)] = if d == usize::MAX {
+                    isize::MAX
+                } else {
+                    (h[v] - h[u]).wrapping_add_unsigned(d)
+                };
+            }
+        }
+
+        Ok(&self.dist)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::*,
+        crate::{
+            AddArcWeighted,
+            AdjacencyListWeighted,
+            Empty,
+            FloydWarshall,
+            proptest_strategy::digraph_weighted,
+            repr::adjacency_list_weighted::fixture::{
+                bang_jensen_94_isize,
+                bang_jensen_96_isize,
+                bang_jensen_99,
+                kattis_bryr_1_isize,
+                kattis_bryr_2_isize,
+                kattis_bryr_3_isize,
+                kattis_crosscountry_isize,
+                kattis_shortestpath1_isize,
+                kattis_shortestpath3,
+            },
+        },
+        proptest::prelude::*,
+    };
+
+    fn assert_eq_floyd_warshall(digraph: &AdjacencyListWeighted<isize>) {
+        assert_eq!(
+            Johnson77::new(digraph).distances(),
+            FloydWarshall::new(digraph).distances()
+        );
+    }
+
+    proptest! {
+        #[test]
+        fn distances_eq_floyd_warshall(
+            order in 1..8_usize,
+            arcs in prop::collection::vec(
+                (0..8_usize, 0..8_usize, -3..10_isize),
+                0..24,
+            ),
+        ) {
+            let digraph = digraph_weighted(order, &arcs);
+            let mut floyd_warshall = FloydWarshall::new(&digraph);
+            let mut johnson = Johnson77::new(&digraph);
+
+            match (johnson.distances(), floyd_warshall.distances()) {
+                (Ok(a), Ok(b)) => assert_eq!(a, b),
+                (Err(_), Err(_)) => {}
+                (a, b) => panic!("{a:?} != {b:?}"),
+            }
+        }
+    }
+
+    #[test]
+    fn distances_doctest() {
+        let mut digraph = AdjacencyListWeighted::empty(4);
+
+        digraph.add_arc_weighted(0, 2, -2);
+        digraph.add_arc_weighted(1, 0, 4);
+        digraph.add_arc_weighted(1, 2, 3);
+        digraph.add_arc_weighted(2, 3, 2);
+        digraph.add_arc_weighted(3, 1, -1);
+
+        assert_eq_floyd_warshall(&digraph);
+    }
+
+    #[test]
+    fn distances_trivial() {
+        assert_eq_floyd_warshall(&AdjacencyListWeighted::<isize>::trivial());
+    }
+
+    #[test]
+    fn distances_bang_jensen_94() {
+        assert_eq_floyd_warshall(&bang_jensen_94_isize());
+    }
+
+    #[test]
+    fn distances_bang_jensen_96() {
+        assert_eq_floyd_warshall(&bang_jensen_96_isize());
+    }
+
+    #[test]
+    fn distances_bang_jensen_99() {
+        assert_eq_floyd_warshall(&bang_jensen_99());
+    }
+
+    #[test]
+    fn distances_kattis_bryr_1() {
+        assert_eq_floyd_warshall(&kattis_bryr_1_isize());
+    }
+
+    #[test]
+    fn distances_kattis_bryr_2() {
+        assert_eq_floyd_warshall(&kattis_bryr_2_isize());
+    }
+
+    #[test]
+    fn distances_kattis_bryr_3() {
+        assert_eq_floyd_warshall(&kattis_bryr_3_isize());
+    }
+
+    #[test]
+    fn distances_kattis_crosscountry() {
+        assert_eq_floyd_warshall(&kattis_crosscountry_isize());
+    }
+
+    #[test]
+    fn distances_kattis_shortestpath1() {
+        assert_eq_floyd_warshall(&kattis_shortestpath1_isize());
+    }
+
+    #[test]
+    fn distances_kattis_shortestpath3() {
+        assert_eq!(
+            Johnson77::new(&kattis_shortestpath3()).distances(),
+            Err(NegativeCircuit {
+                circuit: vec![1, 2],
+                weight: -1
+            })
+        );
+    }
+
+    #[test]
+    fn distances_unreachable_negative_circuit() {
+        let mut digraph = AdjacencyListWeighted::<isize>::empty(4);
+
+        digraph.add_arc_weighted(0, 1, 2);
+        digraph.add_arc_weighted(2, 3, -2);
+        digraph.add_arc_weighted(3, 2, -1);
+
+        assert_eq!(
+            Johnson77::new(&digraph).distances(),
+            Err(NegativeCircuit {
+                circuit: vec![2, 3],
+                weight: -3
+            })
+        );
+    }
+}
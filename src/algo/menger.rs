@@ -0,0 +1,477 @@
+//! Menger's disjoint paths.
+//!
+//! By Menger's theorem, the maximum number of arc-disjoint paths between two
+//! vertices equals the minimum number of arcs whose removal separates
+//! them.[^1] The algorithm finds a maximum unit-capacity flow with
+//! [`Dinic`]'s algorithm and decomposes it into paths.[^2] Internally
+//! vertex-disjoint paths are found by splitting each vertex into an
+//! in-vertex and an out-vertex joined by an arc of capacity one.
+//!
+//! The time complexity is `O(a min(√a, v^(2/3)) + k (v + a))`, where `k` is
+//! the number of paths, `v` is the digraph's order, and `a` is the digraph's
+//! size.
+//!
+//! # Examples
+//!
+//! ```
+//! use graaf::{
+//!     AddArc,
+//!     AdjacencyList,
+//!     Empty,
+//!     Menger,
+//! };
+//!
+//! let mut digraph = AdjacencyList::empty(5);
+//!
+//! digraph.add_arc(0, 1);
+//! digraph.add_arc(0, 2);
+//! digraph.add_arc(1, 2);
+//! digraph.add_arc(2, 3);
+//! digraph.add_arc(2, 4);
+//! digraph.add_arc(3, 4);
+//!
+//! let menger = Menger::new(&digraph);
+//!
+//! assert_eq!(
+//!     menger.arc_disjoint_paths(0, 4),
+//!     vec![vec![0, 1, 2, 3, 4], vec![0, 2, 4]]
+//! );
+//!
+//! assert_eq!(menger.vertex_disjoint_paths(0, 4), vec![vec![0, 2, 4]]);
+//! ```
+//!
+//! [`Dinic`]: crate::Dinic
+//!
+//! [^1]: Karl Menger. 1927. Zur allgemeinen Kurventheorie. Fundamenta
+//!   Mathematicae 10, 1 (1927), 96–115.
+//!   <https://doi.org/10.4064/fm-10-1-96-115>
+//!
+//! [^2]: Shimon Even and R. Endre Tarjan. 1975. Network Flow and Testing
+//!   Graph Connectivity. SIAM J. Comput. 4, 4 (1975), 507–518.
+//!   <https://doi.org/10.1137/0204043>
+
+use {
+    super::flow::{
+        Network,
+        dinic::augment,
+    },
+    crate::{
+        Arcs,
+        Order,
+    },
+};
+
+/// Menger's disjoint paths.
+///
+/// # Examples
+///
+/// ```
+/// use graaf::{
+///     AddArc,
+///     AdjacencyList,
+///     Empty,
+///     Menger,
+/// };
+///
+/// let mut digraph = AdjacencyList::empty(5);
+///
+/// digraph.add_arc(0, 1);
+/// digraph.add_arc(0, 2);
+/// digraph.add_arc(1, 2);
+/// digraph.add_arc(2, 3);
+/// digraph.add_arc(2, 4);
+/// digraph.add_arc(3, 4);
+///
+/// let menger = Menger::new(&digraph);
+///
+/// assert_eq!(
+///     menger.arc_disjoint_paths(0, 4),
+///     vec![vec![0, 1, 2, 3, 4], vec![0, 2, 4]]
+/// );
+///
+/// assert_eq!(menger.vertex_disjoint_paths(0, 4), vec![vec![0, 2, 4]]);
+/// ```
+#[derive(Clone, Debug)]
+pub struct Menger<'a, D> {
+    digraph: &'a D,
+}
+
+impl<'a, D> Menger<'a, D>
+where
+    D: Arcs + Order,
+{
+    /// Initialize Menger's disjoint paths.
+    ///
+    /// # Arguments
+    ///
+    /// * `digraph`: The digraph.
+    #[must_use]
+    pub const fn new(digraph: &'a D) -> Self {
+        Self { digraph }
+    }
+
+    fn assert_endpoints(&self, s: usize, t: usize) {
+        let order = self.digraph.order();
+
+        assert!(s < order, "s = {s} isn't in the digraph");
+        assert!(t < order, "t = {t} isn't in the digraph");
+        assert_ne!(s, t, "s and t are the same vertex");
+    }
+
+    /// Find a maximum set of arc-disjoint paths.
+    ///
+    /// # Arguments
+    ///
+    /// * `s`: The source vertex.
+    /// * `t`: The target vertex.
+    ///
+    /// # Returns
+    ///
+    /// Returns as many arc-disjoint paths from `s` to `t` as the digraph
+    /// contains.
+    ///
+    /// # Panics
+    ///
+    /// * Panics if `s` isn't in the digraph.
+    /// * Panics if `t` isn't in the digraph.
+    /// * Panics if `s` equals `t`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use graaf::{
+    ///     AddArc,
+    ///     AdjacencyList,
+    ///     Empty,
+    ///     Menger,
+    /// };
+    ///
+    /// let mut digraph = AdjacencyList::empty(4);
+    ///
+    /// digraph.add_arc(0, 1);
+    /// digraph.add_arc(0, 2);
+    /// digraph.add_arc(1, 3);
+    /// digraph.add_arc(2, 3);
+    ///
+    /// assert_eq!(
+    ///     Menger::new(&digraph).arc_disjoint_paths(0, 3),
+    ///     vec![vec![0, 1, 3], vec![0, 2, 3]]
+    /// );
+    /// ```
+    #[must_use]
+    pub fn arc_disjoint_paths(&self, s: usize, t: usize) -> Vec<Vec<usize>> {
+        let mut network = Network::from_arcs(
+            self.digraph.order(),
+            s,
+            t,
+            self.digraph.arcs().map(|(u, v)| (u, v, 1)),
+        );
+
+        let k = augment(&mut network, s, t);
+
+        network.into_paths(s, t, k)
+    }
+
+    /// Find a maximum set of internally vertex-disjoint paths.
+    ///
+    /// # Arguments
+    ///
+    /// * `s`: The source vertex.
+    /// * `t`: The target vertex.
+    ///
+    /// # Returns
+    ///
+    /// Returns as many internally vertex-disjoint paths from `s` to `t` as
+    /// the digraph contains.
+    ///
+    /// # Panics
+    ///
+    /// * Panics if `s` isn't in the digraph.
+    /// * Panics if `t` isn't in the digraph.
+    /// * Panics if `s` equals `t`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use graaf::{
+    ///     AddArc,
+    ///     AdjacencyList,
+    ///     Empty,
+    ///     Menger,
+    /// };
+    ///
+    /// let mut digraph = AdjacencyList::empty(4);
+    ///
+    /// digraph.add_arc(0, 1);
+    /// digraph.add_arc(0, 2);
+    /// digraph.add_arc(1, 3);
+    /// digraph.add_arc(2, 3);
+    /// digraph.add_arc(0, 3);
+    ///
+    /// assert_eq!(
+    ///     Menger::new(&digraph).vertex_disjoint_paths(0, 3),
+    ///     vec![vec![0, 1, 3], vec![0, 2, 3], vec![0, 3]]
+    /// );
+    /// ```
+    #[must_use]
+    pub fn vertex_disjoint_paths(
+        &self,
+        s: usize,
+        t: usize,
+    ) -> Vec<Vec<usize>> {
+        self.assert_endpoints(s, t);
+
+        // Vertex `v` splits into the in-vertex `v` and the out-vertex
+        // `v + order`.
+        let order = self.digraph.order();
+
+        let mut network = Network::from_arcs(
+            2 * order,
+            s + order,
+            t,
+            (0..order)
+                .map(|v| (v, v + order, 1))
+                .chain(self.digraph.arcs().map(|(u, v)| (u + order, v, 1))),
+        );
+
+        let k = augment(&mut network, s + order, t);
+
+        network
+            .into_paths(s + order, t, k)
+            .into_iter()
+            .map(|path| {
+                let mut path = path
+                    .into_iter()
+                    .filter(|&v| v < order)
+                    .collect::<Vec<_>>();
+
+                path.insert(0, s);
+
+                path
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::*,
+        crate::{
+            AdjacencyList,
+            HasArc,
+            proptest_strategy::digraph,
+            repr::adjacency_list::fixture::{
+                bang_jensen_34,
+                bang_jensen_94,
+                bang_jensen_196,
+                kattis_builddeps,
+            },
+        },
+        proptest::prelude::*,
+        std::collections::BTreeSet,
+    };
+
+    /// Count the arcs whose removal separates `t` from `s` with a minimum
+    /// number of arcs by trying every subset.
+    fn min_arc_cut(digraph: &AdjacencyList, s: usize, t: usize) -> usize {
+        let arcs = digraph.arcs().collect::<Vec<_>>();
+
+        (0_usize..1 << arcs.len())
+            .filter(|&mask| {
+                let mut visited = vec![false; digraph.order()];
+                let mut stack = vec![s];
+
+                visited[s] = true;
+
+                while let Some(u) = stack.pop() {
+                    for (i, &(x, v)) in arcs.iter().enumerate() {
+                        if x == u && mask & 1 << i == 0 && !visited[v] {
+                            visited[v] = true;
+                            stack.push(v);
+                        }
+                    }
+                }
+
+                !visited[t]
+            })
+            .map(usize::count_ones)
+            .min()
+            .unwrap() as usize
+    }
+
+    #[test]
+    fn arc_disjoint_paths_bang_jensen_196() {
+        let digraph = bang_jensen_196();
+
+        assert_eq!(Menger::new(&digraph).arc_disjoint_paths(0, 7).len(), 2);
+    }
+
+    #[test]
+    fn arc_disjoint_paths_bang_jensen_34() {
+        let digraph = bang_jensen_34();
+
+        assert_eq!(
+            Menger::new(&digraph).arc_disjoint_paths(2, 4),
+            vec![vec![2, 1, 0, 4], vec![2, 5, 4]]
+        );
+    }
+
+    #[test]
+    fn arc_disjoint_paths_bang_jensen_94() {
+        let digraph = bang_jensen_94();
+
+        assert_eq!(
+            Menger::new(&digraph).arc_disjoint_paths(0, 5),
+            vec![vec![0, 1, 3, 5], vec![0, 2, 5]]
+        );
+    }
+
+    #[test]
+    fn arc_disjoint_paths_kattis_builddeps() {
+        let digraph = kattis_builddeps();
+
+        assert!(Menger::new(&digraph).arc_disjoint_paths(1, 0).is_empty());
+    }
+
+    #[test]
+    fn arc_disjoint_paths_cut_vertex() {
+        let digraph = digraph(
+            7,
+            &[
+                (0, 1),
+                (0, 2),
+                (1, 3),
+                (2, 3),
+                (3, 4),
+                (3, 5),
+                (4, 6),
+                (5, 6),
+            ],
+        );
+
+        assert_eq!(
+            Menger::new(&digraph).arc_disjoint_paths(0, 6),
+            vec![vec![0, 1, 3, 4, 6], vec![0, 2, 3, 5, 6]]
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "s and t are the same vertex")]
+    fn arc_disjoint_paths_s_eq_t() {
+        let digraph = digraph(2, &[(0, 1)]);
+
+        drop(Menger::new(&digraph).arc_disjoint_paths(1, 1));
+    }
+
+    #[test]
+    #[should_panic(expected = "s = 2 isn't in the digraph")]
+    fn arc_disjoint_paths_s_out_of_bounds() {
+        let digraph = digraph(2, &[(0, 1)]);
+
+        drop(Menger::new(&digraph).arc_disjoint_paths(2, 1));
+    }
+
+    #[test]
+    #[should_panic(expected = "t = 2 isn't in the digraph")]
+    fn arc_disjoint_paths_t_out_of_bounds() {
+        let digraph = digraph(2, &[(0, 1)]);
+
+        drop(Menger::new(&digraph).arc_disjoint_paths(0, 2));
+    }
+
+    #[test]
+    fn vertex_disjoint_paths_bang_jensen_94() {
+        let digraph = bang_jensen_94();
+
+        assert_eq!(
+            Menger::new(&digraph).vertex_disjoint_paths(0, 5),
+            vec![vec![0, 1, 3, 5], vec![0, 2, 5]]
+        );
+    }
+
+    #[test]
+    fn vertex_disjoint_paths_cut_vertex() {
+        let digraph = digraph(
+            7,
+            &[
+                (0, 1),
+                (0, 2),
+                (1, 3),
+                (2, 3),
+                (3, 4),
+                (3, 5),
+                (4, 6),
+                (5, 6),
+            ],
+        );
+
+        assert_eq!(Menger::new(&digraph).vertex_disjoint_paths(0, 6).len(), 1);
+    }
+
+    #[test]
+    fn vertex_disjoint_paths_unreachable() {
+        let digraph = digraph(3, &[(0, 1), (2, 1)]);
+
+        assert!(Menger::new(&digraph).vertex_disjoint_paths(0, 2).is_empty());
+    }
+
+    proptest! {
+        #[test]
+        fn arc_disjoint_paths_len_eq_min_arc_cut(
+            order in 2..6_usize,
+            arcs in prop::collection::vec((0..6_usize, 0..6_usize), 0..12),
+        ) {
+            let digraph = digraph(order, &arcs);
+            let t = order - 1;
+
+            assert_eq!(
+                Menger::new(&digraph).arc_disjoint_paths(0, t).len(),
+                min_arc_cut(&digraph, 0, t)
+            );
+        }
+
+        #[test]
+        fn arc_disjoint_paths_valid(
+            order in 2..8_usize,
+            arcs in prop::collection::vec((0..8_usize, 0..8_usize), 0..24),
+        ) {
+            let digraph = digraph(order, &arcs);
+            let t = order - 1;
+            let mut used = BTreeSet::new();
+
+            for path in Menger::new(&digraph).arc_disjoint_paths(0, t) {
+                assert_eq!(path.first(), Some(&0));
+                assert_eq!(path.last(), Some(&t));
+
+                for arc in path.windows(2) {
+                    assert!(digraph.has_arc(arc[0], arc[1]));
+                    assert!(used.insert((arc[0], arc[1])));
+                }
+            }
+        }
+
+        #[test]
+        fn vertex_disjoint_paths_valid(
+            order in 2..8_usize,
+            arcs in prop::collection::vec((0..8_usize, 0..8_usize), 0..24),
+        ) {
+            let digraph = digraph(order, &arcs);
+            let t = order - 1;
+            let mut used = BTreeSet::new();
+
+            for path in Menger::new(&digraph).vertex_disjoint_paths(0, t) {
+                assert_eq!(path.first(), Some(&0));
+                assert_eq!(path.last(), Some(&t));
+
+                for arc in path.windows(2) {
+                    assert!(digraph.has_arc(arc[0], arc[1]));
+                }
+
+                for &v in &path[1..path.len() - 1] {
+                    assert!(used.insert(v));
+                }
+            }
+        }
+    }
+}
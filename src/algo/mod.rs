@@ -2,23 +2,57 @@
 //!
 //! Traverse and search digraphs.
 
+pub mod a_star;
 pub mod bellman_ford_moore;
 pub mod bfs;
 pub mod bfs_dist;
 pub mod bfs_pred;
+pub mod bidirectional_bfs;
+pub mod bidirectional_dijkstra;
+pub mod chu_liu_edmonds;
 pub mod dfs;
 pub mod dfs_dist;
 pub mod dfs_pred;
+pub mod dial_dist;
+pub mod dial_pred;
 pub mod dijkstra;
 pub mod dijkstra_dist;
 pub mod dijkstra_pred;
 pub mod distance_matrix;
+pub mod dominators;
+pub mod flow;
 pub mod floyd_warshall;
+pub mod hierholzer;
+pub mod hopcroft_karp;
+pub mod hopcroft_tarjan;
+pub mod hungarian;
+pub mod italiano;
 pub mod johnson_75;
+pub mod johnson_77;
+pub mod menger;
+pub mod path_matrix;
 pub mod predecessor_tree;
+pub mod spanning_forest;
+pub mod spfa;
+pub mod suurballe;
 pub mod tarjan;
+mod union_find;
+pub mod weak_components;
+pub mod yen;
+pub mod zero_one_bfs_dist;
+pub mod zero_one_bfs_pred;
 
 pub use {
     distance_matrix::DistanceMatrix,
+    flow::{
+        MaxFlow,
+        MinCostFlow,
+        MinCut,
+    },
+    path_matrix::PathMatrix,
     predecessor_tree::PredecessorTree,
+    spanning_forest::{
+        Asymmetric,
+        SpanningForest,
+    },
 };
@@ -0,0 +1,217 @@
+//! A path matrix.
+//!
+//! A [`PathMatrix`] contains the next vertex on a shortest path between each
+//! vertex pair in a digraph. It is the companion of a
+//! [`DistanceMatrix`](crate::DistanceMatrix).
+//!
+//! # Examples
+//!
+//! ```
+//! use graaf::{
+//!     AddArcWeighted,
+//!     AdjacencyListWeighted,
+//!     Empty,
+//!     FloydWarshall,
+//! };
+//!
+//! let mut digraph = AdjacencyListWeighted::<isize>::empty(4);
+//!
+//! digraph.add_arc_weighted(0, 2, -2);
+//! digraph.add_arc_weighted(1, 0, 4);
+//! digraph.add_arc_weighted(1, 2, 3);
+//! digraph.add_arc_weighted(2, 3, 2);
+//! digraph.add_arc_weighted(3, 1, -1);
+//!
+//! let mut floyd_warshall = FloydWarshall::new(&digraph);
+//! let paths = floyd_warshall.paths().unwrap();
+//!
+//! assert_eq!(paths.path(0, 1), Some(vec![0, 2, 3, 1]));
+//! assert_eq!(paths.path(1, 3), Some(vec![1, 0, 2, 3]));
+//! ```
+
+use std::ops::{
+    Index,
+    IndexMut,
+};
+
+/// A path matrix.
+///
+/// A [`PathMatrix`] contains the next vertex on a shortest path between each
+/// vertex pair in a digraph.
+///
+/// # Examples
+///
+/// ```
+/// use graaf::{
+///     AddArcWeighted,
+///     AdjacencyListWeighted,
+///     Empty,
+///     FloydWarshall,
+/// };
+///
+/// let mut digraph = AdjacencyListWeighted::<isize>::empty(4);
+///
+/// digraph.add_arc_weighted(0, 2, -2);
+/// digraph.add_arc_weighted(1, 0, 4);
+/// digraph.add_arc_weighted(1, 2, 3);
+/// digraph.add_arc_weighted(2, 3, 2);
+/// digraph.add_arc_weighted(3, 1, -1);
+///
+/// let mut floyd_warshall = FloydWarshall::new(&digraph);
+/// let paths = floyd_warshall.paths().unwrap();
+///
+/// assert_eq!(paths.path(0, 1), Some(vec![0, 2, 3, 1]));
+/// assert_eq!(paths.path(1, 3), Some(vec![1, 0, 2, 3]));
+/// ```
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[non_exhaustive]
+pub struct PathMatrix {
+    /// The next vertex on a shortest path between each vertex pair.
+    pub next: Vec<Option<usize>>,
+    /// The number of vertices.
+    pub order: usize,
+}
+
+impl PathMatrix {
+    /// Construct a new [`PathMatrix`].
+    ///
+    /// # Arguments
+    ///
+    /// * `order`: The number of vertices.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `order` is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use graaf::PathMatrix;
+    ///
+    /// let paths = PathMatrix::new(4);
+    ///
+    /// assert_eq!(paths[(0, 1)], None);
+    /// assert_eq!(paths.path(0, 1), None);
+    /// ```
+    #[must_use]
+    pub fn new(order: usize) -> Self {
+        assert!(order > 0, "a path matrix has at least one vertex");
+
+        let size = order
+            .checked_mul(order)
+            .expect("a matrix has at most `usize::MAX` elements");
+
+        Self {
+            next: vec![None; size],
+            order,
+        }
+    }
+
+    /// Return a shortest path between two vertices.
+    ///
+    /// # Arguments
+    ///
+    /// * `u`: The source vertex.
+    /// * `v`: The target vertex.
+    ///
+    /// # Returns
+    ///
+    /// The vertices on the path from `u` to `v`, including both. Returns
+    /// `None` if there is no path or the next vertices don't lead to `v`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `u` or `v` isn't in the matrix.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use graaf::{
+    ///     AddArcWeighted,
+    ///     AdjacencyListWeighted,
+    ///     Empty,
+    ///     FloydWarshall,
+    /// };
+    ///
+    /// let mut digraph = AdjacencyListWeighted::<isize>::empty(4);
+    ///
+    /// digraph.add_arc_weighted(0, 1, 1);
+    /// digraph.add_arc_weighted(0, 2, 5);
+    /// digraph.add_arc_weighted(1, 2, 1);
+    ///
+    /// let mut floyd_warshall = FloydWarshall::new(&digraph);
+    /// let paths = floyd_warshall.paths().unwrap();
+    ///
+    /// assert_eq!(paths.path(0, 0), Some(vec![0]));
+    /// assert_eq!(paths.path(0, 2), Some(vec![0, 1, 2]));
+    /// assert_eq!(paths.path(2, 0), None);
+    /// assert_eq!(paths.path(0, 3), None);
+    /// ```
+    #[must_use]
+    pub fn path(&self, u: usize, v: usize) -> Option<Vec<usize>> {
+        assert!(u < self.order, "u = {u} isn't in the matrix");
+        assert!(v < self.order, "v = {v} isn't in the matrix");
+
+        let mut path = vec![u];
+        let mut x = u;
+
+        // A shortest path visits each vertex at most once.
+        for _ in 0..self.order {
+            if x == v {
+                return Some(path);
+            }
+
+            x = self[(x, v)]?;
+            path.push(x);
+        }
+
+        None
+    }
+}
+
+impl Index<(usize, usize)> for PathMatrix {
+    type Output = Option<usize>;
+
+    fn index(&self, index: (usize, usize)) -> &Self::Output {
+        &self.next[index.0 * self.order + index.1]
+    }
+}
+
+impl IndexMut<(usize, usize)> for PathMatrix {
+    fn index_mut(&mut self, index: (usize, usize)) -> &mut Self::Output {
+        &mut self.next[index.0 * self.order + index.1]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn new() {
+        let paths = PathMatrix::new(2);
+
+        assert!(paths.next.eq(&[None; 4]));
+        assert_eq!(paths.order, 2);
+    }
+
+    #[test]
+    #[should_panic(expected = "a path matrix has at least one vertex")]
+    fn new_0() {
+        drop(PathMatrix::new(0));
+    }
+
+    #[test]
+    fn path_cycle() {
+        let mut paths = PathMatrix::new(2);
+
+        paths[(0, 1)] = Some(0);
+
+        assert_eq!(paths.path(0, 1), None);
+    }
+
+    #[test]
+    fn path_trivial() {
+        assert_eq!(PathMatrix::new(1).path(0, 0), Some(vec![0]));
+    }
+}
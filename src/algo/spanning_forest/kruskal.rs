@@ -0,0 +1,290 @@
+//! Kruskal's algorithm.
+//!
+//! Kruskal's algorithm finds a minimum spanning forest of a symmetric
+//! arc-weighted digraph.[^1] It scans the edges in order of increasing
+//! weight and adds each edge that joins two different trees, tracking the
+//! trees with a disjoint-set forest.
+//!
+//! The time complexity is `O(a log a)`, where `a` is the digraph's size.
+//!
+//! # Examples
+//!
+//! ```
+//! use graaf::{
+//!     AddArcWeighted,
+//!     AdjacencyListWeighted,
+//!     Empty,
+//!     Kruskal,
+//! };
+//!
+//! let mut digraph = AdjacencyListWeighted::<usize>::empty(4);
+//!
+//! for (u, v, w) in [(0, 1, 4), (0, 2, 1), (1, 2, 2), (1, 3, 5), (2, 3, 8)] {
+//!     digraph.add_arc_weighted(u, v, w);
+//!     digraph.add_arc_weighted(v, u, w);
+//! }
+//!
+//! let forest = Kruskal::new(&digraph).min_spanning_forest().unwrap();
+//!
+//! assert_eq!(forest.edges, vec![(0, 2, 1), (1, 2, 2), (1, 3, 5)]);
+//! assert_eq!(forest.weight, 8);
+//! ```
+//!
+//! [^1]: Joseph B. Kruskal. 1956. On the shortest spanning subtree of a graph
+//!   and the traveling salesman problem. Proc. Amer. Math. Soc. 7, 1 (1956),
+//!   48–50. <https://doi.org/10.1090/S0002-9939-1956-0078686-7>
+
+use {
+    super::{
+        Asymmetric,
+        SpanningForest,
+        check,
+    },
+    crate::{
+        ArcWeight,
+        ArcsWeighted,
+        Order,
+        algo::union_find::UnionFind,
+    },
+    std::ops::Add,
+};
+
+/// Kruskal's algorithm.
+///
+/// # Examples
+///
+/// ```
+/// use graaf::{
+///     AddArcWeighted,
+///     AdjacencyListWeighted,
+///     Empty,
+///     Kruskal,
+/// };
+///
+/// let mut digraph = AdjacencyListWeighted::<usize>::empty(4);
+///
+/// for (u, v, w) in [(0, 1, 4), (0, 2, 1), (1, 2, 2), (1, 3, 5), (2, 3, 8)] {
+///     digraph.add_arc_weighted(u, v, w);
+///     digraph.add_arc_weighted(v, u, w);
+/// }
+///
+/// let forest = Kruskal::new(&digraph).min_spanning_forest().unwrap();
+///
+/// assert_eq!(forest.edges, vec![(0, 2, 1), (1, 2, 2), (1, 3, 5)]);
+/// assert_eq!(forest.weight, 8);
+/// ```
+#[derive(Clone, Debug)]
+pub struct Kruskal<'a, D> {
+    digraph: &'a D,
+}
+
+impl<'a, D> Kruskal<'a, D> {
+    /// Initialize Kruskal's algorithm.
+    ///
+    /// # Arguments
+    ///
+    /// * `digraph`: The symmetric digraph.
+    #[must_use]
+    pub const fn new(digraph: &'a D) -> Self {
+        Self { digraph }
+    }
+
+    /// Find a minimum spanning forest.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Asymmetric`] if an arc has no reverse arc with the same
+    /// weight.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use graaf::{
+    ///     AddArcWeighted,
+    ///     AdjacencyListWeighted,
+    ///     Empty,
+    ///     Kruskal,
+    /// };
+    ///
+    /// let mut digraph = AdjacencyListWeighted::<isize>::empty(5);
+    ///
+    /// for (u, v, w) in [(0, 1, -2), (0, 2, 3), (1, 2, 1), (3, 4, 6)] {
+    ///     digraph.add_arc_weighted(u, v, w);
+    ///     digraph.add_arc_weighted(v, u, w);
+    /// }
+    ///
+    /// let forest = Kruskal::new(&digraph).min_spanning_forest().unwrap();
+    ///
+    /// assert_eq!(forest.edges, vec![(0, 1, -2), (1, 2, 1), (3, 4, 6)]);
+    /// assert_eq!(forest.weight, 5);
+    ///
+    /// digraph.add_arc_weighted(4, 3, 5);
+    ///
+    /// let asymmetric = Kruskal::new(&digraph).min_spanning_forest().unwrap_err();
+    ///
+    /// assert_eq!(asymmetric.arc, (3, 4));
+    /// ```
+    pub fn min_spanning_forest<W>(
+        &self,
+    ) -> Result<SpanningForest<W>, Asymmetric>
+    where
+        D: ArcWeight<usize, Weight = W> + ArcsWeighted<Weight = W> + Order,
+        W: Add<Output = W> + Copy + Default + Ord,
+    {
+        check(self.digraph)?;
+
+        let mut edges = self
+            .digraph
+            .arcs_weighted()
+            .filter(|&(u, v, _)| u < v)
+            .map(|(u, v, &w)| (u, v, w))
+            .collect::<Vec<_>>();
+
+        edges.sort_by_key(|&(_, _, w)| w);
+
+        let mut union_find = UnionFind::new(self.digraph.order());
+        let mut forest = Vec::new();
+        let mut weight = W::default();
+
+        for (u, v, w) in edges {
+            if union_find.union(u, v) {
+                forest.push((u, v, w));
+                weight = weight + w;
+            }
+        }
+
+        forest.sort_unstable_by_key(|&(u, v, _)| (u, v));
+
+        Ok(SpanningForest {
+            edges: forest,
+            weight,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::*,
+        crate::{
+            AdjacencyListWeighted,
+            Prim,
+            proptest_strategy::digraph_weighted,
+            repr::adjacency_list_weighted::fixture::{
+                kattis_bryr_1_usize,
+                kattis_bryr_2_usize,
+                kattis_bryr_3_usize,
+                kattis_crosscountry_usize,
+            },
+        },
+        proptest::prelude::*,
+    };
+
+    /// Build a symmetric digraph with an arc in each direction of each edge.
+    fn graph(
+        order: usize,
+        edges: &[(usize, usize, isize)],
+    ) -> AdjacencyListWeighted<isize> {
+        let arcs = edges
+            .iter()
+            .flat_map(|&(u, v, w)| [(u, v, w), (v, u, w)])
+            .collect::<Vec<_>>();
+
+        digraph_weighted(order, &arcs)
+    }
+
+    #[test]
+    fn min_spanning_forest_kattis_bryr_1() {
+        let digraph = kattis_bryr_1_usize();
+        let forest = Kruskal::new(&digraph).min_spanning_forest().unwrap();
+
+        assert_eq!(forest.edges, vec![(0, 1, 1), (0, 2, 1)]);
+        assert_eq!(forest.weight, 2);
+    }
+
+    #[test]
+    fn min_spanning_forest_kattis_bryr_2() {
+        let digraph = kattis_bryr_2_usize();
+        let forest = Kruskal::new(&digraph).min_spanning_forest().unwrap();
+
+        assert_eq!(forest.edges.len(), 5);
+        assert_eq!(forest.weight, 5);
+    }
+
+    #[test]
+    fn min_spanning_forest_kattis_bryr_3() {
+        let digraph = kattis_bryr_3_usize();
+
+        assert_eq!(
+            Kruskal::new(&digraph).min_spanning_forest(),
+            Err(Asymmetric { arc: (9, 2) })
+        );
+    }
+
+    #[test]
+    fn min_spanning_forest_kattis_crosscountry() {
+        let digraph = kattis_crosscountry_usize();
+
+        assert_eq!(
+            Kruskal::new(&digraph).min_spanning_forest(),
+            Err(Asymmetric { arc: (0, 1) })
+        );
+    }
+
+    #[test]
+    fn min_spanning_forest_isolated_vertices() {
+        let digraph = graph(4, &[(1, 2, 3)]);
+        let forest = Kruskal::new(&digraph).min_spanning_forest().unwrap();
+
+        assert_eq!(forest.edges, vec![(1, 2, 3)]);
+        assert_eq!(forest.weight, 3);
+    }
+
+    proptest! {
+        #[test]
+        fn min_spanning_forest_eq_prim(
+            order in 1..8_usize,
+            edges in prop::collection::vec(
+                (0..8_usize, 0..8_usize, -10..10_isize),
+                0..24,
+            ),
+        ) {
+            let digraph = graph(order, &edges);
+            let kruskal = Kruskal::new(&digraph).min_spanning_forest().unwrap();
+            let prim = Prim::new(&digraph).min_spanning_forest().unwrap();
+
+            assert_eq!(kruskal.weight, prim.weight);
+            assert_eq!(kruskal.edges.len(), prim.edges.len());
+        }
+
+        #[test]
+        fn min_spanning_forest_is_forest(
+            order in 1..8_usize,
+            edges in prop::collection::vec(
+                (0..8_usize, 0..8_usize, -10..10_isize),
+                0..24,
+            ),
+        ) {
+            let digraph = graph(order, &edges);
+            let forest = Kruskal::new(&digraph).min_spanning_forest().unwrap();
+            let mut union_find = UnionFind::new(order);
+            let mut components = order;
+
+            for (u, v, _) in digraph.arcs_weighted() {
+                if union_find.union(u, v) {
+                    components -= 1;
+                }
+            }
+
+            let mut union_find = UnionFind::new(order);
+
+            for &(u, v, w) in &forest.edges {
+                assert!(u < v);
+                assert_eq!(digraph.arc_weight(u, v), Some(&w));
+                assert!(union_find.union(u, v));
+            }
+
+            assert_eq!(forest.edges.len(), order - components);
+        }
+    }
+}
@@ -0,0 +1,155 @@
+//! Minimum spanning forests.
+//!
+//! A symmetric arc-weighted digraph stores each edge `{u, v}` of an
+//! undirected graph as the arcs `(u, v)` and `(v, u)` with the same weight. A
+//! minimum spanning forest of such a digraph is a set of edges that connects
+//! each weakly connected component with the smallest possible total weight.
+//!
+//! - [`Kruskal`](kruskal::Kruskal) adds the lightest edges that don't close a
+//!   cycle.
+//! - [`Prim`](prim::Prim) grows a tree from each component along its lightest
+//!   outgoing edge.
+//!
+//! Both return a [`SpanningForest`] or, if an arc has no reverse arc with the
+//! same weight, an [`Asymmetric`] error.
+//!
+//! # Examples
+//!
+//! ```
+//! use graaf::{
+//!     AddArcWeighted,
+//!     AdjacencyListWeighted,
+//!     Empty,
+//!     Kruskal,
+//!     Prim,
+//! };
+//!
+//! let mut digraph = AdjacencyListWeighted::<usize>::empty(4);
+//!
+//! for (u, v, w) in [(0, 1, 4), (0, 2, 1), (1, 2, 2), (1, 3, 5), (2, 3, 8)] {
+//!     digraph.add_arc_weighted(u, v, w);
+//!     digraph.add_arc_weighted(v, u, w);
+//! }
+//!
+//! let forest = Kruskal::new(&digraph).min_spanning_forest().unwrap();
+//!
+//! assert_eq!(forest.edges, vec![(0, 2, 1), (1, 2, 2), (1, 3, 5)]);
+//! assert_eq!(forest.weight, 8);
+//! assert_eq!(Prim::new(&digraph).min_spanning_forest(), Ok(forest));
+//! ```
+
+pub mod kruskal;
+pub mod prim;
+
+use crate::{
+    ArcWeight,
+    ArcsWeighted,
+};
+
+/// A minimum spanning forest.
+///
+/// # Examples
+///
+/// ```
+/// use graaf::{
+///     AddArcWeighted,
+///     AdjacencyListWeighted,
+///     Empty,
+///     Prim,
+/// };
+///
+/// let mut digraph = AdjacencyListWeighted::<usize>::empty(5);
+///
+/// for (u, v, w) in [(0, 1, 2), (1, 2, 3), (0, 2, 1), (3, 4, 7)] {
+///     digraph.add_arc_weighted(u, v, w);
+///     digraph.add_arc_weighted(v, u, w);
+/// }
+///
+/// let forest = Prim::new(&digraph).min_spanning_forest().unwrap();
+///
+/// assert_eq!(forest.edges, vec![(0, 1, 2), (0, 2, 1), (3, 4, 7)]);
+/// assert_eq!(forest.weight, 10);
+/// ```
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[non_exhaustive]
+pub struct SpanningForest<W> {
+    /// The edges `(u, v, w)` of the forest with `u < v`, ordered by `(u, v)`.
+    pub edges: Vec<(usize, usize, W)>,
+    /// The total weight of the forest.
+    pub weight: W,
+}
+
+/// An arc without a reverse arc of the same weight.
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[non_exhaustive]
+pub struct Asymmetric {
+    /// An arc whose reverse arc is missing or has a different weight.
+    pub arc: (usize, usize),
+}
+
+/// Check that every arc has a reverse arc with the same weight.
+fn check<D, W>(digraph: &D) -> Result<(), Asymmetric>
+where
+    D: ArcWeight<usize, Weight = W> + ArcsWeighted<Weight = W>,
+    W: PartialEq,
+{
+    digraph
+        .arcs_weighted()
+        .find(|&(u, v, w)| digraph.arc_weight(v, u) != Some(w))
+        .map_or(Ok(()), |(u, v, _)| Err(Asymmetric { arc: (u, v) }))
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::*,
+        crate::{
+            AddArcWeighted,
+            AdjacencyListWeighted,
+            Empty,
+            repr::adjacency_list_weighted::fixture::{
+                kattis_bryr_3_usize,
+                kattis_crosscountry_usize,
+            },
+        },
+    };
+
+    #[test]
+    fn check_different_weights() {
+        let mut digraph = AdjacencyListWeighted::<usize>::empty(3);
+
+        digraph.add_arc_weighted(0, 1, 1);
+        digraph.add_arc_weighted(1, 0, 1);
+        digraph.add_arc_weighted(1, 2, 2);
+        digraph.add_arc_weighted(2, 1, 3);
+
+        assert_eq!(check(&digraph), Err(Asymmetric { arc: (1, 2) }));
+    }
+
+    #[test]
+    fn check_kattis_bryr_3() {
+        assert_eq!(
+            check(&kattis_bryr_3_usize()),
+            Err(Asymmetric { arc: (9, 2) })
+        );
+    }
+
+    #[test]
+    fn check_kattis_crosscountry() {
+        assert_eq!(
+            check(&kattis_crosscountry_usize()),
+            Err(Asymmetric { arc: (0, 1) })
+        );
+    }
+
+    #[test]
+    fn check_missing_reverse() {
+        let mut digraph = AdjacencyListWeighted::<usize>::empty(3);
+
+        digraph.add_arc_weighted(0, 1, 1);
+        digraph.add_arc_weighted(1, 0, 1);
+        digraph.add_arc_weighted(2, 0, 1);
+
+        assert_eq!(check(&digraph), Err(Asymmetric { arc: (2, 0) }));
+    }
+}
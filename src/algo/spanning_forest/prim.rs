@@ -0,0 +1,256 @@
+//! Prim's algorithm.
+//!
+//! Prim's algorithm finds a minimum spanning forest of a symmetric
+//! arc-weighted digraph.[^1][^2] It grows a tree from the smallest vertex of
+//! each weakly connected component, repeatedly adding the lightest edge that
+//! leaves the tree.
+//!
+//! The time complexity is `O(a log a)`, where `a` is the digraph's size.
+//!
+//! # Examples
+//!
+//! ```
+//! use graaf::{
+//!     AddArcWeighted,
+//!     AdjacencyListWeighted,
+//!     Empty,
+//!     Prim,
+//! };
+//!
+//! let mut digraph = AdjacencyListWeighted::<usize>::empty(4);
+//!
+//! for (u, v, w) in [(0, 1, 4), (0, 2, 1), (1, 2, 2), (1, 3, 5), (2, 3, 8)] {
+//!     digraph.add_arc_weighted(u, v, w);
+//!     digraph.add_arc_weighted(v, u, w);
+//! }
+//!
+//! let forest = Prim::new(&digraph).min_spanning_forest().unwrap();
+//!
+//! assert_eq!(forest.edges, vec![(0, 2, 1), (1, 2, 2), (1, 3, 5)]);
+//! assert_eq!(forest.weight, 8);
+//! ```
+//!
+//! [^1]: Vojtěch Jarník. 1930. O jistém problému minimálním. Práce moravské
+//!   přírodovědecké společnosti 6, 4 (1930), 57–63.
+//!
+//! [^2]: R. C. Prim. 1957. Shortest connection networks and some
+//!   generalizations. The Bell System Technical Journal 36, 6 (1957),
+//!   1389–1401. <https://doi.org/10.1002/j.1538-7305.1957.tb01515.x>
+
+use {
+    super::{
+        Asymmetric,
+        SpanningForest,
+        check,
+    },
+    crate::{
+        ArcWeight,
+        ArcsWeighted,
+        Order,
+        OutNeighborsWeighted,
+    },
+    core::cmp::Reverse,
+    std::{
+        collections::BinaryHeap,
+        ops::Add,
+    },
+};
+
+/// Prim's algorithm.
+///
+/// # Examples
+///
+/// ```
+/// use graaf::{
+///     AddArcWeighted,
+///     AdjacencyListWeighted,
+///     Empty,
+///     Prim,
+/// };
+///
+/// let mut digraph = AdjacencyListWeighted::<usize>::empty(4);
+///
+/// for (u, v, w) in [(0, 1, 4), (0, 2, 1), (1, 2, 2), (1, 3, 5), (2, 3, 8)] {
+///     digraph.add_arc_weighted(u, v, w);
+///     digraph.add_arc_weighted(v, u, w);
+/// }
+///
+/// let forest = Prim::new(&digraph).min_spanning_forest().unwrap();
+///
+/// assert_eq!(forest.edges, vec![(0, 2, 1), (1, 2, 2), (1, 3, 5)]);
+/// assert_eq!(forest.weight, 8);
+/// ```
+#[derive(Clone, Debug)]
+pub struct Prim<'a, D> {
+    digraph: &'a D,
+}
+
+impl<'a, D> Prim<'a, D> {
+    /// Initialize Prim's algorithm.
+    ///
+    /// # Arguments
+    ///
+    /// * `digraph`: The symmetric digraph.
+    #[must_use]
+    pub const fn new(digraph: &'a D) -> Self {
+        Self { digraph }
+    }
+
+    /// Find a minimum spanning forest.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Asymmetric`] if an arc has no reverse arc with the same
+    /// weight.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use graaf::{
+    ///     AddArcWeighted,
+    ///     AdjacencyListWeighted,
+    ///     Empty,
+    ///     Prim,
+    /// };
+    ///
+    /// let mut digraph = AdjacencyListWeighted::<isize>::empty(5);
+    ///
+    /// for (u, v, w) in [(0, 1, -2), (0, 2, 3), (1, 2, 1), (3, 4, 6)] {
+    ///     digraph.add_arc_weighted(u, v, w);
+    ///     digraph.add_arc_weighted(v, u, w);
+    /// }
+    ///
+    /// let forest = Prim::new(&digraph).min_spanning_forest().unwrap();
+    ///
+    /// assert_eq!(forest.edges, vec![(0, 1, -2), (1, 2, 1), (3, 4, 6)]);
+    /// assert_eq!(forest.weight, 5);
+    ///
+    /// digraph.add_arc_weighted(4, 3, 5);
+    ///
+    /// let asymmetric = Prim::new(&digraph).min_spanning_forest().unwrap_err();
+    ///
+    /// assert_eq!(asymmetric.arc, (3, 4));
+    /// ```
+    pub fn min_spanning_forest<W>(
+        &self,
+    ) -> Result<SpanningForest<W>, Asymmetric>
+    where
+        D: ArcWeight<usize, Weight = W>
+            + ArcsWeighted<Weight = W>
+            + Order
+            + OutNeighborsWeighted<Weight = W>,
+        W: Add<Output = W> + Copy + Default + Ord,
+    {
+        check(self.digraph)?;
+
+        let order = self.digraph.order();
+        let mut visited = vec![false; order];
+        let mut heap = BinaryHeap::new();
+        let mut forest = Vec::new();
+        let mut weight = W::default();
+
+        for s in 0..order {
+            if visited[s] {
+                continue;
+            }
+
+            visited[s] = true;
+
+            for (v, &w) in self.digraph.out_neighbors_weighted(s) {
+                heap.push(Reverse((w, v, s)));
+            }
+
+            while let Some(Reverse((w, v, u))) = heap.pop() {
+                if visited[v] {
+                    continue;
+                }
+
+                visited[v] = true;
+                forest.push((u.min(v), u.max(v), w));
+                weight = weight + w;
+
+                for (x, &w) in self.digraph.out_neighbors_weighted(v) {
+                    if !visited[x] {
+                        heap.push(Reverse((w, x, v)));
+                    }
+                }
+            }
+        }
+
+        forest.sort_unstable_by_key(|&(u, v, _)| (u, v));
+
+        Ok(SpanningForest {
+            edges: forest,
+            weight,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::*,
+        crate::{
+            AddArcWeighted,
+            AdjacencyListWeighted,
+            Empty,
+            repr::adjacency_list_weighted::fixture::{
+                kattis_bryr_1_usize,
+                kattis_bryr_2_usize,
+                kattis_bryr_3_usize,
+                kattis_crosscountry_usize,
+            },
+        },
+    };
+
+    #[test]
+    fn min_spanning_forest_kattis_bryr_1() {
+        let digraph = kattis_bryr_1_usize();
+        let forest = Prim::new(&digraph).min_spanning_forest().unwrap();
+
+        assert_eq!(forest.edges, vec![(0, 1, 1), (0, 2, 1)]);
+        assert_eq!(forest.weight, 2);
+    }
+
+    #[test]
+    fn min_spanning_forest_kattis_bryr_2() {
+        let digraph = kattis_bryr_2_usize();
+        let forest = Prim::new(&digraph).min_spanning_forest().unwrap();
+
+        assert_eq!(forest.edges.len(), 5);
+        assert_eq!(forest.weight, 5);
+    }
+
+    #[test]
+    fn min_spanning_forest_kattis_bryr_3() {
+        let digraph = kattis_bryr_3_usize();
+
+        assert_eq!(
+            Prim::new(&digraph).min_spanning_forest(),
+            Err(Asymmetric { arc: (9, 2) })
+        );
+    }
+
+    #[test]
+    fn min_spanning_forest_kattis_crosscountry() {
+        let digraph = kattis_crosscountry_usize();
+
+        assert_eq!(
+            Prim::new(&digraph).min_spanning_forest(),
+            Err(Asymmetric { arc: (0, 1) })
+        );
+    }
+
+    #[test]
+    fn min_spanning_forest_isolated_vertices() {
+        let mut digraph = AdjacencyListWeighted::<usize>::empty(4);
+
+        digraph.add_arc_weighted(1, 2, 3);
+        digraph.add_arc_weighted(2, 1, 3);
+
+        let forest = Prim::new(&digraph).min_spanning_forest().unwrap();
+
+        assert_eq!(forest.edges, vec![(1, 2, 3)]);
+        assert_eq!(forest.weight, 3);
+    }
+}
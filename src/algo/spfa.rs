@@ -0,0 +1,746 @@
+//! The shortest path faster algorithm.
+//!
+//! Find the shortest distances from a source vertex to all other vertices in
+//! an arc-weighted digraph with negative weights.
+//!
+//! Moore's queue-based variant[^1] of the Bellman-Ford-Moore algorithm only
+//! scans the out-neighbors of vertices whose distance changed. Tarjan's
+//! subtree disassembly[^2] removes the shortest-path subtree of a vertex whose
+//! distance improves, which skips stale work and detects a negative circuit as
+//! soon as one closes in the shortest-path tree[^3].
+//!
+//! The time complexity is `O(v a)`, where `v` is the digraph's order and `a`
+//! is the digraph's size, but the algorithm is much faster than
+//! [`BellmanFordMoore`](crate::BellmanFordMoore) on sparse digraphs.
+//!
+//! # Examples
+//!
+//! ## Shortest distances
+//!
+//! The shortest path from vertex `0` to `4` is red. The dashed arcs represent
+//! the other shortest distances.
+//!
+//! ![A digraph and the shortest distances between the source vertex and the other vertices](https://raw.githubusercontent.com/bsdrks/graaf-images/main/out/bellman_ford_moore_1-0.87.4.svg?)
+//!
+//! ```
+//! use graaf::{
+//!     AddArcWeighted,
+//!     AdjacencyListWeighted,
+//!     Empty,
+//!     Spfa,
+//! };
+//!
+//! let mut digraph = AdjacencyListWeighted::<isize>::empty(6);
+//!
+//! digraph.add_arc_weighted(0, 1, 8);
+//! digraph.add_arc_weighted(0, 2, 4);
+//! digraph.add_arc_weighted(1, 2, -5);
+//! digraph.add_arc_weighted(2, 3, -2);
+//! digraph.add_arc_weighted(2, 4, 4);
+//! digraph.add_arc_weighted(3, 5, -2);
+//! digraph.add_arc_weighted(4, 3, 10);
+//! digraph.add_arc_weighted(4, 5, 9);
+//! digraph.add_arc_weighted(5, 3, 5);
+//! digraph.add_arc_weighted(5, 4, -3);
+//!
+//! let mut spfa = Spfa::new(&digraph, 0);
+//! let dist = spfa.distances();
+//!
+//! assert!(dist.unwrap().eq(&[0, 8, 3, 1, -4, -1]));
+//! ```
+//!
+//! ## Negative cycle
+//!
+//! There is no shortest path between vertices `0` and the other vertices due
+//! to the negative cycle.
+//!
+//! ![A digraph with a negative cycle](https://raw.githubusercontent.com/bsdrks/graaf-images/main/out/bellman_ford_moore_2-0.87.4.svg)
+//!
+//! ```
+//! use graaf::{
+//!     AddArcWeighted,
+//!     AdjacencyListWeighted,
+//!     Empty,
+//!     Spfa,
+//! };
+//!
+//! let mut digraph = AdjacencyListWeighted::empty(3);
+//!
+//! digraph.add_arc_weighted(0, 1, -2);
+//! digraph.add_arc_weighted(1, 2, -1);
+//! digraph.add_arc_weighted(2, 0, -1);
+//!
+//! let circuit = Spfa::new(&digraph, 0).distances().unwrap_err();
+//!
+//! assert!(circuit.circuit.eq(&[0, 1, 2]));
+//! assert_eq!(circuit.weight, -4);
+//! ```
+//!
+//! [^1]: Edward F. Moore. 1959. The shortest path through a maze. In
+//!   Proceedings of the International Symposium on the Theory of Switching,
+//!   285–292.
+//!
+//! [^2]: Robert Endre Tarjan. 1981. Shortest paths. Technical Report. AT&T
+//!   Bell Laboratories, Murray Hill, NJ.
+//!
+//! [^3]: Boris V. Cherkassky and Andrew V. Goldberg. 1999. Negative-cycle
+//!   detection algorithms. Mathematical Programming 85, 2 (1999), 277–311.
+//!   <https://doi.org/10.1007/s101070050058>
+#![doc(alias = "moore")]
+
+use {
+    crate::{
+        NegativeCircuit,
+        Order,
+        OutNeighborsWeighted,
+        PredecessorTree,
+    },
+    std::collections::VecDeque,
+};
+
+/// Find the shortest distances from a source vertex to all other vertices in
+/// an arc-weighted digraph with negative weights.
+///
+/// Moore's queue-based variant[^1] of the Bellman-Ford-Moore algorithm with
+/// Tarjan's subtree disassembly[^2].
+///
+/// # Arguments
+///
+/// * `digraph`: The digraph.
+/// * `s`: The source vertex.
+///
+/// # Returns
+///
+/// The distances from the source vertex to all other vertices. Returns the
+/// [`NegativeCircuit`] if the digraph contains a negative circuit reachable
+/// from the source vertex.
+///
+/// # Examples
+///
+/// ## Shortest distances
+///
+/// The shortest path from vertex `0` to `4` is red. The dashed arcs represent
+/// the other shortest distances.
+///
+/// ![A digraph and the shortest distances between the source vertex and the other vertices](https://raw.githubusercontent.com/bsdrks/graaf-images/main/out/bellman_ford_moore_1-0.87.4.svg?)
+///
+/// ```
+/// use graaf::{
+///     AddArcWeighted,
+///     AdjacencyListWeighted,
+///     Empty,
+///     Spfa,
+/// };
+///
+/// let mut digraph = AdjacencyListWeighted::<isize>::empty(6);
+///
+/// digraph.add_arc_weighted(0, 1, 8);
+/// digraph.add_arc_weighted(0, 2, 4);
+/// digraph.add_arc_weighted(1, 2, -5);
+/// digraph.add_arc_weighted(2, 3, -2);
+/// digraph.add_arc_weighted(2, 4, 4);
+/// digraph.add_arc_weighted(3, 5, -2);
+/// digraph.add_arc_weighted(4, 3, 10);
+/// digraph.add_arc_weighted(4, 5, 9);
+/// digraph.add_arc_weighted(5, 3, 5);
+/// digraph.add_arc_weighted(5, 4, -3);
+///
+/// let mut spfa = Spfa::new(&digraph, 0);
+/// let dist = spfa.distances();
+///
+/// assert!(dist.unwrap().eq(&[0, 8, 3, 1, -4, -1]));
+/// ```
+///
+/// ## Negative cycle
+///
+/// There is no shortest path between vertices `0` and the other vertices due
+/// to the negative cycle.
+///
+/// ![A digraph with a negative cycle](https://raw.githubusercontent.com/bsdrks/graaf-images/main/out/bellman_ford_moore_2-0.87.4.svg)
+///
+/// ```
+/// use graaf::{
+///     AddArcWeighted,
+///     AdjacencyListWeighted,
+///     Empty,
+///     Spfa,
+/// };
+///
+/// let mut digraph = AdjacencyListWeighted::empty(3);
+///
+/// digraph.add_arc_weighted(0, 1, -2);
+/// digraph.add_arc_weighted(1, 2, -1);
+/// digraph.add_arc_weighted(2, 0, -1);
+///
+/// let circuit = Spfa::new(&digraph, 0).distances().unwrap_err();
+///
+/// assert!(circuit.circuit.eq(&[0, 1, 2]));
+/// assert_eq!(circuit.weight, -4);
+/// ```
+///
+/// [^1]: Edward F. Moore. 1959. The shortest path through a maze. In
+///   Proceedings of the International Symposium on the Theory of Switching,
+///   285–292.
+///
+/// [^2]: Robert Endre Tarjan. 1981. Shortest paths. Technical Report. AT&T
+///   Bell Laboratories, Murray Hill, NJ.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Spfa<'a, D> {
+    digraph: &'a D,
+    source: usize,
+    dist: Vec<isize>,
+    pred: Vec<Option<usize>>,
+}
+
+/// The shortest-path tree as a preorder thread.
+///
+/// The tree's vertices form a circular doubly linked list in preorder, so a
+/// vertex's subtree is the run of vertices after it with a greater depth.
+struct Thread {
+    next: Vec<usize>,
+    prev: Vec<usize>,
+    depth: Vec<usize>,
+    in_tree: Vec<bool>,
+}
+
+impl Thread {
+    fn new(order: usize, s: usize) -> Self {
+        let mut in_tree = vec![false; order];
+
+        in_tree[s] = true;
+
+        Self {
+            next: (0..order).collect(),
+            prev: (0..order).collect(),
+            depth: vec![0; order],
+            in_tree,
+        }
+    }
+
+    /// Insert `v` as the first child of `u`.
+    fn insert(&mut self, u: usize, v: usize) {
+        let w = self.next[u];
+
+        self.next[u] = v;
+        self.prev[v] = u;
+        self.next[v] = w;
+        self.prev[w] = v;
+        self.depth[v] = self.depth[u] + 1;
+        self.in_tree[v] = true;
+    }
+
+    /// Remove the subtree of `v` unless it contains `u`. Returns whether it
+    /// contains `u`.
+    fn disassemble(&mut self, u: usize, v: usize, scan: &mut [bool]) -> bool {
+        let depth = self.depth[v];
+        let mut x = v;
+
+        loop {
+            if x == u {
+                return true;
+            }
+
+            self.in_tree[x] = false;
+            scan[x] = false;
+            x = self.next[x];
+
+            if x == v || self.depth[x] <= depth {
+                break;
+            }
+        }
+
+        let p = self.prev[v];
+
+        self.next[p] = x;
+        self.prev[x] = p;
+
+        false
+    }
+}
+
+impl<'a, D> Spfa<'a, D> {
+    /// Construct a new shortest path faster algorithm.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the source vertex is not in the digraph.
+    ///
+    /// # Arguments
+    ///
+    /// * `digraph`: The digraph.
+    /// * `s`: The source vertex.
+    #[must_use]
+    pub fn new(digraph: &'a D, s: usize) -> Self
+    where
+        D: Order,
+    {
+        let order = digraph.order();
+
+        assert!(s < order, "The source vertex is not in the digraph.");
+
+        Self {
+            digraph,
+            source: s,
+            dist: vec![isize::MAX; order],
+            pred: vec![None; order],
+        }
+    }
+
+    /// Scan the vertices whose distance changed until the distances
+    /// converge or a negative circuit closes in the shortest-path tree.
+    fn search(&mut self) -> Result<(), NegativeCircuit>
+    where
+        D: Order + OutNeighborsWeighted<Weight = isize>,
+    {
+        let order = self.digraph.order();
+        let s = self.source;
+        let mut thread = Thread::new(order, s);
+        let mut queue = VecDeque::from([s]);
+        let mut queued = vec![false; order];
+        let mut scan = vec![false; order];
+
+        self.dist.fill(isize::MAX);
+        self.pred.fill(None);
+        self.dist[s] = 0;
+        queued[s] = true;
+        scan[s] = true;
+
+        while let Some(u) = queue.pop_front() {
+            queued[u] = false;
+
+            // A disassembled vertex waits until its distance improves.
+            if !scan[u] {
+                continue;
+            }
+
+            scan[u] = false;
+
+            let dist_u = self.dist[u];
+
+            for (v, &w) in self.digraph.out_neighbors_weighted(u) {
+                let dist_v = dist_u + w;
+
+                if dist_v >= self.dist[v] {
+                    continue;
+                }
+
+                if thread.in_tree[v] && thread.disassemble(u, v, &mut scan) {
+                    return Err(self.circuit(u, v, dist_v - self.dist[v]));
+                }
+
+                self.dist[v] = dist_v;
+                self.pred[v] = Some(u);
+                thread.insert(u, v);
+                scan[v] = true;
+
+                if !queued[v] {
+                    queued[v] = true;
+                    queue.push_back(v);
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    /// The circuit that the arc `(u, v)` closes in the shortest-path tree.
+    fn circuit(&self, u: usize, v: usize, weight: isize) -> NegativeCircuit {
+        let mut circuit = vec![u];
+        let mut x = u;
+
+        while x != v {
+            let Some(p) = self.pred[x] else {
+                unreachable!("the tree path from `v` to `u` is broken");
+            };
+
+            circuit.push(p);
+            x = p;
+        }
+
+        circuit.reverse();
+
+        if let Some(i) = (0..circuit.len()).min_by_key(|&i| circuit[i]) {
+            circuit.rotate_left(i);
+        }
+
+        NegativeCircuit { circuit, weight }
+    }
+
+    /// Find the shortest distances from a source vertex to all other vertices
+    /// in an arc-weighted digraph with negative weights.
+    ///
+    /// # Returns
+    ///
+    /// The distances from the source vertex to all other vertices.
+    ///
+    /// # Errors
+    ///
+    /// Returns the [`NegativeCircuit`] if the digraph contains a negative
+    /// circuit reachable from the source vertex.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use graaf::{
+    ///     AddArcWeighted,
+    ///     AdjacencyListWeighted,
+    ///     Empty,
+    ///     Spfa,
+    /// };
+    ///
+    /// let mut digraph = AdjacencyListWeighted::<isize>::empty(6);
+    ///
+    /// digraph.add_arc_weighted(0, 1, 8);
+    /// digraph.add_arc_weighted(0, 2, 4);
+    /// digraph.add_arc_weighted(1, 2, -5);
+    /// digraph.add_arc_weighted(2, 3, -2);
+    /// digraph.add_arc_weighted(2, 4, 4);
+    /// digraph.add_arc_weighted(3, 5, -2);
+    /// digraph.add_arc_weighted(4, 3, 10);
+    /// digraph.add_arc_weighted(4, 5, 9);
+    /// digraph.add_arc_weighted(5, 3, 5);
+    /// digraph.add_arc_weighted(5, 4, -3);
+    ///
+    /// let mut spfa = Spfa::new(&digraph, 0);
+    ///
+    /// assert!(spfa.distances().unwrap().eq(&[0, 8, 3, 1, -4, -1]));
+    /// ```
+    pub fn distances(&mut self) -> Result<&[isize], NegativeCircuit>
+    where
+        D: Order + OutNeighborsWeighted<Weight = isize>,
+    {
+        self.search()?;
+
+        Ok(&self.dist)
+    }
+
+    /// Find the predecessors on the shortest paths from the source vertex.
+    ///
+    /// # Returns
+    ///
+    /// The [`PredecessorTree`] of the shortest paths from the source vertex.
+    ///
+    /// # Errors
+    ///
+    /// Returns the [`NegativeCircuit`] if the digraph contains a negative
+    /// circuit reachable from the source vertex.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use graaf::{
+    ///     AddArcWeighted,
+    ///     AdjacencyListWeighted,
+    ///     Empty,
+    ///     Spfa,
+    /// };
+    ///
+    /// let mut digraph = AdjacencyListWeighted::<isize>::empty(6);
+    ///
+    /// digraph.add_arc_weighted(0, 1, 8);
+    /// digraph.add_arc_weighted(0, 2, 4);
+    /// digraph.add_arc_weighted(1, 2, -5);
+    /// digraph.add_arc_weighted(2, 3, -2);
+    /// digraph.add_arc_weighted(2, 4, 4);
+    /// digraph.add_arc_weighted(3, 5, -2);
+    /// digraph.add_arc_weighted(4, 3, 10);
+    /// digraph.add_arc_weighted(4, 5, 9);
+    /// digraph.add_arc_weighted(5, 3, 5);
+    /// digraph.add_arc_weighted(5, 4, -3);
+    ///
+    /// let pred = Spfa::new(&digraph, 0).predecessors().unwrap();
+    ///
+    /// assert_eq!(pred.search(4, 0), Some(vec![4, 5, 3, 2, 1, 0]));
+    /// ```
+    pub fn predecessors(&mut self) -> Result<PredecessorTree, NegativeCircuit>
+    where
+        D: Order + OutNeighborsWeighted<Weight = isize>,
+    {
+        self.search()?;
+
+        Ok(PredecessorTree::from(self.pred.clone()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::*,
+        crate::{
+            AddArcWeighted,
+            AdjacencyListWeighted,
+            BellmanFordMoore,
+            Empty,
+            proptest_strategy::digraph_weighted,
+            repr::adjacency_list_weighted::fixture::{
+                bang_jensen_94_isize,
+                bang_jensen_96_isize,
+                bang_jensen_99,
+                kattis_bryr_1_isize,
+                kattis_bryr_2_isize,
+                kattis_bryr_3_isize,
+                kattis_crosscountry_isize,
+                kattis_shortestpath1_isize,
+                kattis_shortestpath3,
+            },
+        },
+        proptest::prelude::*,
+    };
+
+    proptest! {
+        #[test]
+        fn distances_eq_bellman_ford_moore(
+            order in 1..8_usize,
+            arcs in prop::collection::vec(
+                (0..8_usize, 0..8_usize, -4..10_isize),
+                0..24,
+            ),
+        ) {
+            let digraph = digraph_weighted(order, &arcs);
+            let mut spfa = Spfa::new(&digraph, 0);
+            let mut bellman_ford_moore = BellmanFordMoore::new(&digraph, 0);
+
+            match (spfa.distances(), bellman_ford_moore.distances()) {
+                (Ok(a), Ok(b)) => assert_eq!(a, b),
+                (Err(circuit), Err(_)) => {
+                    let len = circuit.circuit.len();
+                    let weight = (0..len)
+                        .map(|i| {
+                            let u = circuit.circuit[i];
+                            let v = circuit.circuit[(i + 1) % len];
+
+                            digraph
+                                .out_neighbors_weighted(u)
+                                .find_map(|(x, &w)| (x == v).then_some(w))
+                                .unwrap()
+                        })
+                        .sum::<isize>();
+
+                    assert_eq!(weight, circuit.weight);
+                    assert!(weight < 0);
+                    assert_eq!(
+                        circuit.circuit.iter().min(),
+                        circuit.circuit.first()
+                    );
+                }
+                (a, b) => panic!("{a:?} != {b:?}"),
+            }
+        }
+
+        #[test]
+        fn predecessors_are_tight(
+            order in 1..8_usize,
+            arcs in prop::collection::vec(
+                (0..8_usize, 0..8_usize, -4..10_isize),
+                0..24,
+            ),
+        ) {
+            let digraph = digraph_weighted(order, &arcs);
+            let mut spfa = Spfa::new(&digraph, 0);
+
+            if let Ok(pred) = spfa.predecessors() {
+                let dist = spfa.distances().unwrap();
+
+                for (v, u) in pred.into_iter().enumerate() {
+                    if let Some(u) = u {
+                        assert!(
+                            digraph
+                                .out_neighbors_weighted(u)
+                                .any(|(x, &w)| x == v
+                                    && dist[u] + w == dist[v])
+                        );
+                    } else {
+                        assert!(v == 0 || dist[v] == isize::MAX);
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn distances_trivial() {
+        assert!(
+            Spfa::new(&AdjacencyListWeighted::<isize>::trivial(), 0)
+                .distances()
+                .unwrap()
+                .eq(&[0])
+        );
+    }
+
+    #[test]
+    fn distances_bang_jensen_94() {
+        assert!(
+            Spfa::new(&bang_jensen_94_isize(), 0)
+                .distances()
+                .unwrap()
+                .eq(&[0, 1, 1, 2, 2, 2, 3])
+        );
+    }
+
+    #[test]
+    fn distances_bang_jensen_96() {
+        assert!(
+            Spfa::new(&bang_jensen_96_isize(), 0)
+                .distances()
+                .unwrap()
+                .eq(&[0, 5, 3, 6, 4, 7])
+        );
+    }
+
+    #[test]
+    fn distances_bang_jensen_99() {
+        assert!(Spfa::new(&bang_jensen_99(), 1).distances().unwrap().eq(&[
+            isize::MAX,
+            0,
+            -5,
+            -7,
+            -12,
+            -9
+        ]));
+    }
+
+    #[test]
+    fn distances_kattis_bryr_1() {
+        assert!(
+            Spfa::new(&kattis_bryr_1_isize(), 0)
+                .distances()
+                .unwrap()
+                .eq(&[0, 1, 1])
+        );
+    }
+
+    #[test]
+    fn distances_kattis_bryr_2() {
+        assert!(
+            Spfa::new(&kattis_bryr_2_isize(), 0)
+                .distances()
+                .unwrap()
+                .eq(&[0, 1, 2, 1, 2, 3])
+        );
+    }
+
+    #[test]
+    fn distances_kattis_bryr_3() {
+        assert!(
+            Spfa::new(&kattis_bryr_3_isize(), 0)
+                .distances()
+                .unwrap()
+                .eq(&[0, 0, 1, 0, 0, 0, 1, 0, 0, 1])
+        );
+    }
+
+    #[test]
+    fn distances_kattis_crosscountry() {
+        assert!(
+            Spfa::new(&kattis_crosscountry_isize(), 0)
+                .distances()
+                .unwrap()
+                .eq(&[0, 1, 3, 10])
+        );
+    }
+
+    #[test]
+    fn distances_kattis_shortestpath1() {
+        assert!(
+            Spfa::new(&kattis_shortestpath1_isize(), 0)
+                .distances()
+                .unwrap()
+                .eq(&[0, 2, 4, isize::MAX])
+        );
+    }
+
+    #[test]
+    fn distances_kattis_shortestpath3() {
+        assert_eq!(
+            Spfa::new(&kattis_shortestpath3(), 0).distances(),
+            Err(NegativeCircuit {
+                circuit: vec![1, 2],
+                weight: -1
+            })
+        );
+    }
+
+    #[test]
+    fn distances_repeated() {
+        let digraph = kattis_shortestpath3();
+        let mut spfa = Spfa::new(&digraph, 0);
+
+        assert!(spfa.distances().is_err());
+        assert!(spfa.distances().is_err());
+    }
+
+    #[test]
+    fn distances_unreachable_negative_circuit() {
+        let mut digraph = AdjacencyListWeighted::<isize>::empty(4);
+
+        digraph.add_arc_weighted(0, 1, 2);
+        digraph.add_arc_weighted(2, 3, -2);
+        digraph.add_arc_weighted(3, 2, -1);
+
+        assert!(Spfa::new(&digraph, 0).distances().unwrap().eq(&[
+            0,
+            2,
+            isize::MAX,
+            isize::MAX
+        ]));
+    }
+
+    #[test]
+    fn negative_circuit() {
+        let mut digraph = AdjacencyListWeighted::<isize>::empty(3);
+
+        digraph.add_arc_weighted(0, 1, -2);
+        digraph.add_arc_weighted(1, 2, -1);
+        digraph.add_arc_weighted(2, 0, -1);
+
+        assert_eq!(
+            Spfa::new(&digraph, 0).distances(),
+            Err(NegativeCircuit {
+                circuit: vec![0, 1, 2],
+                weight: -4
+            })
+        );
+    }
+
+    #[test]
+    fn negative_circuit_behind_path() {
+        let mut digraph = AdjacencyListWeighted::<isize>::empty(6);
+
+        digraph.add_arc_weighted(0, 1, 1);
+        digraph.add_arc_weighted(1, 2, 1);
+        digraph.add_arc_weighted(2, 3, 1);
+        digraph.add_arc_weighted(3, 4, -3);
+        digraph.add_arc_weighted(4, 5, 1);
+        digraph.add_arc_weighted(5, 3, 1);
+
+        assert_eq!(
+            Spfa::new(&digraph, 0).predecessors(),
+            Err(NegativeCircuit {
+                circuit: vec![3, 4, 5],
+                weight: -1
+            })
+        );
+    }
+
+    #[test]
+    fn predecessors_bang_jensen_99() {
+        assert!(
+            Spfa::new(&bang_jensen_99(), 0)
+                .predecessors()
+                .unwrap()
+                .into_iter()
+                .eq([None, Some(0), Some(0), Some(2), Some(5), Some(3)])
+        );
+    }
+
+    #[test]
+    fn predecessors_kattis_shortestpath1() {
+        assert!(
+            Spfa::new(&kattis_shortestpath1_isize(), 0)
+                .predecessors()
+                .unwrap()
+                .into_iter()
+                .eq([None, Some(0), Some(1), None])
+        );
+    }
+}
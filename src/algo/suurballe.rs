@@ -0,0 +1,666 @@
+//! Suurballe and Tarjan's algorithm.
+//!
+//! Suurballe and Tarjan's algorithm finds `k` arc-disjoint or vertex-disjoint
+//! paths between two vertices in an arc-weighted digraph with minimum total
+//! weight.[^1][^2] Like [`SuccessiveShortestPaths`], it augments a
+//! unit-capacity flow along `k` shortest paths in the residual digraph.
+//! Vertex potentials keep the reduced arc weights non-negative, so each
+//! shortest path is found with Dijkstra's algorithm. Vertex-disjoint paths
+//! are found by splitting each vertex into an in-vertex and an out-vertex
+//! joined by an arc of capacity one.
+//!
+//! The time complexity is `O(k (v + a) log v)`, where `k` is the number of
+//! paths, `v` is the digraph's order, and `a` is the digraph's size.
+//!
+//! # Examples
+//!
+//! The shortest path from `0` to `3` is `0 → 1 → 2 → 3`, but no second path
+//! avoids its arcs. The two paths with minimum total weight are
+//! `0 → 1 → 3` and `0 → 2 → 3`.
+//!
+//! ```
+//! use graaf::{
+//!     AddArcWeighted,
+//!     AdjacencyListWeighted,
+//!     Empty,
+//!     Suurballe,
+//! };
+//!
+//! let mut digraph = AdjacencyListWeighted::<usize>::empty(4);
+//!
+//! digraph.add_arc_weighted(0, 1, 1);
+//! digraph.add_arc_weighted(0, 2, 3);
+//! digraph.add_arc_weighted(1, 2, 1);
+//! digraph.add_arc_weighted(1, 3, 3);
+//! digraph.add_arc_weighted(2, 3, 1);
+//!
+//! let suurballe = Suurballe::new(&digraph);
+//!
+//! assert_eq!(
+//!     suurballe.arc_disjoint_paths(0, 3, 2),
+//!     Some(vec![vec![0, 1, 3], vec![0, 2, 3]])
+//! );
+//!
+//! assert_eq!(suurballe.arc_disjoint_paths(0, 3, 3), None);
+//! ```
+//!
+//! [`SuccessiveShortestPaths`]: crate::SuccessiveShortestPaths
+//!
+//! [^1]: J. W. Suurballe. 1974. Disjoint paths in a network. Networks 4, 2
+//!   (1974), 125–145. <https://doi.org/10.1002/net.3230040204>
+//!
+//! [^2]: J. W. Suurballe and R. E. Tarjan. 1984. A quick method for finding
+//!   shortest pairs of disjoint paths. Networks 14, 2 (1984), 325–336.
+//!   <https://doi.org/10.1002/net.3230140209>
+
+use {
+    super::flow::{
+        Network,
+        successive_shortest_paths::augment,
+    },
+    crate::{
+        ArcsWeighted,
+        Order,
+    },
+};
+
+/// Find `k` paths with minimum total weight through unit-capacity arcs.
+fn min_weight_paths(
+    order: usize,
+    s: usize,
+    t: usize,
+    k: usize,
+    arcs: &[(usize, usize, usize)],
+) -> Option<Vec<Vec<usize>>> {
+    let mut network = Network::from_arcs(
+        order,
+        s,
+        t,
+        arcs.iter().map(|&(u, v, _)| (u, v, 1)),
+    );
+
+    let costs = arcs
+        .iter()
+        .filter(|&&(u, v, _)| u != v)
+        .flat_map(|&(_, _, w)| {
+            let w = isize::try_from(w).expect("the weight fits in isize");
+
+            [w, -w]
+        })
+        .collect::<Vec<_>>();
+
+    let mut potentials = vec![0; order];
+
+    (augment(&mut network, &costs, &mut potentials, s, t, k) == k)
+        .then(|| network.into_paths(s, t, k))
+}
+
+/// Suurballe and Tarjan's algorithm.
+///
+/// # Examples
+///
+/// The shortest path from `0` to `3` is `0 → 1 → 2 → 3`, but no second path
+/// avoids its arcs. The two paths with minimum total weight are
+/// `0 → 1 → 3` and `0 → 2 → 3`.
+///
+/// ```
+/// use graaf::{
+///     AddArcWeighted,
+///     AdjacencyListWeighted,
+///     Empty,
+///     Suurballe,
+/// };
+///
+/// let mut digraph = AdjacencyListWeighted::<usize>::empty(4);
+///
+/// digraph.add_arc_weighted(0, 1, 1);
+/// digraph.add_arc_weighted(0, 2, 3);
+/// digraph.add_arc_weighted(1, 2, 1);
+/// digraph.add_arc_weighted(1, 3, 3);
+/// digraph.add_arc_weighted(2, 3, 1);
+///
+/// let suurballe = Suurballe::new(&digraph);
+///
+/// assert_eq!(
+///     suurballe.arc_disjoint_paths(0, 3, 2),
+///     Some(vec![vec![0, 1, 3], vec![0, 2, 3]])
+/// );
+///
+/// assert_eq!(suurballe.arc_disjoint_paths(0, 3, 3), None);
+/// ```
+#[derive(Clone, Debug)]
+pub struct Suurballe<'a, D> {
+    digraph: &'a D,
+}
+
+impl<'a, D> Suurballe<'a, D>
+where
+    D: ArcsWeighted<Weight = usize> + Order,
+{
+    /// Initialize Suurballe and Tarjan's algorithm.
+    ///
+    /// # Arguments
+    ///
+    /// * `digraph`: The digraph.
+    #[must_use]
+    pub const fn new(digraph: &'a D) -> Self {
+        Self { digraph }
+    }
+
+    fn assert_endpoints(&self, s: usize, t: usize) {
+        let order = self.digraph.order();
+
+        assert!(s < order, "s = {s} isn't in the digraph");
+        assert!(t < order, "t = {t} isn't in the digraph");
+        assert_ne!(s, t, "s and t are the same vertex");
+    }
+
+    /// Find `k` arc-disjoint paths with minimum total weight.
+    ///
+    /// # Arguments
+    ///
+    /// * `s`: The source vertex.
+    /// * `t`: The target vertex.
+    /// * `k`: The number of paths.
+    ///
+    /// # Returns
+    ///
+    /// If the digraph contains `k` arc-disjoint paths from `s` to `t`, the
+    /// function returns `k` such paths with minimum total weight. Otherwise,
+    /// it returns `None`.
+    ///
+    /// # Panics
+    ///
+    /// * Panics if `s` isn't in the digraph.
+    /// * Panics if `t` isn't in the digraph.
+    /// * Panics if `s` equals `t`.
+    /// * Panics if an arc weight exceeds `isize::MAX`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use graaf::{
+    ///     AddArcWeighted,
+    ///     AdjacencyListWeighted,
+    ///     Empty,
+    ///     Suurballe,
+    /// };
+    ///
+    /// let mut digraph = AdjacencyListWeighted::<usize>::empty(5);
+    ///
+    /// digraph.add_arc_weighted(0, 1, 1);
+    /// digraph.add_arc_weighted(0, 2, 1);
+    /// digraph.add_arc_weighted(1, 2, 1);
+    /// digraph.add_arc_weighted(2, 3, 1);
+    /// digraph.add_arc_weighted(2, 4, 1);
+    /// digraph.add_arc_weighted(3, 4, 1);
+    ///
+    /// assert_eq!(
+    ///     Suurballe::new(&digraph).arc_disjoint_paths(0, 4, 2),
+    ///     Some(vec![vec![0, 1, 2, 3, 4], vec![0, 2, 4]])
+    /// );
+    /// ```
+    #[must_use]
+    pub fn arc_disjoint_paths(
+        &self,
+        s: usize,
+        t: usize,
+        k: usize,
+    ) -> Option<Vec<Vec<usize>>> {
+        let arcs = self
+            .digraph
+            .arcs_weighted()
+            .map(|(u, v, &w)| (u, v, w))
+            .collect::<Vec<_>>();
+
+        min_weight_paths(self.digraph.order(), s, t, k, &arcs)
+    }
+
+    /// Find `k` internally vertex-disjoint paths with minimum total weight.
+    ///
+    /// # Arguments
+    ///
+    /// * `s`: The source vertex.
+    /// * `t`: The target vertex.
+    /// * `k`: The number of paths.
+    ///
+    /// # Returns
+    ///
+    /// If the digraph contains `k` internally vertex-disjoint paths from `s`
+    /// to `t`, the function returns `k` such paths with minimum total weight.
+    /// Otherwise, it returns `None`.
+    ///
+    /// # Panics
+    ///
+    /// * Panics if `s` isn't in the digraph.
+    /// * Panics if `t` isn't in the digraph.
+    /// * Panics if `s` equals `t`.
+    /// * Panics if an arc weight exceeds `isize::MAX`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use graaf::{
+    ///     AddArcWeighted,
+    ///     AdjacencyListWeighted,
+    ///     Empty,
+    ///     Suurballe,
+    /// };
+    ///
+    /// let mut digraph = AdjacencyListWeighted::<usize>::empty(5);
+    ///
+    /// digraph.add_arc_weighted(0, 1, 1);
+    /// digraph.add_arc_weighted(0, 2, 1);
+    /// digraph.add_arc_weighted(1, 2, 1);
+    /// digraph.add_arc_weighted(2, 3, 1);
+    /// digraph.add_arc_weighted(2, 4, 1);
+    /// digraph.add_arc_weighted(3, 4, 1);
+    ///
+    /// let suurballe = Suurballe::new(&digraph);
+    ///
+    /// assert_eq!(
+    ///     suurballe.vertex_disjoint_paths(0, 4, 1),
+    ///     Some(vec![vec![0, 2, 4]])
+    /// );
+    ///
+    /// assert_eq!(suurballe.vertex_disjoint_paths(0, 4, 2), None);
+    /// ```
+    #[must_use]
+    pub fn vertex_disjoint_paths(
+        &self,
+        s: usize,
+        t: usize,
+        k: usize,
+    ) -> Option<Vec<Vec<usize>>> {
+        self.assert_endpoints(s, t);
+
+        // Vertex `v` splits into the in-vertex `v` and the out-vertex
+        // `v + order`.
+        let order = self.digraph.order();
+
+        let arcs = (0..order)
+            .map(|v| (v, v + order, 0))
+            .chain(
+                self.digraph
+                    .arcs_weighted()
+                    .map(|(u, v, &w)| (u + order, v, w)),
+            )
+            .collect::<Vec<_>>();
+
+        let paths = min_weight_paths(2 * order, s + order, t, k, &arcs)?;
+
+        Some(
+            paths
+                .into_iter()
+                .map(|path| {
+                    let mut path = path
+                        .into_iter()
+                        .filter(|&v| v < order)
+                        .collect::<Vec<_>>();
+
+                    path.insert(0, s);
+
+                    path
+                })
+                .collect(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::*,
+        crate::{
+            AdjacencyListWeighted,
+            ArcWeight,
+            DijkstraDist,
+            Menger,
+            OutNeighborsWeighted,
+            proptest_strategy::digraph_weighted,
+            repr::adjacency_list_weighted::fixture::{
+                bang_jensen_94_usize,
+                bang_jensen_96_usize,
+                kattis_crosscountry_usize,
+                kattis_shortestpath1_usize,
+            },
+        },
+        proptest::prelude::*,
+        std::{
+            collections::BTreeSet,
+            iter::once,
+        },
+    };
+
+    fn weight(
+        digraph: &AdjacencyListWeighted<usize>,
+        path: &[usize],
+    ) -> usize {
+        path.windows(2)
+            .map(|arc| *digraph.arc_weight(arc[0], arc[1]).unwrap())
+            .sum()
+    }
+
+    fn simple_paths(
+        digraph: &AdjacencyListWeighted<usize>,
+        path: &mut Vec<usize>,
+        t: usize,
+        paths: &mut Vec<Vec<usize>>,
+    ) {
+        let u = *path.last().unwrap();
+
+        if u == t {
+            paths.push(path.clone());
+
+            return;
+        }
+
+        for (v, _) in digraph.out_neighbors_weighted(u) {
+            if !path.contains(&v) {
+                path.push(v);
+                simple_paths(digraph, path, t, paths);
+                let _ = path.pop();
+            }
+        }
+    }
+
+    fn arc_set(path: &[usize]) -> BTreeSet<(usize, usize)> {
+        path.windows(2).map(|arc| (arc[0], arc[1])).collect()
+    }
+
+    #[test]
+    fn arc_disjoint_paths_bang_jensen_94() {
+        let digraph = bang_jensen_94_usize();
+        let suurballe = Suurballe::new(&digraph);
+
+        assert_eq!(
+            suurballe.arc_disjoint_paths(0, 5, 2),
+            Some(vec![vec![0, 1, 3, 5], vec![0, 2, 5]])
+        );
+
+        assert_eq!(suurballe.arc_disjoint_paths(0, 5, 3), None);
+    }
+
+    #[test]
+    fn arc_disjoint_paths_bang_jensen_96() {
+        let digraph = bang_jensen_96_usize();
+        let suurballe = Suurballe::new(&digraph);
+
+        assert_eq!(
+            suurballe.arc_disjoint_paths(0, 5, 2),
+            Some(vec![vec![0, 1, 3, 5], vec![0, 2, 4, 5]])
+        );
+
+        assert_eq!(suurballe.arc_disjoint_paths(0, 5, 3), None);
+    }
+
+    #[test]
+    fn arc_disjoint_paths_cut_vertex() {
+        let digraph = digraph_weighted(
+            7,
+            &[
+                (0, 1, 1),
+                (0, 2, 1),
+                (1, 3, 1),
+                (2, 3, 1),
+                (3, 4, 1),
+                (3, 5, 1),
+                (4, 6, 1),
+                (5, 6, 1),
+            ],
+        );
+
+        assert_eq!(
+            Suurballe::new(&digraph).arc_disjoint_paths(0, 6, 2),
+            Some(vec![vec![0, 1, 3, 4, 6], vec![0, 2, 3, 5, 6]])
+        );
+    }
+
+    #[test]
+    fn arc_disjoint_paths_kattis_crosscountry() {
+        let digraph = kattis_crosscountry_usize();
+        let paths = Suurballe::new(&digraph).arc_disjoint_paths(0, 3, 3);
+
+        assert_eq!(
+            paths
+                .unwrap()
+                .iter()
+                .map(|p| weight(&digraph, p))
+                .sum::<usize>(),
+            47
+        );
+    }
+
+    #[test]
+    fn arc_disjoint_paths_kattis_shortestpath1() {
+        let digraph = kattis_shortestpath1_usize();
+
+        assert_eq!(Suurballe::new(&digraph).arc_disjoint_paths(0, 3, 1), None);
+    }
+
+    #[test]
+    fn arc_disjoint_paths_trap() {
+        let digraph = digraph_weighted(
+            4,
+            &[(0, 1, 1), (0, 2, 3), (1, 2, 1), (1, 3, 3), (2, 3, 1)],
+        );
+
+        let suurballe = Suurballe::new(&digraph);
+
+        assert_eq!(
+            suurballe.arc_disjoint_paths(0, 3, 1),
+            Some(vec![vec![0, 1, 2, 3]])
+        );
+
+        assert_eq!(
+            suurballe.arc_disjoint_paths(0, 3, 2),
+            Some(vec![vec![0, 1, 3], vec![0, 2, 3]])
+        );
+    }
+
+    #[test]
+    fn arc_disjoint_paths_zero() {
+        let digraph = digraph_weighted(2, &[]);
+
+        assert_eq!(
+            Suurballe::new(&digraph).arc_disjoint_paths(0, 1, 0),
+            Some(Vec::new())
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "s and t are the same vertex")]
+    fn arc_disjoint_paths_s_eq_t() {
+        let digraph = digraph_weighted(2, &[(0, 1, 1)]);
+
+        drop(Suurballe::new(&digraph).arc_disjoint_paths(0, 0, 1));
+    }
+
+    #[test]
+    #[should_panic(expected = "s = 2 isn't in the digraph")]
+    fn arc_disjoint_paths_s_out_of_bounds() {
+        let digraph = digraph_weighted(2, &[(0, 1, 1)]);
+
+        drop(Suurballe::new(&digraph).arc_disjoint_paths(2, 1, 1));
+    }
+
+    #[test]
+    #[should_panic(expected = "t = 2 isn't in the digraph")]
+    fn arc_disjoint_paths_t_out_of_bounds() {
+        let digraph = digraph_weighted(2, &[(0, 1, 1)]);
+
+        drop(Suurballe::new(&digraph).arc_disjoint_paths(0, 2, 1));
+    }
+
+    #[test]
+    fn vertex_disjoint_paths_bang_jensen_96() {
+        let digraph = bang_jensen_96_usize();
+        let suurballe = Suurballe::new(&digraph);
+
+        assert_eq!(
+            suurballe.vertex_disjoint_paths(0, 5, 2),
+            Some(vec![vec![0, 1, 3, 5], vec![0, 2, 4, 5]])
+        );
+
+        assert_eq!(suurballe.vertex_disjoint_paths(0, 5, 3), None);
+    }
+
+    #[test]
+    fn vertex_disjoint_paths_cut_vertex() {
+        let digraph = digraph_weighted(
+            7,
+            &[
+                (0, 1, 1),
+                (0, 2, 1),
+                (1, 3, 1),
+                (2, 3, 1),
+                (3, 4, 1),
+                (3, 5, 1),
+                (4, 6, 1),
+                (5, 6, 1),
+            ],
+        );
+
+        let suurballe = Suurballe::new(&digraph);
+
+        assert_eq!(
+            suurballe
+                .vertex_disjoint_paths(0, 6, 1)
+                .map(|paths| paths.len()),
+            Some(1)
+        );
+
+        assert_eq!(suurballe.vertex_disjoint_paths(0, 6, 2), None);
+    }
+
+    #[test]
+    fn vertex_disjoint_paths_direct_arc() {
+        let digraph = digraph_weighted(3, &[(0, 1, 5), (0, 2, 1), (2, 1, 1)]);
+
+        assert_eq!(
+            Suurballe::new(&digraph).vertex_disjoint_paths(0, 1, 2),
+            Some(vec![vec![0, 1], vec![0, 2, 1]])
+        );
+    }
+
+    proptest! {
+        #[test]
+        fn arc_disjoint_paths_1_eq_dijkstra_dist(
+            order in 2..8_usize,
+            arcs in prop::collection::vec(
+                (0..8_usize, 0..8_usize, 0..10_usize),
+                0..24,
+            ),
+        ) {
+            let digraph = digraph_weighted(order, &arcs);
+            let t = order - 1;
+            let dist = DijkstraDist::new(&digraph, once(0)).distances();
+            let paths = Suurballe::new(&digraph).arc_disjoint_paths(0, t, 1);
+
+            if dist[t] == usize::MAX {
+                assert_eq!(paths, None);
+            } else {
+                assert_eq!(weight(&digraph, &paths.unwrap()[0]), dist[t]);
+            }
+        }
+
+        #[test]
+        fn arc_disjoint_paths_2_eq_brute_force(
+            order in 2..7_usize,
+            arcs in prop::collection::vec(
+                (0..7_usize, 0..7_usize, 0..10_usize),
+                0..18,
+            ),
+        ) {
+            let digraph = digraph_weighted(order, &arcs);
+            let t = order - 1;
+            let mut simple = Vec::new();
+
+            simple_paths(&digraph, &mut vec![0], t, &mut simple);
+
+            let mut expected = None;
+
+            for (i, p) in simple.iter().enumerate() {
+                for q in &simple[i + 1..] {
+                    if arc_set(p).is_disjoint(&arc_set(q)) {
+                        let w = weight(&digraph, p) + weight(&digraph, q);
+
+                        expected = Some(expected.map_or(w, |e: usize| e.min(w)));
+                    }
+                }
+            }
+
+            let paths = Suurballe::new(&digraph).arc_disjoint_paths(0, t, 2);
+
+            assert_eq!(
+                paths.map(|paths| {
+                    paths.iter().map(|p| weight(&digraph, p)).sum::<usize>()
+                }),
+                expected
+            );
+        }
+
+        #[test]
+        fn arc_disjoint_paths_valid(
+            order in 2..8_usize,
+            arcs in prop::collection::vec(
+                (0..8_usize, 0..8_usize, 0..10_usize),
+                0..24,
+            ),
+        ) {
+            let digraph = digraph_weighted(order, &arcs);
+            let t = order - 1;
+            let k = Menger::new(&digraph).arc_disjoint_paths(0, t).len();
+            let suurballe = Suurballe::new(&digraph);
+            let paths = suurballe.arc_disjoint_paths(0, t, k).unwrap();
+            let mut used = BTreeSet::new();
+
+            for path in &paths {
+                assert_eq!(path.first(), Some(&0));
+                assert_eq!(path.last(), Some(&t));
+                assert_eq!(
+                    path.iter().collect::<BTreeSet<_>>().len(),
+                    path.len()
+                );
+
+                for arc in arc_set(path) {
+                    assert!(digraph.arc_weight(arc.0, arc.1).is_some());
+                    assert!(used.insert(arc));
+                }
+            }
+
+            assert_eq!(suurballe.arc_disjoint_paths(0, t, k + 1), None);
+        }
+
+        #[test]
+        fn vertex_disjoint_paths_valid(
+            order in 2..8_usize,
+            arcs in prop::collection::vec(
+                (0..8_usize, 0..8_usize, 0..10_usize),
+                0..24,
+            ),
+        ) {
+            let digraph = digraph_weighted(order, &arcs);
+            let t = order - 1;
+            let k = Menger::new(&digraph).vertex_disjoint_paths(0, t).len();
+            let suurballe = Suurballe::new(&digraph);
+            let paths = suurballe.vertex_disjoint_paths(0, t, k).unwrap();
+            let mut used = BTreeSet::new();
+
+            for path in &paths {
+                assert_eq!(path.first(), Some(&0));
+                assert_eq!(path.last(), Some(&t));
+
+                for arc in path.windows(2) {
+                    assert!(digraph.arc_weight(arc[0], arc[1]).is_some());
+                }
+
+                for &v in &path[1..path.len() - 1] {
+                    assert!(used.insert(v));
+                }
+            }
+
+            assert_eq!(suurballe.vertex_disjoint_paths(0, t, k + 1), None);
+        }
+    }
+}
@@ -0,0 +1,117 @@
+//! A disjoint-set forest.
+//!
+//! A disjoint-set forest tracks a partition of the vertices `0..order`. It
+//! joins the smaller tree below the larger one and halves the path to the
+//! root on each lookup.
+//!
+//! # Complexity
+//!
+//! Each operation runs in `O(α(v))` amortized time, where `v` is the number
+//! of vertices and `α` is the inverse Ackermann function.
+
+use std::mem::swap;
+
+/// A disjoint-set forest with union by size and path halving.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct UnionFind {
+    parent: Vec<usize>,
+    size: Vec<usize>,
+}
+
+impl UnionFind {
+    /// Construct a forest with each vertex in its own set.
+    ///
+    /// # Arguments
+    ///
+    /// * `order`: The number of vertices.
+    #[must_use]
+    pub fn new(order: usize) -> Self {
+        Self {
+            parent: (0..order).collect(),
+            size: vec![1; order],
+        }
+    }
+
+    /// Find the root of `u`'s tree, halving the path to it.
+    ///
+    /// # Arguments
+    ///
+    /// * `u`: The vertex.
+    pub fn find(&mut self, mut u: usize) -> usize {
+        while self.parent[u] != u {
+            self.parent[u] = self.parent[self.parent[u]];
+            u = self.parent[u];
+        }
+
+        u
+    }
+
+    /// Join the sets of `u` and `v`.
+    ///
+    /// # Arguments
+    ///
+    /// * `u`: A vertex.
+    /// * `v`: A vertex.
+    ///
+    /// # Returns
+    ///
+    /// Returns `false` if `u` and `v` are already in the same set.
+    pub fn union(&mut self, u: usize, v: usize) -> bool {
+        let mut x = self.find(u);
+        let mut y = self.find(v);
+
+        if x == y {
+            return false;
+        }
+
+        if self.size[x] < self.size[y] {
+            swap(&mut x, &mut y);
+        }
+
+        self.parent[y] = x;
+        self.size[x] += self.size[y];
+
+        true
+    }
+
+    /// Return the size of the set with root `root`.
+    ///
+    /// # Arguments
+    ///
+    /// * `root`: A root returned by [`UnionFind::find`].
+    #[must_use]
+    pub fn size(&self, root: usize) -> usize {
+        self.size[root]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn find_new() {
+        let mut union_find = UnionFind::new(3);
+
+        assert_eq!(union_find.find(0), 0);
+        assert_eq!(union_find.find(1), 1);
+        assert_eq!(union_find.find(2), 2);
+    }
+
+    #[test]
+    fn union_joins_smaller_below_larger() {
+        let mut union_find = UnionFind::new(4);
+
+        assert!(union_find.union(0, 1));
+        assert!(union_find.union(2, 0));
+        assert!(!union_find.union(1, 2));
+
+        let root = union_find.find(2);
+
+        assert_eq!(root, union_find.find(0));
+        assert_eq!(root, union_find.find(1));
+        assert_eq!(union_find.size(root), 3);
+        assert_eq!(union_find.find(3), 3);
+        assert_eq!(union_find.size(3), 1);
+    }
+}
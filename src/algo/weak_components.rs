@@ -0,0 +1,368 @@
+//! Weakly connected components.
+//!
+//! A digraph's weakly connected components are the connected components of
+//! its underlying undirected graph. [`WeakComponents`] finds them with a
+//! disjoint-set forest over the digraph's arcs.
+//!
+//! The time complexity is `O(v + a α(v))`, where `v` is the digraph's order,
+//! `a` is the digraph's size, and `α` is the inverse Ackermann function.
+//!
+//! # Examples
+//!
+//! There are three weakly connected components in this digraph.
+//!
+//! ```
+//! use graaf::{
+//!     AddArc,
+//!     AdjacencyList,
+//!     Empty,
+//!     WeakComponents,
+//! };
+//!
+//! let mut digraph = AdjacencyList::empty(7);
+//!
+//! digraph.add_arc(0, 1);
+//! digraph.add_arc(2, 1);
+//! digraph.add_arc(3, 4);
+//! digraph.add_arc(5, 4);
+//!
+//! let weak_components = WeakComponents::new(&digraph);
+//!
+//! assert!(weak_components.labels().eq(&[0, 0, 0, 1, 1, 1, 2]));
+//! assert!(weak_components.sizes().eq(&[3, 3, 1]));
+//! ```
+
+use {
+    super::union_find::UnionFind,
+    crate::{
+        Arcs,
+        Order,
+    },
+};
+
+/// Weakly connected components.
+///
+/// A digraph's weakly connected components are the connected components of
+/// its underlying undirected graph.
+///
+/// # Examples
+///
+/// There are three weakly connected components in this digraph.
+///
+/// ```
+/// use graaf::{
+///     AddArc,
+///     AdjacencyList,
+///     Empty,
+///     WeakComponents,
+/// };
+///
+/// let mut digraph = AdjacencyList::empty(7);
+///
+/// digraph.add_arc(0, 1);
+/// digraph.add_arc(2, 1);
+/// digraph.add_arc(3, 4);
+/// digraph.add_arc(5, 4);
+///
+/// let weak_components = WeakComponents::new(&digraph);
+///
+/// assert!(weak_components.labels().eq(&[0, 0, 0, 1, 1, 1, 2]));
+/// assert!(weak_components.sizes().eq(&[3, 3, 1]));
+/// ```
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct WeakComponents {
+    labels: Vec<usize>,
+    sizes: Vec<usize>,
+}
+
+impl WeakComponents {
+    /// Find the weakly connected components of a digraph.
+    ///
+    /// # Arguments
+    ///
+    /// * `digraph`: The digraph.
+    #[must_use]
+    pub fn new<D>(digraph: &D) -> Self
+    where
+        D: Arcs + Order,
+    {
+        let order = digraph.order();
+        let mut union_find = UnionFind::new(order);
+
+        for (u, v) in digraph.arcs() {
+            let _ = union_find.union(u, v);
+        }
+
+        let mut label = vec![usize::MAX; order];
+        let mut labels = Vec::with_capacity(order);
+        let mut sizes = Vec::new();
+
+        for u in 0..order {
+            let root = union_find.find(u);
+
+            if label[root] == usize::MAX {
+                label[root] = sizes.len();
+
+                sizes.push(union_find.size(root));
+            }
+
+            labels.push(label[root]);
+        }
+
+        Self { labels, sizes }
+    }
+
+    /// Label each vertex with its weakly connected component.
+    ///
+    /// The components are numbered from `0` in the order of their smallest
+    /// vertex.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use graaf::{
+    ///     AddArc,
+    ///     AdjacencyList,
+    ///     Empty,
+    ///     WeakComponents,
+    /// };
+    ///
+    /// let mut digraph = AdjacencyList::empty(7);
+    ///
+    /// digraph.add_arc(0, 1);
+    /// digraph.add_arc(2, 1);
+    /// digraph.add_arc(3, 4);
+    /// digraph.add_arc(5, 4);
+    ///
+    /// assert!(
+    ///     WeakComponents::new(&digraph)
+    ///         .labels()
+    ///         .eq(&[0, 0, 0, 1, 1, 1, 2])
+    /// );
+    /// ```
+    #[must_use]
+    pub fn labels(&self) -> &[usize] {
+        &self.labels
+    }
+
+    /// Count the vertices in each weakly connected component.
+    ///
+    /// The sizes are indexed by the labels returned by
+    /// [`WeakComponents::labels`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use graaf::{
+    ///     AddArc,
+    ///     AdjacencyList,
+    ///     Empty,
+    ///     WeakComponents,
+    /// };
+    ///
+    /// let mut digraph = AdjacencyList::empty(7);
+    ///
+    /// digraph.add_arc(0, 1);
+    /// digraph.add_arc(2, 1);
+    /// digraph.add_arc(3, 4);
+    /// digraph.add_arc(5, 4);
+    ///
+    /// assert!(WeakComponents::new(&digraph).sizes().eq(&[3, 3, 1]));
+    /// ```
+    #[must_use]
+    pub fn sizes(&self) -> &[usize] {
+        &self.sizes
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::*,
+        crate::{
+            AdjacencyList,
+            Empty,
+            repr::adjacency_list::fixture::{
+                bang_jensen_34,
+                bang_jensen_94,
+                bang_jensen_196,
+                kattis_builddeps,
+                kattis_cantinaofbabel_1,
+                kattis_cantinaofbabel_2,
+                kattis_escapewallmaria_1,
+                kattis_escapewallmaria_2,
+                kattis_escapewallmaria_3,
+            },
+        },
+    };
+
+    #[test]
+    fn labels_bang_jensen_196() {
+        assert!(WeakComponents::new(&bang_jensen_196()).labels().eq(&[0; 8]));
+    }
+
+    #[test]
+    fn labels_bang_jensen_34() {
+        assert!(WeakComponents::new(&bang_jensen_34()).labels().eq(&[0; 6]));
+    }
+
+    #[test]
+    fn labels_bang_jensen_94() {
+        assert!(WeakComponents::new(&bang_jensen_94()).labels().eq(&[0; 7]));
+    }
+
+    #[test]
+    fn labels_empty() {
+        assert!(
+            WeakComponents::new(&AdjacencyList::empty(4))
+                .labels()
+                .eq(&[0, 1, 2, 3])
+        );
+    }
+
+    #[test]
+    fn labels_kattis_builddeps() {
+        assert!(
+            WeakComponents::new(&kattis_builddeps())
+                .labels()
+                .eq(&[0; 6])
+        );
+    }
+
+    #[test]
+    fn labels_kattis_cantinaofbabel_1() {
+        assert!(
+            WeakComponents::new(&kattis_cantinaofbabel_1())
+                .labels()
+                .eq(&[0; 12])
+        );
+    }
+
+    #[test]
+    fn labels_kattis_cantinaofbabel_2() {
+        assert!(
+            WeakComponents::new(&kattis_cantinaofbabel_2())
+                .labels()
+                .eq(&[0; 12])
+        );
+    }
+
+    #[test]
+    fn labels_kattis_escapewallmaria_1() {
+        assert!(
+            WeakComponents::new(&kattis_escapewallmaria_1())
+                .labels()
+                .eq(&[0, 1, 2, 3, 4, 5, 5, 6, 7, 5, 8, 9, 5, 5, 10, 11])
+        );
+    }
+
+    #[test]
+    fn labels_kattis_escapewallmaria_2() {
+        assert!(
+            WeakComponents::new(&kattis_escapewallmaria_2())
+                .labels()
+                .eq(&[0, 1, 2, 3, 4, 5, 5, 6, 7, 5, 8, 9, 5, 5, 10, 11])
+        );
+    }
+
+    #[test]
+    fn labels_kattis_escapewallmaria_3() {
+        assert!(
+            WeakComponents::new(&kattis_escapewallmaria_3())
+                .labels()
+                .eq(&[0, 1, 1, 2, 3, 1, 1, 4, 5, 1, 6, 7, 1, 1, 8, 9])
+        );
+    }
+
+    #[test]
+    fn labels_trivial() {
+        assert!(
+            WeakComponents::new(&AdjacencyList::trivial())
+                .labels()
+                .eq(&[0])
+        );
+    }
+
+    #[test]
+    fn sizes_bang_jensen_196() {
+        assert!(WeakComponents::new(&bang_jensen_196()).sizes().eq(&[8]));
+    }
+
+    #[test]
+    fn sizes_bang_jensen_34() {
+        assert!(WeakComponents::new(&bang_jensen_34()).sizes().eq(&[6]));
+    }
+
+    #[test]
+    fn sizes_bang_jensen_94() {
+        assert!(WeakComponents::new(&bang_jensen_94()).sizes().eq(&[7]));
+    }
+
+    #[test]
+    fn sizes_empty() {
+        assert!(
+            WeakComponents::new(&AdjacencyList::empty(4))
+                .sizes()
+                .eq(&[1, 1, 1, 1])
+        );
+    }
+
+    #[test]
+    fn sizes_kattis_builddeps() {
+        assert!(WeakComponents::new(&kattis_builddeps()).sizes().eq(&[6]));
+    }
+
+    #[test]
+    fn sizes_kattis_cantinaofbabel_1() {
+        assert!(
+            WeakComponents::new(&kattis_cantinaofbabel_1())
+                .sizes()
+                .eq(&[12])
+        );
+    }
+
+    #[test]
+    fn sizes_kattis_cantinaofbabel_2() {
+        assert!(
+            WeakComponents::new(&kattis_cantinaofbabel_2())
+                .sizes()
+                .eq(&[12])
+        );
+    }
+
+    #[test]
+    fn sizes_kattis_escapewallmaria_1() {
+        assert!(
+            WeakComponents::new(&kattis_escapewallmaria_1())
+                .sizes()
+                .eq(&[1, 1, 1, 1, 1, 5, 1, 1, 1, 1, 1, 1])
+        );
+    }
+
+    #[test]
+    fn sizes_kattis_escapewallmaria_2() {
+        assert!(
+            WeakComponents::new(&kattis_escapewallmaria_2())
+                .sizes()
+                .eq(&[1, 1, 1, 1, 1, 5, 1, 1, 1, 1, 1, 1])
+        );
+    }
+
+    #[test]
+    fn sizes_kattis_escapewallmaria_3() {
+        assert!(
+            WeakComponents::new(&kattis_escapewallmaria_3())
+                .sizes()
+                .eq(&[1, 7, 1, 1, 1, 1, 1, 1, 1, 1])
+        );
+    }
+
+    #[test]
+    fn sizes_trivial() {
+        assert!(
+            WeakComponents::new(&AdjacencyList::trivial())
+                .sizes()
+                .eq(&[1])
+        );
+    }
+}
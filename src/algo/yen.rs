@@ -0,0 +1,405 @@
+//! Yen's k-shortest loopless paths algorithm.
+//!
+//! Yen's algorithm iterates the loopless paths between two vertices in an
+//! arc-weighted digraph in order of their weight.[^1] Each path after the
+//! first deviates from a previous path at a spur vertex. The spur paths are
+//! shortest paths found with [`DijkstraPred`] in the digraph without the
+//! arcs and vertices that would repeat a previous path or form a loop.
+//!
+//! The time complexity is `O(k v (v log v + a))`, where `k` is the number of
+//! paths, `v` is the digraph's order, and `a` is the digraph's size.
+//!
+//! # Examples
+//!
+//! ```
+//! use graaf::{
+//!     AddArcWeighted,
+//!     AdjacencyListWeighted,
+//!     Empty,
+//!     Yen,
+//! };
+//!
+//! let mut digraph = AdjacencyListWeighted::<usize>::empty(6);
+//!
+//! digraph.add_arc_weighted(0, 1, 3);
+//! digraph.add_arc_weighted(0, 2, 2);
+//! digraph.add_arc_weighted(1, 3, 4);
+//! digraph.add_arc_weighted(2, 1, 1);
+//! digraph.add_arc_weighted(2, 3, 2);
+//! digraph.add_arc_weighted(2, 4, 3);
+//! digraph.add_arc_weighted(3, 4, 2);
+//! digraph.add_arc_weighted(3, 5, 1);
+//! digraph.add_arc_weighted(4, 5, 2);
+//!
+//! assert!(Yen::new(&digraph, 0, 5).take(3).eq([
+//!     (vec![0, 2, 3, 5], 5),
+//!     (vec![0, 2, 4, 5], 7),
+//!     (vec![0, 1, 3, 5], 8),
+//! ]));
+//! ```
+//!
+//! [^1]: Jin Y. Yen. 1971. Finding the K Shortest Loopless Paths in a
+//!   Network. Manage. Sci. 17, 11 (July 1971), 712–716.
+//!   <https://doi.org/10.1287/mnsc.17.11.712>
+
+use {
+    crate::{
+        DijkstraPred,
+        Order,
+        OutNeighborsWeighted,
+    },
+    core::cmp::Reverse,
+    std::{
+        collections::{
+            BTreeSet,
+            BinaryHeap,
+        },
+        iter::once,
+    },
+};
+
+/// The digraph without the removed arcs and vertices of a spur search.
+struct Spur<'a, D> {
+    digraph: &'a D,
+    removed_arcs: &'a BTreeSet<(usize, usize)>,
+    removed_vertices: &'a [bool],
+}
+
+impl<D> Order for Spur<'_, D>
+where
+    D: Order,
+{
+    fn order(&self) -> usize {
+        self.digraph.order()
+    }
+}
+
+impl<D> OutNeighborsWeighted for Spur<'_, D>
+where
+    D: OutNeighborsWeighted<Weight = usize>,
+{
+    type Weight = usize;
+
+    fn out_neighbors_weighted(
+        &self,
+        u: usize,
+    ) -> impl Iterator<Item = (usize, &Self::Weight)> {
+        self.digraph
+            .out_neighbors_weighted(u)
+            .filter(move |&(v, _)| {
+                !self.removed_vertices[v]
+                    && !self.removed_arcs.contains(&(u, v))
+            })
+    }
+}
+
+/// Yen's k-shortest loopless paths algorithm.
+///
+/// # Examples
+///
+/// ```
+/// use graaf::{
+///     AddArcWeighted,
+///     AdjacencyListWeighted,
+///     Empty,
+///     Yen,
+/// };
+///
+/// let mut digraph = AdjacencyListWeighted::<usize>::empty(6);
+///
+/// digraph.add_arc_weighted(0, 1, 3);
+/// digraph.add_arc_weighted(0, 2, 2);
+/// digraph.add_arc_weighted(1, 3, 4);
+/// digraph.add_arc_weighted(2, 1, 1);
+/// digraph.add_arc_weighted(2, 3, 2);
+/// digraph.add_arc_weighted(2, 4, 3);
+/// digraph.add_arc_weighted(3, 4, 2);
+/// digraph.add_arc_weighted(3, 5, 1);
+/// digraph.add_arc_weighted(4, 5, 2);
+///
+/// assert!(Yen::new(&digraph, 0, 5).take(3).eq([
+///     (vec![0, 2, 3, 5], 5),
+///     (vec![0, 2, 4, 5], 7),
+///     (vec![0, 1, 3, 5], 8),
+/// ]));
+/// ```
+#[derive(Clone, Debug)]
+pub struct Yen<'a, D> {
+    digraph: &'a D,
+    s: usize,
+    t: usize,
+    paths: Vec<Vec<usize>>,
+    candidates: BinaryHeap<Reverse<(usize, Vec<usize>)>>,
+    seen: BTreeSet<Vec<usize>>,
+}
+
+impl<'a, D> Yen<'a, D>
+where
+    D: Order,
+{
+    /// Initialize Yen's algorithm.
+    ///
+    /// # Arguments
+    ///
+    /// * `digraph`: The digraph.
+    /// * `s`: The source vertex.
+    /// * `t`: The target vertex.
+    ///
+    /// # Panics
+    ///
+    /// * Panics if `s` isn't in the digraph.
+    /// * Panics if `t` isn't in the digraph.
+    #[must_use]
+    pub fn new(digraph: &'a D, s: usize, t: usize) -> Self {
+        let order = digraph.order();
+
+        assert!(s < order, "s = {s} isn't in the digraph");
+        assert!(t < order, "t = {t} isn't in the digraph");
+
+        Self {
+            digraph,
+            s,
+            t,
+            paths: Vec::new(),
+            candidates: BinaryHeap::new(),
+            seen: BTreeSet::new(),
+        }
+    }
+
+    /// Return the weight of the arc `(u, v)`.
+    fn weight(&self, u: usize, v: usize) -> usize
+    where
+        D: OutNeighborsWeighted<Weight = usize>,
+    {
+        self.digraph
+            .out_neighbors_weighted(u)
+            .find_map(|(x, &w)| (x == v).then_some(w))
+            .expect("consecutive path vertices are adjacent")
+    }
+
+    /// Return the weight of a path.
+    fn path_weight(&self, path: &[usize]) -> usize
+    where
+        D: OutNeighborsWeighted<Weight = usize>,
+    {
+        path.windows(2).map(|arc| self.weight(arc[0], arc[1])).sum()
+    }
+
+    /// Add a candidate path if no previous path or candidate equals it.
+    fn push_candidate(&mut self, path: Vec<usize>)
+    where
+        D: OutNeighborsWeighted<Weight = usize>,
+    {
+        if self.seen.insert(path.clone()) {
+            let weight = self.path_weight(&path);
+
+            self.candidates.push(Reverse((weight, path)));
+        }
+    }
+
+    /// Add the candidates that deviate from the last path.
+    fn spur(&mut self)
+    where
+        D: OutNeighborsWeighted<Weight = usize>,
+    {
+        let Some(last) = self.paths.last().cloned() else {
+            return;
+        };
+
+        let mut removed_vertices = vec![false; self.digraph.order()];
+
+        for i in 0..last.len() - 1 {
+            let root = &last[..=i];
+            let spur_vertex = last[i];
+
+            let removed_arcs = self
+                .paths
+                .iter()
+                .filter(|path| path.len() > i + 1 && path[..=i] == *root)
+                .map(|path| (path[i], path[i + 1]))
+                .collect::<BTreeSet<_>>();
+
+            if i > 0 {
+                removed_vertices[last[i - 1]] = true;
+            }
+
+            let spur = Spur {
+                digraph: self.digraph,
+                removed_arcs: &removed_arcs,
+                removed_vertices: &removed_vertices,
+            };
+
+            let t = self.t;
+
+            if let Some(spur_path) =
+                DijkstraPred::new(&spur, once(spur_vertex))
+                    .shortest_path(|v| v == t)
+            {
+                let mut path = root[..i].to_vec();
+
+                path.extend(spur_path);
+                self.push_candidate(path);
+            }
+        }
+    }
+}
+
+impl<D> Iterator for Yen<'_, D>
+where
+    D: Order + OutNeighborsWeighted<Weight = usize>,
+{
+    type Item = (Vec<usize>, usize);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.paths.is_empty() {
+            let t = self.t;
+            let path = DijkstraPred::new(self.digraph, once(self.s))
+                .shortest_path(|v| v == t)?;
+
+            let _ = self.seen.insert(path.clone());
+            let weight = self.path_weight(&path);
+
+            self.paths.push(path.clone());
+
+            return Some((path, weight));
+        }
+
+        self.spur();
+
+        let Reverse((weight, path)) = self.candidates.pop()?;
+
+        self.paths.push(path.clone());
+
+        Some((path, weight))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::*,
+        crate::{
+            AdjacencyListWeighted,
+            Empty,
+            proptest_strategy::digraph_weighted,
+            repr::adjacency_list_weighted::fixture::{
+                bang_jensen_94_usize,
+                bang_jensen_96_usize,
+                kattis_bryr_1_usize,
+                kattis_crosscountry_usize,
+                kattis_shortestpath1_usize,
+            },
+        },
+        proptest::prelude::*,
+    };
+
+    #[test]
+    fn iter_bang_jensen_94() {
+        let digraph = bang_jensen_94_usize();
+
+        assert!(Yen::new(&digraph, 0, 6).eq([(vec![0, 2, 4, 6], 3)]));
+    }
+
+    #[test]
+    fn iter_bang_jensen_96() {
+        let digraph = bang_jensen_96_usize();
+
+        assert!(Yen::new(&digraph, 0, 5).eq([
+            (vec![0, 2, 4, 3, 5], 7),
+            (vec![0, 2, 1, 3, 5], 8),
+            (vec![0, 2, 4, 5], 11),
+            (vec![0, 1, 3, 5], 12),
+            (vec![0, 1, 2, 4, 3, 5], 19),
+            (vec![0, 1, 2, 4, 5], 23),
+        ]));
+    }
+
+    #[test]
+    fn iter_kattis_bryr_1() {
+        let digraph = kattis_bryr_1_usize();
+
+        assert!(
+            Yen::new(&digraph, 0, 2).eq([(vec![0, 2], 1), (vec![0, 1, 2], 2)])
+        );
+    }
+
+    #[test]
+    fn iter_kattis_crosscountry() {
+        let digraph = kattis_crosscountry_usize();
+
+        assert_eq!(Yen::new(&digraph, 0, 3).count(), 5);
+    }
+
+    #[test]
+    fn iter_kattis_shortestpath1() {
+        let digraph = kattis_shortestpath1_usize();
+
+        assert!(Yen::new(&digraph, 0, 3).next().is_none());
+    }
+
+    #[test]
+    fn iter_trivial() {
+        let digraph = AdjacencyListWeighted::<usize>::trivial();
+
+        assert!(Yen::new(&digraph, 0, 0).eq([(vec![0], 0)]));
+    }
+
+    #[test]
+    #[should_panic(expected = "t = 3 isn't in the digraph")]
+    fn new_t_out_of_bounds() {
+        let digraph = kattis_bryr_1_usize();
+
+        drop(Yen::new(&digraph, 0, 3));
+    }
+
+    fn simple_paths(
+        digraph: &AdjacencyListWeighted<usize>,
+        path: &mut Vec<usize>,
+        weight: usize,
+        t: usize,
+        paths: &mut Vec<usize>,
+    ) {
+        let u = *path.last().unwrap();
+
+        if u == t {
+            paths.push(weight);
+
+            return;
+        }
+
+        for (v, &w) in digraph.out_neighbors_weighted(u) {
+            if !path.contains(&v) {
+                path.push(v);
+                simple_paths(digraph, path, weight + w, t, paths);
+                let _ = path.pop();
+            }
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn iter_eq_simple_paths(
+            order in 1..7_usize,
+            arcs in prop::collection::vec(
+                (0..7_usize, 0..7_usize, 0..10_usize),
+                0..20,
+            ),
+            t in 0..7_usize,
+        ) {
+            let digraph = digraph_weighted(order, &arcs);
+            let t = t % order;
+            let mut expected = Vec::new();
+
+            simple_paths(&digraph, &mut vec![0], 0, t, &mut expected);
+            expected.sort_unstable();
+
+            let paths = Yen::new(&digraph, 0, t).collect::<Vec<_>>();
+
+            assert!(paths.iter().map(|(_, w)| *w).eq(expected));
+            assert_eq!(
+                paths.iter().map(|(p, _)| p).collect::<BTreeSet<_>>().len(),
+                paths.len()
+            );
+        }
+    }
+}
@@ -0,0 +1,303 @@
+//! 0-1 breadth-first search with distances.
+//!
+//! 0-1 breadth-first search finds the shortest paths in an arc-weighted
+//! digraph whose arc weights are `0` or `1`. It replaces the binary heap of
+//! Dijkstra's algorithm with a double-ended queue: vertices reached through
+//! an arc of weight `0` go to the front, vertices reached through an arc of
+//! weight `1` go to the back.
+//!
+//! The time complexity is `O(v + a)`, where `v` is the digraph's order and `a`
+//! is the digraph's size.
+//!
+//! # Examples
+//!
+//! ```
+//! use {
+//!     graaf::{
+//!         AddArcWeighted,
+//!         AdjacencyListWeighted,
+//!         Empty,
+//!         ZeroOneBfsDist,
+//!     },
+//!     std::iter::once,
+//! };
+//!
+//! let mut digraph = AdjacencyListWeighted::<usize>::empty(5);
+//!
+//! digraph.add_arc_weighted(0, 1, 1);
+//! digraph.add_arc_weighted(0, 2, 0);
+//! digraph.add_arc_weighted(1, 4, 0);
+//! digraph.add_arc_weighted(2, 3, 1);
+//! digraph.add_arc_weighted(3, 4, 1);
+//!
+//! let mut bfs = ZeroOneBfsDist::new(&digraph, once(0));
+//!
+//! assert!(bfs.eq([(0, 0), (2, 0), (1, 1), (4, 1), (3, 1)]));
+//! ```
+
+use {
+    crate::{
+        Order,
+        OutNeighborsWeighted,
+    },
+    std::collections::VecDeque,
+};
+
+type Step = (usize, usize);
+
+/// 0-1 breadth-first search with distances.
+///
+/// # Examples
+///
+/// ```
+/// use {
+///     graaf::{
+///         AddArcWeighted,
+///         AdjacencyListWeighted,
+///         Empty,
+///         ZeroOneBfsDist,
+///     },
+///     std::iter::once,
+/// };
+///
+/// let mut digraph = AdjacencyListWeighted::<usize>::empty(5);
+///
+/// digraph.add_arc_weighted(0, 1, 1);
+/// digraph.add_arc_weighted(0, 2, 0);
+/// digraph.add_arc_weighted(1, 4, 0);
+/// digraph.add_arc_weighted(2, 3, 1);
+/// digraph.add_arc_weighted(3, 4, 1);
+///
+/// let mut bfs = ZeroOneBfsDist::new(&digraph, once(0));
+///
+/// assert!(bfs.eq([(0, 0), (2, 0), (1, 1), (4, 1), (3, 1)]));
+/// ```
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ZeroOneBfsDist<'a, D> {
+    digraph: &'a D,
+    dist: Vec<usize>,
+    deque: VecDeque<Step>,
+}
+
+impl<'a, D> ZeroOneBfsDist<'a, D>
+where
+    D: Order,
+{
+    /// Initialize 0-1 breadth-first search.
+    ///
+    /// # Arguments
+    ///
+    /// * `digraph`: The digraph.
+    /// * `sources`: The source vertices.
+    ///
+    /// # Panics
+    ///
+    /// Panics if a source vertex isn't in the digraph.
+    #[must_use]
+    pub fn new<T>(digraph: &'a D, sources: T) -> Self
+    where
+        T: Iterator<Item = usize>,
+    {
+        let order = digraph.order();
+        let mut deque = VecDeque::with_capacity(order);
+        let mut dist = vec![usize::MAX; order];
+
+        for u in sources {
+            // Each distinct source is settled once.
+            if dist[u] == 0 {
+                continue;
+            }
+
+            dist[u] = 0;
+            deque.push_back((u, 0));
+        }
+
+        Self {
+            digraph,
+            dist,
+            deque,
+        }
+    }
+
+    /// Find the distances from the source vertices to all other vertices.
+    ///
+    /// # Panics
+    ///
+    /// * Panics if a successor vertex isn't in the digraph.
+    /// * Panics if an arc weight isn't `0` or `1`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use {
+    ///     graaf::{
+    ///         AddArcWeighted,
+    ///         AdjacencyListWeighted,
+    ///         Empty,
+    ///         ZeroOneBfsDist,
+    ///     },
+    ///     std::iter::once,
+    /// };
+    ///
+    /// let mut digraph = AdjacencyListWeighted::<usize>::empty(6);
+    ///
+    /// digraph.add_arc_weighted(0, 1, 1);
+    /// digraph.add_arc_weighted(0, 2, 0);
+    /// digraph.add_arc_weighted(1, 4, 0);
+    /// digraph.add_arc_weighted(2, 3, 1);
+    /// digraph.add_arc_weighted(3, 4, 1);
+    ///
+    /// assert!(ZeroOneBfsDist::new(&digraph, once(0)).distances().eq(&[
+    ///     0,
+    ///     1,
+    ///     0,
+    ///     1,
+    ///     1,
+    ///     usize::MAX
+    /// ]));
+    /// ```
+    #[must_use]
+    pub fn distances(&mut self) -> Vec<usize>
+    where
+        D: OutNeighborsWeighted<Weight = usize>,
+    {
+        let mut dist = vec![usize::MAX; self.digraph.order()];
+
+        for (v, d) in self {
+            dist[v] = d;
+        }
+
+        dist
+    }
+}
+
+impl<D> Iterator for ZeroOneBfsDist<'_, D>
+where
+    D: Order + OutNeighborsWeighted<Weight = usize>,
+{
+    type Item = Step;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let step @ (u, distance) = self.deque.pop_front()?;
+
+            if distance != self.dist[u] {
+                continue;
+            }
+
+            for (v, &w) in self.digraph.out_neighbors_weighted(u) {
+                assert!(w <= 1, "arc ({u}, {v}) has weight {w}, not 0 or 1");
+
+                let distance = distance + w;
+
+                if distance < self.dist[v] {
+                    self.dist[v] = distance;
+
+                    if w == 0 {
+                        self.deque.push_front((v, distance));
+                    } else {
+                        self.deque.push_back((v, distance));
+                    }
+                }
+            }
+
+            return Some(step);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::*,
+        crate::{
+            AdjacencyListWeighted,
+            DijkstraDist,
+            Empty,
+            proptest_strategy::digraph_weighted,
+        },
+        proptest::prelude::*,
+        std::iter::once,
+    };
+
+    #[test]
+    fn distances_multi_source() {
+        let digraph =
+            digraph_weighted(5, &[(0, 1, 1), (1, 2, 1), (3, 2, 0), (2, 4, 1)]);
+
+        assert!(
+            ZeroOneBfsDist::new(&digraph, [0, 3].into_iter())
+                .distances()
+                .eq(&[0, 1, 0, 0, 1])
+        );
+    }
+
+    #[test]
+    fn distances_trivial() {
+        let digraph = AdjacencyListWeighted::<usize>::trivial();
+
+        assert!(ZeroOneBfsDist::new(&digraph, once(0)).distances().eq(&[0]));
+    }
+
+    #[test]
+    fn iter_duplicate_sources() {
+        let digraph = digraph_weighted(3, &[(0, 1, 1), (1, 2, 0)]);
+
+        assert!(ZeroOneBfsDist::new(&digraph, [0, 0].into_iter()).eq([
+            (0, 0),
+            (1, 1),
+            (2, 1)
+        ]));
+    }
+
+    #[test]
+    fn iter_stale_entry() {
+        let digraph =
+            digraph_weighted(4, &[(0, 1, 1), (0, 2, 0), (2, 1, 0), (1, 3, 1)]);
+
+        assert!(ZeroOneBfsDist::new(&digraph, once(0)).eq([
+            (0, 0),
+            (2, 0),
+            (1, 0),
+            (3, 1),
+        ]));
+    }
+
+    #[test]
+    fn iter_zero_weights() {
+        let digraph = digraph_weighted(4, &[(0, 1, 0), (1, 2, 0), (2, 3, 0)]);
+
+        assert!(ZeroOneBfsDist::new(&digraph, once(0)).eq([
+            (0, 0),
+            (1, 0),
+            (2, 0),
+            (3, 0),
+        ]));
+    }
+
+    #[test]
+    #[should_panic(expected = "arc (0, 1) has weight 2, not 0 or 1")]
+    fn iter_weight_2() {
+        let digraph = digraph_weighted(2, &[(0, 1, 2)]);
+
+        drop(ZeroOneBfsDist::new(&digraph, once(0)).distances());
+    }
+
+    proptest! {
+        #[test]
+        fn distances_eq_dijkstra_dist(
+            order in 1..8_usize,
+            arcs in prop::collection::vec(
+                (0..8_usize, 0..8_usize, 0..2_usize),
+                0..24,
+            ),
+        ) {
+            let digraph = digraph_weighted(order, &arcs);
+
+            assert_eq!(
+                ZeroOneBfsDist::new(&digraph, once(0)).distances(),
+                DijkstraDist::new(&digraph, once(0)).distances()
+            );
+        }
+    }
+}
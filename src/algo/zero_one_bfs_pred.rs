@@ -0,0 +1,403 @@
+//! 0-1 breadth-first search with predecessors.
+//!
+//! 0-1 breadth-first search finds the shortest paths in an arc-weighted
+//! digraph whose arc weights are `0` or `1`. It replaces the binary heap of
+//! Dijkstra's algorithm with a double-ended queue: vertices reached through
+//! an arc of weight `0` go to the front, vertices reached through an arc of
+//! weight `1` go to the back.
+//!
+//! The time complexity is `O(v + a)`, where `v` is the digraph's order and `a`
+//! is the digraph's size.
+//!
+//! # Examples
+//!
+//! ```
+//! use {
+//!     graaf::{
+//!         AddArcWeighted,
+//!         AdjacencyListWeighted,
+//!         Empty,
+//!         ZeroOneBfsPred,
+//!     },
+//!     std::iter::once,
+//! };
+//!
+//! let mut digraph = AdjacencyListWeighted::<usize>::empty(5);
+//!
+//! digraph.add_arc_weighted(0, 1, 1);
+//! digraph.add_arc_weighted(0, 2, 0);
+//! digraph.add_arc_weighted(1, 4, 0);
+//! digraph.add_arc_weighted(2, 3, 1);
+//! digraph.add_arc_weighted(3, 4, 1);
+//!
+//! let mut bfs = ZeroOneBfsPred::new(&digraph, once(0));
+//!
+//! assert!(bfs.eq([
+//!     (None, 0),
+//!     (Some(0), 2),
+//!     (Some(0), 1),
+//!     (Some(1), 4),
+//!     (Some(2), 3),
+//! ]));
+//! ```
+
+use {
+    crate::{
+        Order,
+        OutNeighborsWeighted,
+        PredecessorTree,
+    },
+    std::collections::VecDeque,
+};
+
+type Step = (Option<usize>, usize);
+
+/// 0-1 breadth-first search with predecessors.
+///
+/// # Examples
+///
+/// ```
+/// use {
+///     graaf::{
+///         AddArcWeighted,
+///         AdjacencyListWeighted,
+///         Empty,
+///         ZeroOneBfsPred,
+///     },
+///     std::iter::once,
+/// };
+///
+/// let mut digraph = AdjacencyListWeighted::<usize>::empty(5);
+///
+/// digraph.add_arc_weighted(0, 1, 1);
+/// digraph.add_arc_weighted(0, 2, 0);
+/// digraph.add_arc_weighted(1, 4, 0);
+/// digraph.add_arc_weighted(2, 3, 1);
+/// digraph.add_arc_weighted(3, 4, 1);
+///
+/// let mut bfs = ZeroOneBfsPred::new(&digraph, once(0));
+///
+/// assert!(bfs.eq([
+///     (None, 0),
+///     (Some(0), 2),
+///     (Some(0), 1),
+///     (Some(1), 4),
+///     (Some(2), 3),
+/// ]));
+/// ```
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ZeroOneBfsPred<'a, D> {
+    digraph: &'a D,
+    dist: Vec<usize>,
+    deque: VecDeque<(usize, Step)>,
+}
+
+impl<'a, D> ZeroOneBfsPred<'a, D>
+where
+    D: Order,
+{
+    /// Initialize 0-1 breadth-first search.
+    ///
+    /// # Arguments
+    ///
+    /// * `digraph`: The digraph.
+    /// * `sources`: The source vertices.
+    ///
+    /// # Panics
+    ///
+    /// Panics if a source vertex isn't in the digraph.
+    #[must_use]
+    pub fn new<T>(digraph: &'a D, sources: T) -> Self
+    where
+        T: Iterator<Item = usize>,
+    {
+        let order = digraph.order();
+        let mut deque = VecDeque::with_capacity(order);
+        let mut dist = vec![usize::MAX; order];
+
+        for u in sources {
+            // Each distinct source is settled once.
+            if dist[u] == 0 {
+                continue;
+            }
+
+            dist[u] = 0;
+            deque.push_back((0, (None, u)));
+        }
+
+        Self {
+            digraph,
+            dist,
+            deque,
+        }
+    }
+
+    /// Find the predecessor tree.
+    ///
+    /// # Panics
+    ///
+    /// * Panics if a successor vertex isn't in the digraph.
+    /// * Panics if an arc weight isn't `0` or `1`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use {
+    ///     graaf::{
+    ///         AddArcWeighted,
+    ///         AdjacencyListWeighted,
+    ///         Empty,
+    ///         ZeroOneBfsPred,
+    ///     },
+    ///     std::iter::once,
+    /// };
+    ///
+    /// let mut digraph = AdjacencyListWeighted::<usize>::empty(6);
+    ///
+    /// digraph.add_arc_weighted(0, 1, 1);
+    /// digraph.add_arc_weighted(0, 2, 0);
+    /// digraph.add_arc_weighted(1, 4, 0);
+    /// digraph.add_arc_weighted(2, 3, 1);
+    /// digraph.add_arc_weighted(3, 4, 1);
+    ///
+    /// assert!(
+    ///     ZeroOneBfsPred::new(&digraph, once(0))
+    ///         .predecessors()
+    ///         .into_iter()
+    ///         .eq([None, Some(0), Some(0), Some(2), Some(1), None])
+    /// );
+    /// ```
+    #[must_use]
+    pub fn predecessors(&mut self) -> PredecessorTree
+    where
+        D: OutNeighborsWeighted<Weight = usize>,
+    {
+        let mut pred = PredecessorTree::new(self.digraph.order());
+
+        for (u, v) in self {
+            pred[v] = u;
+        }
+
+        pred
+    }
+
+    /// Find the shortest path from the source vertices to a target vertex.
+    ///
+    /// # Arguments
+    ///
+    /// * `is_target`: The function determining if the vertex is a target.
+    ///
+    /// # Returns
+    ///
+    /// If a target vertex is reachable, the function returns the shortest
+    /// path to the nearest target vertex. Otherwise, it returns `None`.
+    ///
+    /// # Panics
+    ///
+    /// * Panics if `is_target` panics.
+    /// * Panics if a successor vertex isn't in the digraph.
+    /// * Panics if an arc weight isn't `0` or `1`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use {
+    ///     graaf::{
+    ///         AddArcWeighted,
+    ///         AdjacencyListWeighted,
+    ///         Empty,
+    ///         ZeroOneBfsPred,
+    ///     },
+    ///     std::iter::once,
+    /// };
+    ///
+    /// let mut digraph = AdjacencyListWeighted::<usize>::empty(5);
+    ///
+    /// digraph.add_arc_weighted(0, 1, 1);
+    /// digraph.add_arc_weighted(0, 2, 0);
+    /// digraph.add_arc_weighted(1, 4, 0);
+    /// digraph.add_arc_weighted(2, 3, 1);
+    /// digraph.add_arc_weighted(3, 4, 1);
+    ///
+    /// assert!(
+    ///     ZeroOneBfsPred::new(&digraph, once(0))
+    ///         .shortest_path(|v| v == 4)
+    ///         .unwrap()
+    ///         .eq(&[0, 1, 4])
+    /// );
+    /// ```
+    #[must_use]
+    pub fn shortest_path<P>(&mut self, is_target: P) -> Option<Vec<usize>>
+    where
+        D: OutNeighborsWeighted<Weight = usize>,
+        P: Fn(usize) -> bool,
+    {
+        let mut pred = PredecessorTree::new(self.digraph.order());
+
+        for (u, v) in self {
+            pred[v] = u;
+
+            if is_target(v) {
+                return pred.search_by(v, |_, b| b.is_none()).map(
+                    |mut path| {
+                        path.reverse();
+
+                        path
+                    },
+                );
+            }
+        }
+
+        None
+    }
+}
+
+impl<D> Iterator for ZeroOneBfsPred<'_, D>
+where
+    D: Order + OutNeighborsWeighted<Weight = usize>,
+{
+    type Item = Step;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let (distance, step @ (_, u)) = self.deque.pop_front()?;
+
+            if distance != self.dist[u] {
+                continue;
+            }
+
+            for (v, &w) in self.digraph.out_neighbors_weighted(u) {
+                assert!(w <= 1, "arc ({u}, {v}) has weight {w}, not 0 or 1");
+
+                let distance = distance + w;
+
+                if distance < self.dist[v] {
+                    self.dist[v] = distance;
+
+                    if w == 0 {
+                        self.deque.push_front((distance, (Some(u), v)));
+                    } else {
+                        self.deque.push_back((distance, (Some(u), v)));
+                    }
+                }
+            }
+
+            return Some(step);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::*,
+        crate::{
+            AdjacencyListWeighted,
+            ArcWeight,
+            DijkstraDist,
+            Empty,
+            proptest_strategy::digraph_weighted,
+        },
+        proptest::prelude::*,
+        std::iter::once,
+    };
+
+    #[test]
+    fn iter_duplicate_sources() {
+        let digraph = digraph_weighted(3, &[(0, 1, 1), (1, 2, 0)]);
+
+        assert!(ZeroOneBfsPred::new(&digraph, [0, 0].into_iter()).eq([
+            (None, 0),
+            (Some(0), 1),
+            (Some(1), 2),
+        ]));
+    }
+
+    #[test]
+    fn iter_stale_entry() {
+        let digraph =
+            digraph_weighted(4, &[(0, 1, 1), (0, 2, 0), (2, 1, 0), (1, 3, 1)]);
+
+        assert!(ZeroOneBfsPred::new(&digraph, once(0)).eq([
+            (None, 0),
+            (Some(0), 2),
+            (Some(2), 1),
+            (Some(1), 3),
+        ]));
+    }
+
+    #[test]
+    #[should_panic(expected = "arc (0, 1) has weight 2, not 0 or 1")]
+    fn iter_weight_2() {
+        let digraph = digraph_weighted(2, &[(0, 1, 2)]);
+
+        drop(ZeroOneBfsPred::new(&digraph, once(0)).predecessors());
+    }
+
+    #[test]
+    fn predecessors_multi_source() {
+        let digraph =
+            digraph_weighted(5, &[(0, 1, 1), (1, 2, 1), (3, 2, 0), (2, 4, 1)]);
+
+        assert!(
+            ZeroOneBfsPred::new(&digraph, [0, 3].into_iter())
+                .predecessors()
+                .into_iter()
+                .eq([None, Some(0), Some(3), None, Some(2)])
+        );
+    }
+
+    #[test]
+    fn predecessors_trivial() {
+        let digraph = AdjacencyListWeighted::<usize>::trivial();
+
+        assert!(
+            ZeroOneBfsPred::new(&digraph, once(0))
+                .predecessors()
+                .into_iter()
+                .eq([None])
+        );
+    }
+
+    #[test]
+    fn shortest_path_unreachable() {
+        let digraph = digraph_weighted(3, &[(0, 1, 0), (2, 1, 1)]);
+
+        assert!(
+            ZeroOneBfsPred::new(&digraph, once(0))
+                .shortest_path(|v| v == 2)
+                .is_none()
+        );
+    }
+
+    proptest! {
+        #[test]
+        fn shortest_path_weight_eq_dijkstra_dist(
+            order in 1..8_usize,
+            arcs in prop::collection::vec(
+                (0..8_usize, 0..8_usize, 0..2_usize),
+                0..24,
+            ),
+            t in 0..8_usize,
+        ) {
+            let digraph = digraph_weighted(order, &arcs);
+            let t = t % order;
+            let dist = DijkstraDist::new(&digraph, once(0)).distances();
+
+            let path = ZeroOneBfsPred::new(&digraph, once(0))
+                .shortest_path(|v| v == t);
+
+            if dist[t] == usize::MAX {
+                assert_eq!(path, None);
+            } else {
+                let weight = path
+                    .unwrap()
+                    .windows(2)
+                    .map(|w| *digraph.arc_weight(w[0], w[1]).unwrap())
+                    .sum::<usize>();
+
+                assert_eq!(weight, dist[t]);
+            }
+        }
+    }
+}
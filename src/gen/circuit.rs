@@ -199,6 +199,7 @@ macro_rules! proptest_circuit {
             $crate::{
                 Degree,
                 IsBalanced,
+                IsEulerian,
                 IsIsolated,
                 IsOriented,
                 IsPendant,
@@ -298,6 +299,11 @@ macro_rules! proptest_circuit {
                 assert!((order < 3) == <$type>::circuit(order).is_complete());
             }
 
+            #[test]
+            fn circuit_is_eulerian(order in 1..5_usize) {
+                assert!(<$type>::circuit(order).is_eulerian());
+            }
+
             #[test]
             fn circuit_is_isolated(order in 1..5_usize) {
                 let digraph = <$type>::circuit(order);
@@ -8,16 +8,37 @@
 //! - [Generators](#generators)
 //! - [Operations](#operations)
 //! - [Algorithms](#algorithms)
+//!    - [A* Search](#a-search)
 //!    - [Bellman-Ford-Moore](#bellman-ford-moore)
 //!    - [Breadth-First Search](#breadth-first-search)
+//!    - [Chu-Liu/Edmonds](#chu-liuedmonds)
 //!    - [Depth-First Search](#depth-first-search)
+//!    - [Dial](#dial)
 //!    - [Dijkstra](#dijkstra)
 //!    - [Distance Matrix](#distance-matrix)
+//!    - [Dominators](#dominators)
 //!    - [Floyd-Warshall](#floyd-warshall)
+//!    - [Hierholzer](#hierholzer)
+//!    - [Hopcroft-Karp](#hopcroft-karp)
+//!    - [Hopcroft-Tarjan](#hopcroft-tarjan)
+//!    - [Hungarian](#hungarian)
+//!    - [Italiano](#italiano)
 //!    - [Johnson's Circuit-Finding
 //!      Algorithm](#johnsons-circuit-finding-algorithm)
+//!    - [Johnson's All-Pairs Shortest-Path
+//!      Algorithm](#johnsons-all-pairs-shortest-path-algorithm)
+//!    - [Maximum Flow](#maximum-flow)
+//!    - [Menger](#menger)
+//!    - [Minimum Spanning Forest](#minimum-spanning-forest)
+//!    - [Minimum-Cost Flow](#minimum-cost-flow)
+//!    - [Path Matrix](#path-matrix)
 //!    - [Predecessor Tree](#predecessor-tree)
+//!    - [Shortest Path Faster Algorithm](#shortest-path-faster-algorithm)
+//!    - [Suurballe-Tarjan](#suurballe-tarjan)
 //!    - [Tarjan](#tarjan)
+//!    - [Weakly Connected Components](#weakly-connected-components)
+//!    - [Yen](#yen)
+//!    - [Zero-One Breadth-First Search](#zero-one-breadth-first-search)
 //!
 //! # Representations
 //!
@@ -64,12 +85,14 @@
 //! - [`FilterVertices`] filters a digraph's vertices.
 //! - [`HasArc`] checks whether a digraph contains an arc.
 //! - [`HasEdge`] checks whether a digraph contains an edge.
+//! - [`HasEulerianTrail`] checks whether a digraph contains an Eulerian trail.
 //! - [`HasWalk`] checks whether a digraph contains a walk.
 //! - [`InNeighbors`] iterates a vertex's in-neighbors.
 //! - [`IndegreeSequence`] iterates a digraph's indegrees.
 //! - [`Indegree`] returns a vertex's indegree.
 //! - [`IsBalanced`] checks whether a digraph is balanced.
 //! - [`IsComplete`] checks whether a digraph is complete.
+//! - [`IsEulerian`] checks whether a digraph is Eulerian.
 //! - [`IsIsolated`] checks whether a vertex is isolated.
 //! - [`IsOriented`] checks whether a digraph is oriented.
 //! - [`IsPendant`] checks whether a vertex is a pendant.
@@ -77,10 +100,12 @@
 //! - [`IsSemicomplete`] checks whether a digraph is semicomplete.
 //! - [`IsSimple`] checks whether a digraph is simple.
 //! - [`IsSpanningSubdigraph`] checks whether a digraph spans a superdigraph.
+//! - [`IsStronglyConnected`] checks whether a digraph is strongly connected.
 //! - [`IsSubdigraph`] checks whether a digraph is a subdigraph.
 //! - [`IsSuperdigraph`] checks whether a digraph is a superdigraph.
 //! - [`IsSymmetric`] checks whether a digraph is symmetric.
 //! - [`IsTournament`] checks whether a digraph is a tournament.
+//! - [`IsWeaklyConnected`] checks whether a digraph is weakly connected.
 //! - [`Order`] counts the vertices in a digraph.
 //! - [`OutNeighborsWeighted`] iterates a vertex's weighted out-neighbors.
 //! - [`OutNeighbors`] iterates a vertex's out-neighbors.
@@ -96,11 +121,25 @@
 //!
 //! # Algorithms
 //!
+//! ## A* Search
+//!
+//! The A* algorithm finds the shortest path from one or more source vertices
+//! to a target vertex in an arc-weighted digraph, guided by a heuristic.
+//!
+//! - [`AStar`] iterates the vertices and their predecessors.
+//! - [`AStar::shortest_path`](AStar::shortest_path) finds the shortest path
+//!   and its weight.
+//!
 //! ## Bellman-Ford-Moore
 //!
 //! - [`BellmanFordMoore::distances`] finds the shortest distances from a
 //!   source vertex to all other vertices in an arc-weighted digraph with
 //!   negative weights.
+//! - [`BellmanFordMoore::predecessors`] finds the shortest-path predecessors
+//!   from a source vertex in an arc-weighted digraph with negative weights.
+//!
+//! A [`NegativeCircuit`] is returned if the digraph contains a negative
+//! circuit reachable from the source vertex.
 //!
 //! ## Breadth-First Search
 //!
@@ -116,6 +155,16 @@
 //! - [`BfsPred::predecessors`](BfsPred::predecessors) finds the predecessors.
 //! - [`BfsPred::shortest_path`](BfsPred::shortest_path) finds the shortest
 //!   path.
+//! - [`BidirectionalBfs::shortest_path`](BidirectionalBfs::shortest_path)
+//!   finds the shortest path between two vertices.
+//!
+//! ## Chu-Liu/Edmonds
+//!
+//! An [`Arborescence`] contains the predecessor tree and total weight of a
+//! minimum-weight arborescence.
+//!
+//! - [`ChuLiuEdmonds::min_arborescence`] finds a minimum-weight arborescence
+//!   rooted at a vertex.
 //!
 //! ## Depth-First Search
 //!
@@ -127,12 +176,38 @@
 //! - [`DfsPred`] iterates the vertices and their predecessors.
 //! - [`DfsPred::predecessors`](DfsPred::predecessors) finds the predecessors.
 //!
+//! ## Dial
+//!
+//! Dial's algorithm finds the shortest paths from one or more source vertices
+//! in an arc-weighted digraph with small integer weights.
+//!
+//! - [`DialDist`] iterates the vertices and their distance from the source.
+//! - [`DialPred`] iterates the vertices and their predecessors.
+//! - [`DialDist::distances`](DialDist::distances) finds the shortest
+//!   distances.
+//! - [`DialPred::predecessors`](DialPred::predecessors) finds the
+//!   predecessors.
+//! - [`DialPred::shortest_path`](DialPred::shortest_path) finds the shortest
+//!   path.
+//!
 //! ## Dijkstra
 //!
 //! Dijkstra's algorithm finds the shortest paths from one or more source
 //! vertices in an arc-weighted digraph.
 //!
 //! - [`Dijkstra`] iterates the vertices.
+//! - [`Dijkstra::max_distance`](Dijkstra::max_distance),
+//!   [`DijkstraDist::max_distance`](DijkstraDist::max_distance), and
+//!   [`DijkstraPred::max_distance`](DijkstraPred::max_distance) skip the
+//!   vertices beyond a distance.
+//! - [`Dijkstra::reset`](Dijkstra::reset),
+//!   [`DijkstraDist::reset`](DijkstraDist::reset), and
+//!   [`DijkstraPred::reset`](DijkstraPred::reset) restart the search from new
+//!   sources.
+//! - [`Dijkstra::targets`](Dijkstra::targets),
+//!   [`DijkstraDist::targets`](DijkstraDist::targets), and
+//!   [`DijkstraPred::targets`](DijkstraPred::targets) stop the search after
+//!   visiting the target vertices.
 //! - [`DijkstraDist`] iterates the vertices and their distance from the
 //!   source.
 //! - [`DijkstraPred`] iterates the vertices and their predecessors.
@@ -142,31 +217,152 @@
 //!   predecessors.
 //! - [`DijkstraPred::shortest_path`](DijkstraPred::shortest_path) finds the
 //!   shortest path.
+//! - [`BidirectionalDijkstra::shortest_path`] finds the shortest path between
+//!   two vertices.
 //!
 //! ## Distance Matrix
 //!
 //! A [`DistanceMatrix`] contains the shortest distances between all vertex
 //! pairs in a digraph.
 //!
+//! - [`DistanceMatrix::average_path_length`] finds the digraph's average
+//!   shortest path length.
 //! - [`DistanceMatrix::center`](DistanceMatrix::center) finds the digraph's
 //!   center.
+//! - [`DistanceMatrix::closeness_centrality`] finds the vertices' closeness
+//!   centralities.
 //! - [`DistanceMatrix::diameter`](DistanceMatrix::diameter) finds the
 //!   digraph's diameter.
-//! - [`DistanceMatrix::eccentricities`](DistanceMatrix::eccentricities) finds
-//!   the vertices' eccentricities.
+//! - [`DistanceMatrix::eccentricities`](DistanceMatrix::eccentricities)
+//!   returns the vertices' eccentricities.
+//! - [`DistanceMatrix::girth`](DistanceMatrix::girth) finds the digraph's
+//!   girth.
+//! - [`DistanceMatrix::harmonic_centrality`] finds the vertices' harmonic
+//!   centralities.
 //! - [`DistanceMatrix::is_connected`](DistanceMatrix::is_connected) checks the
 //!   digraph's connectedness.
 //! - [`DistanceMatrix::periphery`](DistanceMatrix::periphery) finds the
 //!   digraph's periphery.
+//! - [`DistanceMatrix::radius`](DistanceMatrix::radius) finds the digraph's
+//!   radius.
+//! - [`DistanceMatrix::reachability_counts`] counts the vertices reachable
+//!   from each vertex.
+//! - [`DistanceMatrix::wiener_index`](DistanceMatrix::wiener_index) finds the
+//!   digraph's Wiener index.
+//!
+//! ## Dominators
+//!
+//! - [`Dominators::frontiers`] finds the dominance frontier of each vertex.
+//! - [`Dominators::tree`] finds the immediate dominator of each vertex.
+//!
+//! A [`DominatorTree`] contains the immediate dominators.
+//!
+//! - [`DominatorTree::dominates`] checks whether a vertex dominates another.
+//! - [`DominatorTree::idom`] returns a vertex's immediate dominator.
 //!
 //! ## Floyd-Warshall
 //!
 //! - [`FloydWarshall::distances`] finds the distance between each vertex pair
 //!   in an arc-weighted digraph.
+//! - [`FloydWarshall::paths`] finds the next vertex on a shortest path between
+//!   each vertex pair in an arc-weighted digraph.
+//!
+//! ## Hierholzer
+//!
+//! - [`Hierholzer::circuit`] finds an Eulerian circuit.
+//! - [`Hierholzer::trail`] finds an Eulerian trail.
+//!
+//! ## Hopcroft-Karp
+//!
+//! A [`Matching`] contains the pairs of a maximum matching and a minimum
+//! vertex cover.
+//!
+//! - [`HopcroftKarp::max_matching`] finds a maximum matching in a bipartite
+//!   digraph.
+//!
+//! ## Hopcroft-Tarjan
+//!
+//! - [`HopcroftTarjan::articulation_points`] finds the articulation points of
+//!   a digraph's underlying graph.
+//! - [`HopcroftTarjan::biconnected_components`] finds the biconnected
+//!   components of a digraph's underlying graph.
+//! - [`HopcroftTarjan::bridges`] finds the bridges of a digraph's underlying
+//!   graph.
+//! - [`HopcroftTarjan::two_edge_connected_components`] finds the
+//!   2-edge-connected components of a digraph's underlying graph.
+//!
+//! ## Hungarian
+//!
+//! An [`Assignment`] contains the pairs and total cost of a minimum-cost
+//! assignment.
+//!
+//! - [`Hungarian::assignment`] finds a minimum-cost assignment in a bipartite
+//!   digraph.
+//! - [`HungarianMatrix::assignment`] assigns the rows of a square cost matrix
+//!   to its columns.
+//!
+//! ## Italiano
+//!
+//! - [`Italiano::strong_articulation_points`] finds a digraph's strong
+//!   articulation points.
+//! - [`Italiano::strong_bridges`] finds a digraph's strong bridges.
 //!
 //! ## Johnson's Circuit-Finding Algorithm
 //!
-//! - [`Johnson75::circuits`] finds a digraph's circuits.
+//! - [`Johnson75`] iterates over a digraph's circuits.
+//! - [`Johnson75::max_count`] bounds the number of circuits.
+//! - [`Johnson75::max_length`] bounds the length of the circuits.
+//!
+//! ## Johnson's All-Pairs Shortest-Path Algorithm
+//!
+//! - [`Johnson77::distances`] finds the distance between each vertex pair in
+//!   an arc-weighted digraph with negative weights.
+//!
+//! ## Maximum Flow
+//!
+//! A [`MaxFlow`] contains the value of a maximum flow, the flow on each arc,
+//! and the residual digraph.
+//!
+//! - [`Dinic::max_flow`] finds a maximum flow with blocking flows.
+//! - [`PushRelabel::max_flow`] finds a maximum flow with highest-label
+//!   push-relabel.
+//! - [`MaxFlow::min_cut`] finds a minimum cut: the source side and the arcs
+//!   leaving it.
+//!
+//! ## Menger
+//!
+//! - [`Menger::arc_disjoint_paths`] finds a maximum set of arc-disjoint paths
+//!   between two vertices.
+//! - [`Menger::vertex_disjoint_paths`] finds a maximum set of internally
+//!   vertex-disjoint paths between two vertices.
+//!
+//! ## Minimum Spanning Forest
+//!
+//! A [`SpanningForest`] contains the edges and total weight of a minimum
+//! spanning forest of a symmetric digraph.
+//!
+//! - [`Kruskal::min_spanning_forest`] finds a minimum spanning forest with a
+//!   disjoint-set forest.
+//! - [`Prim::min_spanning_forest`] finds a minimum spanning forest with a
+//!   binary heap.
+//!
+//! ## Minimum-Cost Flow
+//!
+//! A [`MinCostFlow`] contains the value and cost of a minimum-cost maximum
+//! flow and the flow on each arc. The arc weights are `(capacity, cost)`
+//! pairs.
+//!
+//! - [`NetworkSimplex::min_cost_max_flow`] finds a minimum-cost maximum flow
+//!   with the network simplex method.
+//! - [`SuccessiveShortestPaths::min_cost_max_flow`] finds a minimum-cost
+//!   maximum flow with successive shortest paths.
+//!
+//! ## Path Matrix
+//!
+//! A [`PathMatrix`] contains the next vertex on a shortest path between each
+//! vertex pair.
+//!
+//! - [`PathMatrix::path`] returns a shortest path between two vertices.
 //!
 //! ## Predecessor Tree
 //!
@@ -176,9 +372,52 @@
 //! - [`PredecessorTree::search`] finds a vertex by value.
 //! - [`PredecessorTree::search_by`] finds a vertex by predicate.
 //!
+//! ## Shortest Path Faster Algorithm
+//!
+//! - [`Spfa::distances`] finds the shortest distances from a source vertex to
+//!   all other vertices in an arc-weighted digraph with negative weights.
+//! - [`Spfa::predecessors`] finds the shortest-path predecessors from a source
+//!   vertex in an arc-weighted digraph with negative weights.
+//!
+//! ## Suurballe-Tarjan
+//!
+//! - [`Suurballe::arc_disjoint_paths`] finds `k` arc-disjoint paths between
+//!   two vertices with minimum total weight.
+//! - [`Suurballe::vertex_disjoint_paths`] finds `k` internally vertex-disjoint
+//!   paths between two vertices with minimum total weight.
+//!
 //! ## Tarjan
 //!
 //! - [`Tarjan::components`] finds strongly connected components in a digraph.
+//!
+//! ## Weakly Connected Components
+//!
+//! - [`WeakComponents::labels`] labels each vertex with its weakly connected
+//!   component.
+//! - [`WeakComponents::sizes`] counts the vertices in each weakly connected
+//!   component.
+//!
+//! ## Yen
+//!
+//! Yen's algorithm finds the shortest loopless paths between two vertices in
+//! an arc-weighted digraph.
+//!
+//! - [`Yen`] iterates the loopless paths in order of their weight.
+//!
+//! ## Zero-One Breadth-First Search
+//!
+//! A 0-1 breadth-first search finds the shortest paths from one or more
+//! source vertices in an arc-weighted digraph with weights `0` and `1`.
+//!
+//! - [`ZeroOneBfsDist`] iterates the vertices and their distance from the
+//!   source.
+//! - [`ZeroOneBfsPred`] iterates the vertices and their predecessors.
+//! - [`ZeroOneBfsDist::distances`](ZeroOneBfsDist::distances) finds the
+//!   shortest distances.
+//! - [`ZeroOneBfsPred::predecessors`](ZeroOneBfsPred::predecessors) finds the
+//!   predecessors.
+//! - [`ZeroOneBfsPred::shortest_path`](ZeroOneBfsPred::shortest_path) finds
+//!   the shortest path.
 
 pub mod algo;
 pub mod r#gen;
@@ -209,12 +448,14 @@ pub use op::{
     FilterVertices,
     HasArc,
     HasEdge,
+    HasEulerianTrail,
     HasWalk,
     InNeighbors,
     Indegree,
     IndegreeSequence,
     IsBalanced,
     IsComplete,
+    IsEulerian,
     IsIsolated,
     IsOriented,
     IsPendant,
@@ -222,10 +463,12 @@ pub use op::{
     IsSemicomplete,
     IsSimple,
     IsSpanningSubdigraph,
+    IsStronglyConnected,
     IsSubdigraph,
     IsSuperdigraph,
     IsSymmetric,
     IsTournament,
+    IsWeaklyConnected,
     Order,
     OutNeighbors,
     OutNeighborsWeighted,
@@ -255,19 +498,72 @@ pub use r#gen::{
 };
 
 pub use algo::{
-    bellman_ford_moore::BellmanFordMoore,
+    a_star::AStar,
+    bellman_ford_moore::{
+        BellmanFordMoore,
+        NegativeCircuit,
+    },
     bfs::Bfs,
     bfs_dist::BfsDist,
     bfs_pred::BfsPred,
+    bidirectional_bfs::BidirectionalBfs,
+    bidirectional_dijkstra::BidirectionalDijkstra,
+    chu_liu_edmonds::{
+        Arborescence,
+        ChuLiuEdmonds,
+        Unreachable,
+    },
     dfs::Dfs,
     dfs_dist::DfsDist,
     dfs_pred::DfsPred,
+    dial_dist::DialDist,
+    dial_pred::DialPred,
     dijkstra::Dijkstra,
     dijkstra_dist::DijkstraDist,
     dijkstra_pred::DijkstraPred,
     distance_matrix::DistanceMatrix,
+    dominators::{
+        DominatorTree,
+        Dominators,
+    },
+    flow::{
+        MaxFlow,
+        MinCostFlow,
+        MinCut,
+        dinic::Dinic,
+        network_simplex::NetworkSimplex,
+        push_relabel::PushRelabel,
+        successive_shortest_paths::SuccessiveShortestPaths,
+    },
     floyd_warshall::FloydWarshall,
+    hierholzer::Hierholzer,
+    hopcroft_karp::{
+        HopcroftKarp,
+        Matching,
+    },
+    hopcroft_tarjan::HopcroftTarjan,
+    hungarian::{
+        Assignment,
+        Hungarian,
+        HungarianMatrix,
+    },
+    italiano::Italiano,
     johnson_75::Johnson75,
+    johnson_77::Johnson77,
+    menger::Menger,
+    path_matrix::PathMatrix,
     predecessor_tree::PredecessorTree,
+    spanning_forest::{
+        Asymmetric,
+        SpanningForest,
+        kruskal::Kruskal,
+        prim::Prim,
+    },
+    spfa::Spfa,
+    suurballe::Suurballe,
     tarjan::Tarjan,
+    weak_components::WeakComponents,
+    yen::Yen,
+    zero_one_bfs_dist::ZeroOneBfsDist,
+    zero_one_bfs_pred::ZeroOneBfsPred,
 };
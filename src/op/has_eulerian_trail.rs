@@ -0,0 +1,200 @@
+//! Check whether a digraph contains an Eulerian trail.
+//!
+//! An Eulerian trail is a trail that traverses each arc exactly once. A
+//! digraph contains an Eulerian trail if and only if its non-isolated
+//! vertices are weakly connected and it's either balanced or balanced except
+//! for one vertex whose outdegree exceeds its indegree by one and one vertex
+//! whose indegree exceeds its outdegree by one.
+//!
+//! # Examples
+//!
+//! ```
+//! use graaf::{
+//!     AddArc,
+//!     AdjacencyList,
+//!     Empty,
+//!     HasEulerianTrail,
+//! };
+//!
+//! let mut digraph = AdjacencyList::empty(4);
+//!
+//! digraph.add_arc(0, 1);
+//! digraph.add_arc(1, 2);
+//! digraph.add_arc(3, 2);
+//!
+//! assert!(!digraph.has_eulerian_trail());
+//!
+//! digraph.add_arc(2, 3);
+//!
+//! assert!(digraph.has_eulerian_trail());
+//! ```
+
+use {
+    super::is_eulerian::arcs_are_weakly_connected,
+    crate::{
+        Arcs,
+        Indegree,
+        Order,
+        Outdegree,
+        Vertices,
+    },
+};
+
+/// Check whether a digraph contains an Eulerian trail.
+pub trait HasEulerianTrail {
+    /// Check whether the digraph contains an Eulerian trail.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use graaf::{
+    ///     AddArc,
+    ///     AdjacencyList,
+    ///     Empty,
+    ///     HasEulerianTrail,
+    /// };
+    ///
+    /// let mut digraph = AdjacencyList::empty(4);
+    ///
+    /// digraph.add_arc(0, 1);
+    /// digraph.add_arc(1, 2);
+    /// digraph.add_arc(3, 2);
+    ///
+    /// assert!(!digraph.has_eulerian_trail());
+    ///
+    /// digraph.add_arc(2, 3);
+    ///
+    /// assert!(digraph.has_eulerian_trail());
+    /// ```
+    #[must_use]
+    fn has_eulerian_trail(&self) -> bool;
+}
+
+impl<D> HasEulerianTrail for D
+where
+    D: Arcs + Indegree + Order + Outdegree + Vertices,
+{
+    fn has_eulerian_trail(&self) -> bool {
+        if !arcs_are_weakly_connected(self) {
+            return false;
+        }
+
+        let mut starts = 0;
+        let mut ends = 0;
+
+        for u in self.vertices() {
+            let indegree = self.indegree(u);
+            let outdegree = self.outdegree(u);
+
+            if outdegree == indegree + 1 {
+                starts += 1;
+            } else if indegree == outdegree + 1 {
+                ends += 1;
+            } else if indegree != outdegree {
+                return false;
+            }
+        }
+
+        starts == ends && starts <= 1
+    }
+}
+
+/// `HasEulerianTrail` tests
+#[macro_export]
+macro_rules! test_has_eulerian_trail {
+    ($fixture:path) => {
+        use $fixture::{
+            bang_jensen_34,
+            bang_jensen_94,
+            bang_jensen_196,
+            kattis_builddeps,
+            kattis_cantinaofbabel_1,
+            kattis_cantinaofbabel_2,
+            kattis_escapewallmaria_1,
+            kattis_escapewallmaria_2,
+            kattis_escapewallmaria_3,
+        };
+
+        #[test]
+        fn has_eulerian_trail_bang_jensen_196() {
+            assert!(!bang_jensen_196().has_eulerian_trail());
+        }
+
+        #[test]
+        fn has_eulerian_trail_bang_jensen_34() {
+            assert!(!bang_jensen_34().has_eulerian_trail());
+        }
+
+        #[test]
+        fn has_eulerian_trail_bang_jensen_94() {
+            assert!(!bang_jensen_94().has_eulerian_trail());
+        }
+
+        #[test]
+        fn has_eulerian_trail_kattis_builddeps() {
+            assert!(!kattis_builddeps().has_eulerian_trail());
+        }
+
+        #[test]
+        fn has_eulerian_trail_kattis_cantinaofbabel_1() {
+            assert!(!kattis_cantinaofbabel_1().has_eulerian_trail());
+        }
+
+        #[test]
+        fn has_eulerian_trail_kattis_cantinaofbabel_2() {
+            assert!(!kattis_cantinaofbabel_2().has_eulerian_trail());
+        }
+
+        #[test]
+        fn has_eulerian_trail_kattis_escapewallmaria_1() {
+            assert!(kattis_escapewallmaria_1().has_eulerian_trail());
+        }
+
+        #[test]
+        fn has_eulerian_trail_kattis_escapewallmaria_2() {
+            assert!(kattis_escapewallmaria_2().has_eulerian_trail());
+        }
+
+        #[test]
+        fn has_eulerian_trail_kattis_escapewallmaria_3() {
+            assert!(kattis_escapewallmaria_3().has_eulerian_trail());
+        }
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::*,
+        crate::{
+            AddArc,
+            AdjacencyList,
+            Empty,
+        },
+    };
+
+    #[test]
+    fn has_eulerian_trail_empty() {
+        assert!(AdjacencyList::empty(3).has_eulerian_trail());
+    }
+
+    #[test]
+    fn has_eulerian_trail_path_isolated_vertex() {
+        let mut digraph = AdjacencyList::empty(4);
+
+        digraph.add_arc(1, 2);
+        digraph.add_arc(2, 3);
+
+        assert!(digraph.has_eulerian_trail());
+    }
+
+    #[test]
+    fn has_eulerian_trail_two_paths() {
+        let mut digraph = AdjacencyList::empty(4);
+
+        digraph.add_arc(0, 1);
+        digraph.add_arc(2, 3);
+
+        assert!(!digraph.has_eulerian_trail());
+    }
+}
@@ -0,0 +1,189 @@
+//! Check whether a digraph is Eulerian.
+//!
+//! A digraph is Eulerian if it contains a closed trail that traverses each arc
+//! exactly once. A digraph is Eulerian if and only if it's balanced and its
+//! non-isolated vertices are weakly connected.
+//!
+//! # Examples
+//!
+//! ```
+//! use graaf::{
+//!     AddArc,
+//!     AdjacencyList,
+//!     Empty,
+//!     IsEulerian,
+//! };
+//!
+//! let mut digraph = AdjacencyList::empty(3);
+//!
+//! digraph.add_arc(0, 1);
+//! digraph.add_arc(1, 2);
+//!
+//! assert!(!digraph.is_eulerian());
+//!
+//! digraph.add_arc(2, 0);
+//!
+//! assert!(digraph.is_eulerian());
+//! ```
+
+use crate::{
+    Arcs,
+    IsBalanced,
+    Order,
+    WeakComponents,
+};
+
+/// Check whether a digraph is Eulerian.
+pub trait IsEulerian {
+    /// Check whether the digraph is Eulerian.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use graaf::{
+    ///     AddArc,
+    ///     AdjacencyList,
+    ///     Empty,
+    ///     IsEulerian,
+    /// };
+    ///
+    /// let mut digraph = AdjacencyList::empty(3);
+    ///
+    /// digraph.add_arc(0, 1);
+    /// digraph.add_arc(1, 2);
+    ///
+    /// assert!(!digraph.is_eulerian());
+    ///
+    /// digraph.add_arc(2, 0);
+    ///
+    /// assert!(digraph.is_eulerian());
+    /// ```
+    #[must_use]
+    fn is_eulerian(&self) -> bool;
+}
+
+impl<D> IsEulerian for D
+where
+    D: Arcs + IsBalanced + Order,
+{
+    fn is_eulerian(&self) -> bool {
+        self.is_balanced() && arcs_are_weakly_connected(self)
+    }
+}
+
+/// Check whether the non-isolated vertices of a digraph are weakly connected.
+pub(crate) fn arcs_are_weakly_connected<D>(digraph: &D) -> bool
+where
+    D: Arcs + Order,
+{
+    // Without self-loops, isolated vertices form the singleton components.
+    WeakComponents::new(digraph)
+        .sizes()
+        .iter()
+        .filter(|&&size| size > 1)
+        .count()
+        <= 1
+}
+
+/// `IsEulerian` tests
+#[macro_export]
+macro_rules! test_is_eulerian {
+    ($fixture:path) => {
+        use $fixture::{
+            bang_jensen_34,
+            bang_jensen_94,
+            bang_jensen_196,
+            kattis_builddeps,
+            kattis_cantinaofbabel_1,
+            kattis_cantinaofbabel_2,
+            kattis_escapewallmaria_1,
+            kattis_escapewallmaria_2,
+            kattis_escapewallmaria_3,
+        };
+
+        #[test]
+        fn is_eulerian_bang_jensen_196() {
+            assert!(!bang_jensen_196().is_eulerian());
+        }
+
+        #[test]
+        fn is_eulerian_bang_jensen_34() {
+            assert!(!bang_jensen_34().is_eulerian());
+        }
+
+        #[test]
+        fn is_eulerian_bang_jensen_94() {
+            assert!(!bang_jensen_94().is_eulerian());
+        }
+
+        #[test]
+        fn is_eulerian_kattis_builddeps() {
+            assert!(!kattis_builddeps().is_eulerian());
+        }
+
+        #[test]
+        fn is_eulerian_kattis_cantinaofbabel_1() {
+            assert!(!kattis_cantinaofbabel_1().is_eulerian());
+        }
+
+        #[test]
+        fn is_eulerian_kattis_cantinaofbabel_2() {
+            assert!(!kattis_cantinaofbabel_2().is_eulerian());
+        }
+
+        #[test]
+        fn is_eulerian_kattis_escapewallmaria_1() {
+            assert!(!kattis_escapewallmaria_1().is_eulerian());
+        }
+
+        #[test]
+        fn is_eulerian_kattis_escapewallmaria_2() {
+            assert!(!kattis_escapewallmaria_2().is_eulerian());
+        }
+
+        #[test]
+        fn is_eulerian_kattis_escapewallmaria_3() {
+            assert!(kattis_escapewallmaria_3().is_eulerian());
+        }
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::*,
+        crate::{
+            AddArc,
+            AdjacencyList,
+            Empty,
+        },
+    };
+
+    #[test]
+    fn is_eulerian_circuit_isolated_vertex() {
+        let mut digraph = AdjacencyList::empty(4);
+
+        digraph.add_arc(1, 2);
+        digraph.add_arc(2, 3);
+        digraph.add_arc(3, 1);
+
+        assert!(digraph.is_eulerian());
+    }
+
+    #[test]
+    fn is_eulerian_empty() {
+        assert!(AdjacencyList::empty(3).is_eulerian());
+    }
+
+    #[test]
+    fn is_eulerian_two_circuits() {
+        let mut digraph = AdjacencyList::empty(4);
+
+        digraph.add_arc(0, 1);
+        digraph.add_arc(1, 0);
+        digraph.add_arc(2, 3);
+        digraph.add_arc(3, 2);
+
+        assert!(!digraph.is_eulerian());
+    }
+}
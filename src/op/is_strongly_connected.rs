@@ -0,0 +1,198 @@
+//! Check whether a digraph is strongly connected.
+//!
+//! A digraph is strongly connected if every vertex is reachable from every
+//! other vertex. One breadth-first search along the out-neighbors and one
+//! along the in-neighbors of a vertex decide strong connectivity without
+//! computing a [`DistanceMatrix`](crate::DistanceMatrix).
+//!
+//! The time complexity is `O(v + a)`, where `v` is the digraph's order and
+//! `a` is the digraph's size.
+//!
+//! # Examples
+//!
+//! ```
+//! use graaf::{
+//!     AddArc,
+//!     AdjacencyList,
+//!     Circuit,
+//!     IsStronglyConnected,
+//!     RemoveArc,
+//! };
+//!
+//! let mut digraph = AdjacencyList::circuit(4);
+//!
+//! assert!(digraph.is_strongly_connected());
+//!
+//! digraph.remove_arc(3, 0);
+//!
+//! assert!(!digraph.is_strongly_connected());
+//!
+//! digraph.add_arc(3, 2);
+//! digraph.add_arc(2, 1);
+//! digraph.add_arc(1, 0);
+//!
+//! assert!(digraph.is_strongly_connected());
+//! ```
+
+use {
+    crate::{
+        Bfs,
+        InNeighbors,
+        Order,
+        OutNeighbors,
+    },
+    std::iter::once,
+};
+
+/// A digraph's in-neighbors exposed as out-neighbors.
+struct Reversed<'a, D>(&'a D);
+
+impl<D> Order for Reversed<'_, D>
+where
+    D: Order,
+{
+    fn order(&self) -> usize {
+        self.0.order()
+    }
+}
+
+impl<D> OutNeighbors for Reversed<'_, D>
+where
+    D: InNeighbors,
+{
+    fn out_neighbors(&self, u: usize) -> impl Iterator<Item = usize> {
+        self.0.in_neighbors(u)
+    }
+}
+
+/// Check whether a digraph is strongly connected.
+pub trait IsStronglyConnected {
+    /// Check whether the digraph is strongly connected.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use graaf::{
+    ///     AddArc,
+    ///     AdjacencyList,
+    ///     Circuit,
+    ///     IsStronglyConnected,
+    ///     RemoveArc,
+    /// };
+    ///
+    /// let mut digraph = AdjacencyList::circuit(4);
+    ///
+    /// assert!(digraph.is_strongly_connected());
+    ///
+    /// digraph.remove_arc(3, 0);
+    ///
+    /// assert!(!digraph.is_strongly_connected());
+    ///
+    /// digraph.add_arc(3, 2);
+    /// digraph.add_arc(2, 1);
+    /// digraph.add_arc(1, 0);
+    ///
+    /// assert!(digraph.is_strongly_connected());
+    /// ```
+    #[must_use]
+    fn is_strongly_connected(&self) -> bool;
+}
+
+impl<D> IsStronglyConnected for D
+where
+    D: InNeighbors + Order + OutNeighbors,
+{
+    fn is_strongly_connected(&self) -> bool {
+        let order = self.order();
+
+        if order == 0 {
+            return true;
+        }
+
+        Bfs::new(self, once(0)).count() == order
+            && Bfs::new(&Reversed(self), once(0)).count() == order
+    }
+}
+
+/// `IsStronglyConnected` tests
+#[macro_export]
+macro_rules! test_is_strongly_connected {
+    ($type:ty, $fixture:path) => {
+        use $fixture::{
+            bang_jensen_34,
+            bang_jensen_94,
+            bang_jensen_196,
+            kattis_builddeps,
+            kattis_cantinaofbabel_1,
+            kattis_cantinaofbabel_2,
+            kattis_escapewallmaria_1,
+            kattis_escapewallmaria_2,
+            kattis_escapewallmaria_3,
+        };
+
+        #[test]
+        fn is_strongly_connected_bang_jensen_196() {
+            assert!(!bang_jensen_196().is_strongly_connected());
+        }
+
+        #[test]
+        fn is_strongly_connected_bang_jensen_34() {
+            assert!(!bang_jensen_34().is_strongly_connected());
+        }
+
+        #[test]
+        fn is_strongly_connected_bang_jensen_94() {
+            assert!(!bang_jensen_94().is_strongly_connected());
+        }
+
+        #[test]
+        fn is_strongly_connected_circuit() {
+            assert!(<$type>::circuit(5).is_strongly_connected());
+        }
+
+        #[test]
+        fn is_strongly_connected_cycle() {
+            assert!(<$type>::cycle(5).is_strongly_connected());
+        }
+
+        #[test]
+        fn is_strongly_connected_kattis_builddeps() {
+            assert!(!kattis_builddeps().is_strongly_connected());
+        }
+
+        #[test]
+        fn is_strongly_connected_kattis_cantinaofbabel_1() {
+            assert!(!kattis_cantinaofbabel_1().is_strongly_connected());
+        }
+
+        #[test]
+        fn is_strongly_connected_kattis_cantinaofbabel_2() {
+            assert!(!kattis_cantinaofbabel_2().is_strongly_connected());
+        }
+
+        #[test]
+        fn is_strongly_connected_kattis_escapewallmaria_1() {
+            assert!(!kattis_escapewallmaria_1().is_strongly_connected());
+        }
+
+        #[test]
+        fn is_strongly_connected_kattis_escapewallmaria_2() {
+            assert!(!kattis_escapewallmaria_2().is_strongly_connected());
+        }
+
+        #[test]
+        fn is_strongly_connected_kattis_escapewallmaria_3() {
+            assert!(!kattis_escapewallmaria_3().is_strongly_connected());
+        }
+
+        #[test]
+        fn is_strongly_connected_path() {
+            assert!(!<$type>::path(5).is_strongly_connected());
+        }
+
+        #[test]
+        fn is_strongly_connected_trivial() {
+            assert!(<$type>::trivial().is_strongly_connected());
+        }
+    };
+}
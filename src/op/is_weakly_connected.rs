@@ -0,0 +1,131 @@
+//! Check whether a digraph is weakly connected.
+//!
+//! A digraph is weakly connected if its underlying undirected graph is
+//! connected.
+//!
+//! # Examples
+//!
+//! ```
+//! use graaf::{
+//!     AddArc,
+//!     AdjacencyList,
+//!     Empty,
+//!     IsWeaklyConnected,
+//! };
+//!
+//! let mut digraph = AdjacencyList::empty(3);
+//!
+//! digraph.add_arc(0, 1);
+//!
+//! assert!(!digraph.is_weakly_connected());
+//!
+//! digraph.add_arc(2, 1);
+//!
+//! assert!(digraph.is_weakly_connected());
+//! ```
+
+use crate::{
+    Arcs,
+    Order,
+    WeakComponents,
+};
+
+/// Check whether a digraph is weakly connected.
+pub trait IsWeaklyConnected {
+    /// Check whether the digraph is weakly connected.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use graaf::{
+    ///     AddArc,
+    ///     AdjacencyList,
+    ///     Empty,
+    ///     IsWeaklyConnected,
+    /// };
+    ///
+    /// let mut digraph = AdjacencyList::empty(3);
+    ///
+    /// digraph.add_arc(0, 1);
+    ///
+    /// assert!(!digraph.is_weakly_connected());
+    ///
+    /// digraph.add_arc(2, 1);
+    ///
+    /// assert!(digraph.is_weakly_connected());
+    /// ```
+    #[must_use]
+    fn is_weakly_connected(&self) -> bool;
+}
+
+impl<D> IsWeaklyConnected for D
+where
+    D: Arcs + Order,
+{
+    fn is_weakly_connected(&self) -> bool {
+        WeakComponents::new(self).sizes().len() <= 1
+    }
+}
+
+/// `IsWeaklyConnected` tests
+#[macro_export]
+macro_rules! test_is_weakly_connected {
+    ($fixture:path) => {
+        use $fixture::{
+            bang_jensen_34,
+            bang_jensen_94,
+            bang_jensen_196,
+            kattis_builddeps,
+            kattis_cantinaofbabel_1,
+            kattis_cantinaofbabel_2,
+            kattis_escapewallmaria_1,
+            kattis_escapewallmaria_2,
+            kattis_escapewallmaria_3,
+        };
+
+        #[test]
+        fn is_weakly_connected_bang_jensen_196() {
+            assert!(bang_jensen_196().is_weakly_connected());
+        }
+
+        #[test]
+        fn is_weakly_connected_bang_jensen_34() {
+            assert!(bang_jensen_34().is_weakly_connected());
+        }
+
+        #[test]
+        fn is_weakly_connected_bang_jensen_94() {
+            assert!(bang_jensen_94().is_weakly_connected());
+        }
+
+        #[test]
+        fn is_weakly_connected_kattis_builddeps() {
+            assert!(kattis_builddeps().is_weakly_connected());
+        }
+
+        #[test]
+        fn is_weakly_connected_kattis_cantinaofbabel_1() {
+            assert!(kattis_cantinaofbabel_1().is_weakly_connected());
+        }
+
+        #[test]
+        fn is_weakly_connected_kattis_cantinaofbabel_2() {
+            assert!(kattis_cantinaofbabel_2().is_weakly_connected());
+        }
+
+        #[test]
+        fn is_weakly_connected_kattis_escapewallmaria_1() {
+            assert!(!kattis_escapewallmaria_1().is_weakly_connected());
+        }
+
+        #[test]
+        fn is_weakly_connected_kattis_escapewallmaria_2() {
+            assert!(!kattis_escapewallmaria_2().is_weakly_connected());
+        }
+
+        #[test]
+        fn is_weakly_connected_kattis_escapewallmaria_3() {
+            assert!(!kattis_escapewallmaria_3().is_weakly_connected());
+        }
+    };
+}
@@ -42,12 +42,14 @@ pub mod degree_sequence;
 pub mod filter_vertices;
 pub mod has_arc;
 pub mod has_edge;
+pub mod has_eulerian_trail;
 pub mod has_walk;
 pub mod in_neighbors;
 pub mod indegree;
 pub mod indegree_sequence;
 pub mod is_balanced;
 pub mod is_complete;
+pub mod is_eulerian;
 pub mod is_isolated;
 pub mod is_oriented;
 pub mod is_pendant;
@@ -55,10 +57,12 @@ pub mod is_regular;
 pub mod is_semicomplete;
 pub mod is_simple;
 pub mod is_spanning_subdigraph;
+pub mod is_strongly_connected;
 pub mod is_subdigraph;
 pub mod is_superdigraph;
 pub mod is_symmetric;
 pub mod is_tournament;
+pub mod is_weakly_connected;
 pub mod order;
 pub mod out_neighbors;
 pub mod out_neighbors_weighted;
@@ -86,12 +90,14 @@ pub use {
     filter_vertices::FilterVertices,
     has_arc::HasArc,
     has_edge::HasEdge,
+    has_eulerian_trail::HasEulerianTrail,
     has_walk::HasWalk,
     in_neighbors::InNeighbors,
     indegree::Indegree,
     indegree_sequence::IndegreeSequence,
     is_balanced::IsBalanced,
     is_complete::IsComplete,
+    is_eulerian::IsEulerian,
     is_isolated::IsIsolated,
     is_oriented::IsOriented,
     is_pendant::IsPendant,
@@ -99,10 +105,12 @@ pub use {
     is_semicomplete::IsSemicomplete,
     is_simple::IsSimple,
     is_spanning_subdigraph::IsSpanningSubdigraph,
+    is_strongly_connected::IsStronglyConnected,
     is_subdigraph::IsSubdigraph,
     is_superdigraph::IsSuperdigraph,
     is_symmetric::IsSymmetric,
     is_tournament::IsTournament,
+    is_weakly_connected::IsWeaklyConnected,
     order::Order,
     out_neighbors::OutNeighbors,
     out_neighbors_weighted::OutNeighborsWeighted,
@@ -1,6 +1,15 @@
 //! Proptest strategies
 
-use proptest::strategy::Strategy;
+use {
+    crate::{
+        AddArc,
+        AddArcWeighted,
+        AdjacencyList,
+        AdjacencyListWeighted,
+        Empty,
+    },
+    proptest::strategy::Strategy,
+};
 
 /// Generate an arc's head and tail.
 ///
@@ -10,3 +19,49 @@ use proptest::strategy::Strategy;
 pub fn arc(order: usize) -> impl Strategy<Value = (usize, usize)> {
     (1..order, 1..order).prop_filter("u != v", |(u, v)| u != v)
 }
+
+/// Build a digraph from generated arcs, skipping loops and arcs with a vertex
+/// outside the digraph.
+///
+/// # Arguments
+///
+/// * `order`: The digraph's order.
+/// * `arcs`: The arcs.
+#[must_use]
+pub(crate) fn digraph(order: usize, arcs: &[(usize, usize)]) -> AdjacencyList {
+    let mut digraph = AdjacencyList::empty(order);
+
+    for &(u, v) in arcs {
+        if u < order && v < order && u != v {
+            digraph.add_arc(u, v);
+        }
+    }
+
+    digraph
+}
+
+/// Build an arc-weighted digraph from generated arcs, skipping loops and arcs
+/// with a vertex outside the digraph.
+///
+/// # Arguments
+///
+/// * `order`: The digraph's order.
+/// * `arcs`: The arcs and their weights.
+#[must_use]
+pub(crate) fn digraph_weighted<W>(
+    order: usize,
+    arcs: &[(usize, usize, W)],
+) -> AdjacencyListWeighted<W>
+where
+    W: Copy,
+{
+    let mut digraph = AdjacencyListWeighted::empty(order);
+
+    for &(u, v, w) in arcs {
+        if u < order && v < order && u != v {
+            digraph.add_arc_weighted(u, v, w);
+        }
+    }
+
+    digraph
+}
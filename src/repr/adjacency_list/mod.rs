@@ -1511,6 +1511,16 @@ mod tests_erdos_renyi {
     test_erdos_renyi!(AdjacencyList);
 }
 
+#[cfg(test)]
+mod tests_has_eulerian_trail {
+    use crate::{
+        HasEulerianTrail,
+        test_has_eulerian_trail,
+    };
+
+    test_has_eulerian_trail!(crate::repr::adjacency_list::fixture);
+}
+
 #[cfg(test)]
 mod tests_has_walk {
     use {
@@ -1571,6 +1581,16 @@ mod tests_is_complete {
     test_is_complete!(crate::repr::adjacency_list::fixture);
 }
 
+#[cfg(test)]
+mod tests_is_eulerian {
+    use crate::{
+        IsEulerian,
+        test_is_eulerian,
+    };
+
+    test_is_eulerian!(crate::repr::adjacency_list::fixture);
+}
+
 #[cfg(test)]
 mod tests_is_isolated {
     use crate::{
@@ -1631,6 +1651,22 @@ mod tests_is_simple {
     test_is_simple!(crate::repr::adjacency_list::fixture);
 }
 
+#[cfg(test)]
+mod tests_is_strongly_connected {
+    use {
+        super::*,
+        crate::{
+            IsStronglyConnected,
+            test_is_strongly_connected,
+        },
+    };
+
+    test_is_strongly_connected!(
+        AdjacencyList,
+        crate::repr::adjacency_list::fixture
+    );
+}
+
 #[cfg(test)]
 mod tests_is_symmetric {
     use crate::{
@@ -1651,6 +1687,16 @@ mod tests_is_tournament {
     test_is_tournament!(crate::repr::adjacency_list::fixture);
 }
 
+#[cfg(test)]
+mod tests_is_weakly_connected {
+    use crate::{
+        IsWeaklyConnected,
+        test_is_weakly_connected,
+    };
+
+    test_is_weakly_connected!(crate::repr::adjacency_list::fixture);
+}
+
 #[cfg(test)]
 mod tests_order {
     use crate::{
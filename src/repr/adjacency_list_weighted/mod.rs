@@ -469,8 +469,10 @@ mod tests {
             IsOriented,
             IsPendant,
             IsSemicomplete,
+            IsStronglyConnected,
             IsSymmetric,
             IsTournament,
+            IsWeaklyConnected,
             SemidegreeSequence,
             repr::{
                 adjacency_list,
@@ -2031,6 +2033,51 @@ mod tests {
         assert!(!digraph.is_simple());
     }
 
+    #[test]
+    fn is_strongly_connected_bang_jensen_94_weighted() {
+        assert!(!bang_jensen_94_usize().is_strongly_connected());
+    }
+
+    #[test]
+    fn is_strongly_connected_bang_jensen_96() {
+        assert!(!bang_jensen_96_usize().is_strongly_connected());
+    }
+
+    #[test]
+    fn is_strongly_connected_bang_jensen_99() {
+        assert!(!bang_jensen_99().is_strongly_connected());
+    }
+
+    #[test]
+    fn is_strongly_connected_kattis_bryr_1() {
+        assert!(kattis_bryr_1_usize().is_strongly_connected());
+    }
+
+    #[test]
+    fn is_strongly_connected_kattis_bryr_2() {
+        assert!(kattis_bryr_2_usize().is_strongly_connected());
+    }
+
+    #[test]
+    fn is_strongly_connected_kattis_bryr_3() {
+        assert!(kattis_bryr_3_usize().is_strongly_connected());
+    }
+
+    #[test]
+    fn is_strongly_connected_kattis_crosscountry() {
+        assert!(kattis_crosscountry_usize().is_strongly_connected());
+    }
+
+    #[test]
+    fn is_strongly_connected_kattis_shortestpath1() {
+        assert!(!kattis_shortestpath1_usize().is_strongly_connected());
+    }
+
+    #[test]
+    fn is_strongly_connected_kattis_shortestpath3() {
+        assert!(!kattis_shortestpath3().is_strongly_connected());
+    }
+
     #[test]
     fn is_symmetric_bang_jensen_94_weighted() {
         assert!(!bang_jensen_94_usize().is_symmetric());
@@ -2111,6 +2158,51 @@ mod tests {
         assert!(!kattis_shortestpath1_usize().is_tournament());
     }
 
+    #[test]
+    fn is_weakly_connected_bang_jensen_94_weighted() {
+        assert!(bang_jensen_94_usize().is_weakly_connected());
+    }
+
+    #[test]
+    fn is_weakly_connected_bang_jensen_96() {
+        assert!(bang_jensen_96_usize().is_weakly_connected());
+    }
+
+    #[test]
+    fn is_weakly_connected_bang_jensen_99() {
+        assert!(bang_jensen_99().is_weakly_connected());
+    }
+
+    #[test]
+    fn is_weakly_connected_kattis_bryr_1() {
+        assert!(kattis_bryr_1_usize().is_weakly_connected());
+    }
+
+    #[test]
+    fn is_weakly_connected_kattis_bryr_2() {
+        assert!(kattis_bryr_2_usize().is_weakly_connected());
+    }
+
+    #[test]
+    fn is_weakly_connected_kattis_bryr_3() {
+        assert!(kattis_bryr_3_usize().is_weakly_connected());
+    }
+
+    #[test]
+    fn is_weakly_connected_kattis_crosscountry() {
+        assert!(kattis_crosscountry_usize().is_weakly_connected());
+    }
+
+    #[test]
+    fn is_weakly_connected_kattis_shortestpath1() {
+        assert!(kattis_shortestpath1_usize().is_weakly_connected());
+    }
+
+    #[test]
+    fn is_weakly_connected_kattis_shortestpath3() {
+        assert!(!kattis_shortestpath3().is_weakly_connected());
+    }
+
     #[test]
     fn order_bang_jensen_94_weighted() {
         assert!(bang_jensen_94_usize().order() == 7);
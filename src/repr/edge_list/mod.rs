@@ -921,6 +921,16 @@ mod tests_erdos_renyi {
     test_erdos_renyi!(EdgeList);
 }
 
+#[cfg(test)]
+mod tests_has_eulerian_trail {
+    use crate::{
+        HasEulerianTrail,
+        test_has_eulerian_trail,
+    };
+
+    test_has_eulerian_trail!(crate::repr::edge_list::fixture);
+}
+
 #[cfg(test)]
 mod tests_has_walk {
     use {
@@ -981,6 +991,16 @@ mod tests_is_complete {
     test_is_complete!(crate::repr::edge_list::fixture);
 }
 
+#[cfg(test)]
+mod tests_is_eulerian {
+    use crate::{
+        IsEulerian,
+        test_is_eulerian,
+    };
+
+    test_is_eulerian!(crate::repr::edge_list::fixture);
+}
+
 #[cfg(test)]
 mod tests_is_isolated {
     use crate::{
@@ -1041,6 +1061,19 @@ mod tests_is_simple {
     test_is_simple!(crate::repr::edge_list::fixture);
 }
 
+#[cfg(test)]
+mod tests_is_strongly_connected {
+    use {
+        super::*,
+        crate::{
+            IsStronglyConnected,
+            test_is_strongly_connected,
+        },
+    };
+
+    test_is_strongly_connected!(EdgeList, crate::repr::edge_list::fixture);
+}
+
 #[cfg(test)]
 mod tests_is_symmetric {
     use crate::{
@@ -1061,6 +1094,16 @@ mod tests_is_tournament {
     test_is_tournament!(crate::repr::edge_list::fixture);
 }
 
+#[cfg(test)]
+mod tests_is_weakly_connected {
+    use crate::{
+        IsWeaklyConnected,
+        test_is_weakly_connected,
+    };
+
+    test_is_weakly_connected!(crate::repr::edge_list::fixture);
+}
+
 #[cfg(test)]
 mod tests_order {
     use crate::{